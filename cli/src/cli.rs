@@ -348,6 +348,9 @@ impl KaspaCli {
                                     this.term().refresh_prompt();
 
                                 },
+                                Events::AddressBookEntryAdd { .. } |
+                                Events::AddressBookEntryUpdate { .. } |
+                                Events::AddressBookEntryRemove { .. } => {},
                                 Events::WalletClose => {
                                     this.term().refresh_prompt();
                                 },
@@ -401,6 +404,15 @@ impl KaspaCli {
                                         tx.iter().for_each(|line|tprintln!(this,"{NOTIFY} {line}"));
                                     }
                                 },
+                                Events::Compound {
+                                    id, utxos, amount, fees, transaction_ids
+                                } => {
+                                    let network_id = this.wallet.network_id().expect("missing network type");
+                                    let network_type = NetworkType::from(network_id);
+                                    let amount = sompi_to_kaspa_string_with_suffix(amount, &network_type);
+                                    let fees = sompi_to_kaspa_string_with_suffix(fees, &network_type);
+                                    tprintln!(this, "{NOTIFY} {} {}: {utxos} UTXOs into {amount} (fees {fees}) in {} transaction(s)",style("compound".pad_to_width(8)).blue(), id.short(), transaction_ids.len());
+                                },
                                 Events::Balance {
                                     balance,
                                     id,
@@ -507,6 +519,16 @@ impl KaspaCli {
             list_by_key.push((key.clone(), prv_key_accounts));
         }
 
+        let mut watch_only_accounts = Vec::new();
+        let mut accounts = self.wallet.accounts(None).await?;
+        while let Some(account) = accounts.next().await {
+            let account = account?;
+            if matches!(account.account_kind(), AccountKind::WatchOnly) {
+                watch_only_accounts.push((flat_list.len(), account.clone()));
+                flat_list.push(account.clone());
+            }
+        }
+
         if flat_list.is_empty() {
             return Err(Error::NoAccounts);
         } else if autoselect && flat_list.len() == 1 {
@@ -526,6 +548,15 @@ impl KaspaCli {
                 })
             });
 
+            if !watch_only_accounts.is_empty() {
+                tprintln!(self, "• watch-only");
+                watch_only_accounts.iter().for_each(|(seq, account)| {
+                    let seq = style(seq.to_string()).cyan();
+                    let ls_string = account.get_list_string().unwrap_or_else(|err| panic!("{err}"));
+                    tprintln!(self, "    {seq}: {ls_string}");
+                });
+            }
+
             tprintln!(self);
 
             let range = if flat_list.len() > 1 { format!("[{}..{}] ", 0, flat_list.len() - 1) } else { "".to_string() };
@@ -612,6 +643,21 @@ impl KaspaCli {
                 tprintln!(self, "      {}", style(receive_address.to_string()).blue());
             }
         }
+
+        let mut header = false;
+        let mut accounts = self.wallet.accounts(None).await?;
+        while let Some(account) = accounts.try_next().await? {
+            if !matches!(account.account_kind(), AccountKind::WatchOnly) {
+                continue;
+            }
+            if !header {
+                tprintln!(self, "• {}", style("watch-only").dim());
+                header = true;
+            }
+            let receive_address = account.receive_address()?;
+            tprintln!(self, "    • {}", account.get_list_string()?);
+            tprintln!(self, "      {}", style(receive_address.to_string()).blue());
+        }
         tprintln!(self);
 
         Ok(())
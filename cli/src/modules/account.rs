@@ -58,6 +58,22 @@ impl Account {
                 let account_name = account_name.as_deref();
                 wizards::account::create(&ctx, prv_key_data_id, account_kind, account_name).await?;
             }
+            "watch" => {
+                if argv.is_empty() {
+                    tprintln!(ctx, "usage: 'account watch <xpub>[,<xpub>...] [<minimum signatures>] [<name>]'");
+                    return Ok(());
+                }
+                let xpub_keys = argv.remove(0).split(',').map(|xpub| xpub.trim().to_string()).collect::<Vec<_>>();
+                let minimum_signatures =
+                    if xpub_keys.len() > 1 && !argv.is_empty() { argv.remove(0).parse::<u16>()? } else { xpub_keys.len() as u16 };
+                let name = if argv.is_empty() { None } else { Some(argv.join(" ").trim().to_string()) };
+
+                let (wallet_secret, _) = ctx.ask_wallet_secret(None).await?;
+                let _ = ctx.notifier().show(Notification::Processing).await;
+                let account = wallet.create_watch_only_account(wallet_secret, xpub_keys, minimum_signatures, name).await?;
+                tprintln!(ctx, "\nwatch-only account created: {}\n", account.name_with_id());
+                wallet.select(Some(&account)).await?;
+            }
             "scan" => {
                 let extent = if argv.is_empty() {
                     100_000
@@ -103,6 +119,10 @@ impl Account {
                 ("create [<type>] [<name>]", "Create a new account (types: 'bip32' (default), 'legacy')"),
                 // ("import", "Import a private key using 24 or 12 word mnemonic"),
                 ("name <name>", "Name or rename the selected account (use 'remove' to remove the name"),
+                (
+                    "watch <xpub>[,<xpub>...] [<minimum signatures>] [<name>]",
+                    "Create a watch-only account from extended public key(s) (multiple keys for multisig)",
+                ),
                 ("scan [<derivations>]", "Scan extended address derivation chain (legacy accounts)"),
                 ("sweep [<derivations>]", "Sweep extended address derivation chain (legacy accounts)"),
                 // ("purge", "Purge an account from the wallet"),
@@ -0,0 +1,143 @@
+use crate::imports::*;
+use kaspa_wallet_core::error::Error as WalletError;
+use kaspa_wallet_core::storage::AddressBookEntry;
+
+#[derive(Default, Handler)]
+#[help("Address book (contacts) management")]
+pub struct Book;
+
+impl Book {
+    async fn main(self: Arc<Self>, ctx: &Arc<dyn Context>, mut argv: Vec<String>, _cmd: &str) -> Result<()> {
+        let ctx = ctx.clone().downcast_arc::<KaspaCli>()?;
+        let wallet = ctx.wallet();
+
+        if !wallet.is_open() {
+            return Err(Error::WalletIsNotOpen);
+        }
+
+        if argv.is_empty() {
+            return self.list(&ctx).await;
+        }
+
+        match argv.remove(0).as_str() {
+            "list" => {
+                self.list(&ctx).await?;
+            }
+            "search" => {
+                if argv.is_empty() {
+                    tprintln!(ctx, "usage: 'book search <text>'");
+                    return Ok(());
+                }
+                let entries = wallet.search_address_book(argv.join(" ").as_str()).await?;
+                if entries.is_empty() {
+                    tprintln!(ctx, "no matching entries found");
+                }
+                entries.iter().for_each(|entry| self.display(&ctx, entry));
+            }
+            "add" => {
+                if argv.len() < 2 {
+                    tprintln!(ctx, "usage: 'book add <alias> <address> [<title>]'");
+                    return Ok(());
+                }
+                let alias = argv.remove(0);
+                let address = Address::try_from(argv.remove(0).as_str())?;
+                let title = argv.join(" ");
+                let network_id = wallet.network_id().ok();
+                let entry = AddressBookEntry::try_new(&alias, &title, address, vec![], network_id)?;
+                let (wallet_secret, _) = ctx.ask_wallet_secret(None).await?;
+                wallet.add_address_book_entry(wallet_secret, entry).await?;
+                tprintln!(ctx, "added '{alias}' to the address book");
+            }
+            "edit" => {
+                if argv.len() < 3 {
+                    tprintln!(ctx, "usage: 'book edit <alias> <alias|address|title|labels|network> <value>'");
+                    return Ok(());
+                }
+                let alias = argv.remove(0);
+                let field = argv.remove(0);
+                let value = argv.join(" ");
+                let mut entry = wallet
+                    .get_address_book_entry(&alias)
+                    .await?
+                    .ok_or_else(|| WalletError::AddressBookEntryNotFound(alias.clone()))?
+                    .as_ref()
+                    .clone();
+                match field.as_str() {
+                    "alias" => entry.alias = value,
+                    "address" => entry.address = Address::try_from(value.as_str())?,
+                    "title" => entry.title = value,
+                    "labels" => {
+                        entry.labels =
+                            value.split(',').map(|label| label.trim().to_string()).filter(|label| !label.is_empty()).collect();
+                    }
+                    "network" => {
+                        entry.network_id = if value == "any" { None } else { Some(value.parse::<NetworkId>()?) };
+                    }
+                    _ => {
+                        tprintln!(ctx, "unknown field '{field}', must be one of: alias, address, title, labels, network");
+                        return Ok(());
+                    }
+                }
+                let (wallet_secret, _) = ctx.ask_wallet_secret(None).await?;
+                wallet.update_address_book_entry(wallet_secret, &alias, entry).await?;
+                tprintln!(ctx, "address book entry '{alias}' updated");
+            }
+            "remove" => {
+                if argv.len() != 1 {
+                    tprintln!(ctx, "usage: 'book remove <alias>'");
+                    return Ok(());
+                }
+                let alias = argv.remove(0);
+                let (wallet_secret, _) = ctx.ask_wallet_secret(None).await?;
+                wallet.remove_address_book_entry(wallet_secret, &alias).await?;
+                tprintln!(ctx, "removed '{alias}' from the address book");
+            }
+            v => {
+                tprintln!(ctx, "unknown command: '{v}'\r\n");
+                return self.display_help(ctx, argv).await;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn list(self: &Arc<Self>, ctx: &Arc<KaspaCli>) -> Result<()> {
+        let mut entries = ctx.wallet().address_book().await?;
+        let mut empty = true;
+        while let Some(entry) = entries.try_next().await? {
+            self.display(ctx, &entry);
+            empty = false;
+        }
+        if empty {
+            tprintln!(ctx, "the address book is empty");
+        }
+        Ok(())
+    }
+
+    fn display(self: &Arc<Self>, ctx: &Arc<KaspaCli>, entry: &AddressBookEntry) {
+        let labels = if entry.labels.is_empty() { "".to_string() } else { format!(" [{}]", entry.labels.join(", ")) };
+        let network = entry.network_id.map(|network_id| format!(" ({network_id})")).unwrap_or_default();
+        tprintln!(ctx, "{}: {}{network}", style(&entry.alias).blue(), entry.address);
+        if !entry.title.is_empty() || !labels.is_empty() {
+            tprintln!(ctx, "    {}{labels}", entry.title);
+        }
+    }
+
+    async fn display_help(self: Arc<Self>, ctx: Arc<KaspaCli>, _argv: Vec<String>) -> Result<()> {
+        ctx.term().help(
+            &[
+                ("list", "List address book entries"),
+                ("search <text>", "Search entries by alias, title, address or label"),
+                ("add <alias> <address> [<title>]", "Add a new entry bound to the current network"),
+                (
+                    "edit <alias> <field> <value>",
+                    "Change the entry alias, address, title, labels (comma separated) or network ('any' for none)",
+                ),
+                ("remove <alias>", "Remove an entry from the address book"),
+            ],
+            None,
+        )?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,113 @@
+use crate::imports::*;
+use kaspa_wallet_core::runtime::account::CompoundingPolicy;
+
+#[derive(Default, Handler)]
+#[help("Automatic UTXO compounding for the selected account")]
+pub struct Compound;
+
+impl Compound {
+    async fn main(self: Arc<Self>, ctx: &Arc<dyn Context>, mut argv: Vec<String>, _cmd: &str) -> Result<()> {
+        let ctx = ctx.clone().downcast_arc::<KaspaCli>()?;
+
+        if argv.is_empty() {
+            return self.display_help(ctx, argv).await;
+        }
+
+        let account = ctx.account().await?;
+        let network_type = NetworkType::from(ctx.wallet().network_id()?);
+
+        match argv.remove(0).as_str() {
+            "enable" => {
+                if argv.len() < 2 {
+                    tprintln!(ctx, "usage: 'compound enable <utxo threshold> <fee budget> [<max utxo amount>] [<max mempool size>]'");
+                    return Ok(());
+                }
+
+                let utxo_threshold =
+                    argv[0].parse::<usize>().map_err(|_| Error::custom(format!("invalid UTXO threshold '{}'", argv[0])))?;
+                let fee_budget = try_parse_required_kaspa_as_sompi_u64(argv.get(1))?;
+                let max_utxo_amount = match argv.get(2).map(String::as_str) {
+                    None | Some("any") => None,
+                    amount => Some(try_parse_required_nonzero_kaspa_as_sompi_u64(amount)?),
+                };
+                let max_mempool_size = argv
+                    .get(3)
+                    .map(|size| size.parse::<u64>().map_err(|_| Error::custom(format!("invalid mempool size '{size}'"))))
+                    .transpose()?;
+
+                let policy = CompoundingPolicy::new(utxo_threshold, max_utxo_amount, fee_budget, max_mempool_size);
+                let (wallet_secret, payment_secret) = ctx.ask_wallet_secret(Some(&account)).await?;
+                account.enable_compounding(policy, wallet_secret, payment_secret).await?;
+                tprintln!(ctx, "UTXO compounding enabled for account {}", account.name_or_id());
+
+                // apply the policy right away instead of waiting for the next balance update
+                account.compound().await?;
+            }
+            "resume" => {
+                let (wallet_secret, payment_secret) = ctx.ask_wallet_secret(Some(&account)).await?;
+                account.resume_compounding(wallet_secret, payment_secret).await?;
+                tprintln!(ctx, "UTXO compounding resumed for account {}", account.name_or_id());
+                account.compound().await?;
+            }
+            "disable" => {
+                let (wallet_secret, _) = ctx.ask_wallet_secret(None).await?;
+                account.disable_compounding(wallet_secret).await?;
+                tprintln!(ctx, "UTXO compounding disabled for account {}", account.name_or_id());
+            }
+            "status" => {
+                let (is_active, status) = match account.compounding_status() {
+                    Some(status) => (true, Some(status)),
+                    None => (false, account.stored_compounding_status()),
+                };
+                if let Some(status) = status {
+                    let policy = &status.policy;
+                    let max_utxo_amount = policy
+                        .max_utxo_amount
+                        .map(|amount| sompi_to_kaspa_string_with_suffix(amount, &network_type))
+                        .unwrap_or_else(|| "any".to_string());
+                    if is_active {
+                        tprintln!(ctx, "UTXO compounding is enabled:");
+                    } else {
+                        tprintln!(ctx, "UTXO compounding is configured but inactive in this session (use 'compound resume'):");
+                    }
+                    tprintln!(ctx, "    UTXO threshold: {}", policy.utxo_threshold);
+                    tprintln!(ctx, "    max UTXO amount: {max_utxo_amount}");
+                    if let Some(max_mempool_size) = policy.max_mempool_size {
+                        tprintln!(ctx, "    max mempool size: {max_mempool_size}");
+                    }
+                    tprintln!(
+                        ctx,
+                        "    fees spent: {} of {}",
+                        sompi_to_kaspa_string_with_suffix(status.fees_spent, &network_type),
+                        sompi_to_kaspa_string_with_suffix(policy.fee_budget, &network_type)
+                    );
+                } else {
+                    tprintln!(ctx, "UTXO compounding is disabled");
+                }
+            }
+            v => {
+                tprintln!(ctx, "unknown command: '{v}'\r\n");
+                return self.display_help(ctx, argv).await;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn display_help(self: Arc<Self>, ctx: Arc<KaspaCli>, _argv: Vec<String>) -> Result<()> {
+        ctx.term().help(
+            &[
+                (
+                    "enable <utxo threshold> <fee budget> [<max utxo amount>] [<max mempool size>]",
+                    "Compound UTXOs (up to 'max utxo amount', 'any' for all) once their count exceeds the threshold, optionally only while the node mempool is below the given size",
+                ),
+                ("resume", "Re-activate the stored compounding policy (requires the wallet password to sign transactions)"),
+                ("disable", "Disable UTXO compounding"),
+                ("status", "Display the compounding policy and fees spent against the fee budget"),
+            ],
+            None,
+        )?;
+
+        Ok(())
+    }
+}
@@ -1,4 +1,6 @@
 use crate::imports::*;
+use kaspa_wallet_core::storage::{TransactionExportFormat, TransactionExportRange};
+use workflow_store::fs;
 
 #[derive(Default, Handler)]
 #[help("Export transactions, a wallet or a private key")]
@@ -9,12 +11,15 @@ impl Export {
         let ctx = ctx.clone().downcast_arc::<KaspaCli>()?;
 
         if argv.is_empty() || argv.get(0) == Some(&"help".to_string()) {
-            tprintln!(ctx, "usage: export [mnemonic]");
+            tprintln!(ctx, "usage: export [mnemonic|history]");
+            tprintln!(ctx, "       export history <csv|json> <filename> [<from date>] [<to date>] [--network=<network id>]");
+            tprintln!(ctx, "       (dates are YYYY-MM-DD in UTC or unix time in milliseconds, use '-' to omit the start date)");
             return Ok(());
         }
 
         let what = argv.get(0).unwrap();
         match what.as_str() {
+            "history" => self.export_history(&ctx, argv[1..].to_vec()).await,
             "mnemonic" => {
                 let account = ctx.account().await?;
                 let prv_key_data_id = account.prv_key_data_id()?;
@@ -73,4 +78,42 @@ impl Export {
             _ => Err(format!("Invalid argument: {}", what).into()),
         }
     }
+
+    async fn export_history(self: &Arc<Self>, ctx: &Arc<KaspaCli>, argv: Vec<String>) -> Result<()> {
+        let (network, mut argv): (Vec<_>, Vec<_>) = argv.into_iter().partition(|arg| arg.starts_with("--network="));
+        if argv.len() < 2 {
+            tprintln!(ctx, "usage: export history <csv|json> <filename> [<from date>] [<to date>] [--network=<network id>]");
+            return Ok(());
+        }
+
+        let account = ctx.account().await?;
+        let network_id = match network.last() {
+            Some(network) => network.trim_start_matches("--network=").parse::<NetworkId>()?,
+            None => ctx.wallet().network_id()?,
+        };
+        let format = argv.remove(0).parse::<TransactionExportFormat>()?;
+        let filename = argv.remove(0);
+        let range = TransactionExportRange::try_from_dates(
+            argv.get(0).map(String::as_str).filter(|date| *date != "-"),
+            argv.get(1).map(String::as_str),
+        )?;
+
+        let export = ctx.wallet().export_transaction_history(&account, &network_id, &range, format).await?;
+        fs::write_string(&fs::resolve_path(&filename)?, &export.text).await?;
+        tprintln!(
+            ctx,
+            "{} transaction(s) of account {} on {network_id} exported to '{filename}'",
+            export.exported,
+            account.name_or_id()
+        );
+        if export.skipped > 0 {
+            tprintln!(
+                ctx,
+                "{} transaction(s) skipped: no timestamp is available to match them against the date range (connect to a {network_id} node to date them)",
+                export.skipped
+            );
+        }
+
+        Ok(())
+    }
 }
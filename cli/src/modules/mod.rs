@@ -2,11 +2,11 @@ use crate::imports::*;
 
 pub mod account;
 pub mod address;
+pub mod book;
 pub mod broadcast;
 pub mod close;
+pub mod compound;
 pub mod connect;
-#[path = "create-unsigned-tx.rs"]
-pub mod create_unsigned_tx;
 pub mod details;
 pub mod disconnect;
 pub mod estimate;
@@ -39,11 +39,12 @@ pub mod sweep;
 pub mod theme;
 pub mod track;
 pub mod transfer;
+pub mod unsigned;
+pub mod utxo;
 pub mod wallet;
 
 // TODO
 // broadcast
-// create-unsigned-tx
 // sign
 
 pub fn register_handlers(cli: &Arc<KaspaCli>) -> Result<()> {
@@ -51,9 +52,9 @@ pub fn register_handlers(cli: &Arc<KaspaCli>) -> Result<()> {
         cli,
         cli.handlers(),
         [
-            account, address, close, connect, details, disconnect, estimate, exit, export, guide, help, history, import, rpc, list,
-            miner, monitor, mute, network, node, open, ping, reload, select, send, server, settings, sweep, track, transfer,
-            wallet,
+            account, address, book, close, compound, connect, details, disconnect, estimate, exit, export, guide, help, history,
+            import, rpc, list, miner, monitor, mute, network, node, open, ping, reload, select, send, server, settings, sweep, track,
+            transfer, unsigned, utxo, wallet,
             // halt,
             // theme,  start, stop
         ]
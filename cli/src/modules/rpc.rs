@@ -38,7 +38,17 @@ impl Rpc {
                 tprintln!(ctx, "ok");
             }
             RpcApiOps::GetMetrics => {
-                let result = rpc.get_metrics(true, true).await?;
+                let result = rpc
+                    .get_metrics_call(GetMetricsRequest {
+                        process_metrics: true,
+                        consensus_metrics: true,
+                        p2p_metrics: true,
+                        mempool_metrics: true,
+                        dag_metrics: true,
+                        storage_metrics: true,
+                        utxoindex_metrics: true,
+                    })
+                    .await?;
                 self.println(&ctx, result);
             }
             RpcApiOps::GetServerInfo => {
@@ -81,7 +91,7 @@ impl Rpc {
                 self.println(&ctx, result);
             }
             RpcApiOps::GetConnectedPeerInfo => {
-                let result = rpc.get_connected_peer_info_call(GetConnectedPeerInfoRequest {}).await?;
+                let result = rpc.get_connected_peer_info_call(GetConnectedPeerInfoRequest { include_stats: true }).await?;
                 self.println(&ctx, result);
             }
             // RpcApiOps::AddPeer => {
@@ -207,6 +217,22 @@ impl Rpc {
                 let result = rpc.get_coin_supply_call(GetCoinSupplyRequest {}).await?;
                 self.println(&ctx, result);
             }
+            RpcApiOps::GetLogLevels => {
+                let result = rpc.get_log_levels_call(GetLogLevelsRequest {}).await?;
+                self.println(&ctx, result);
+            }
+            RpcApiOps::SetLogLevels => {
+                if argv.is_empty() {
+                    return Err(Error::custom("Please specify log level filters (ie. 'info,kaspa_p2p_flows=debug')"));
+                }
+                let filters = argv.join(",");
+                let result = rpc.set_log_levels_call(SetLogLevelsRequest { filters }).await?;
+                self.println(&ctx, result);
+            }
+            RpcApiOps::RotateLogFiles => {
+                let result = rpc.rotate_log_files_call(RotateLogFilesRequest {}).await?;
+                self.println(&ctx, result);
+            }
             _ => {
                 tprintln!(ctx, "rpc method exists but is not supported by the cli: '{op_str}'\r\n");
                 return Ok(());
@@ -1,7 +1,9 @@
 use crate::imports::*;
+use kaspa_wallet_core::tx::PaymentUri;
+use std::cmp::Ordering;
 
 #[derive(Default, Handler)]
-#[help("Send a Kaspa transaction to a public address")]
+#[help("Send a Kaspa transaction to a public address, an address book contact or a payment URI")]
 pub struct Send;
 
 impl Send {
@@ -11,14 +13,60 @@ impl Send {
 
         let account = ctx.wallet().account()?;
 
-        if argv.len() < 2 {
-            tprintln!(ctx, "usage: send <address> <amount> <priority fee>");
+        if argv.is_empty() {
+            tprintln!(ctx, "usage: send <address|contact> <amount> <priority fee>");
+            tprintln!(ctx, "       send <payment uri> <amount> <priority fee>");
+            tprintln!(ctx, "       send <payment uri with amount> <priority fee>");
             return Ok(());
         }
 
-        let address = Address::try_from(argv.get(0).unwrap().as_str())?;
-        let amount_sompi = try_parse_required_nonzero_kaspa_as_sompi_u64(argv.get(1))?;
-        let priority_fee_sompi = try_parse_optional_kaspa_as_sompi_i64(argv.get(2))?.unwrap_or(0);
+        let target = argv.get(0).unwrap();
+        let (address, amount_sompi, priority_fee) = if PaymentUri::is_uri(target) {
+            let uri = target.parse::<PaymentUri>()?;
+            uri.try_validate_network(ctx.wallet().network_id()?.network_type())?;
+            if let Some(label) = &uri.label {
+                tprintln!(ctx, "Payment to: {label}");
+            }
+            if let Some(message) = &uri.message {
+                tprintln!(ctx, "Message: {message}");
+            }
+            match uri.amount {
+                Some(amount_sompi) => {
+                    // the only argument accepted along with a URI carrying an amount is the priority fee,
+                    // which must be supplied explicitly so that it can not be mistaken for an amount
+                    let network_type = ctx.wallet().network_id()?.network_type();
+                    let amount = sompi_to_kaspa_string_with_suffix(amount_sompi, &network_type);
+                    let usage = "send <payment uri> <priority fee>";
+                    match argv.len().cmp(&2) {
+                        Ordering::Less => {
+                            return Err(Error::custom(format!(
+                                "the payment URI requests {amount}, please specify the priority fee explicitly ('0' for none): {usage}"
+                            )));
+                        }
+                        Ordering::Greater => {
+                            return Err(Error::custom(format!(
+                                "the payment URI already specifies the amount ({amount}), usage: {usage}"
+                            )));
+                        }
+                        Ordering::Equal => {}
+                    }
+                    tprintln!(ctx, "Amount: {amount} (requested by the payment URI)");
+                    (uri.address, amount_sompi, argv.get(1))
+                }
+                None => (uri.address, try_parse_required_nonzero_kaspa_as_sompi_u64(argv.get(1))?, argv.get(2)),
+            }
+        } else {
+            if argv.len() < 2 {
+                tprintln!(ctx, "usage: send <address|contact> <amount> <priority fee>");
+                return Ok(());
+            }
+            let address = match Address::try_from(target.as_str()) {
+                Ok(address) => address,
+                Err(_) => ctx.wallet().resolve_address_book_alias(target).await?,
+            };
+            (address, try_parse_required_nonzero_kaspa_as_sompi_u64(argv.get(1))?, argv.get(2))
+        };
+        let priority_fee_sompi = try_parse_optional_kaspa_as_sompi_i64(priority_fee)?.unwrap_or(0);
         let outputs = PaymentOutputs::from((address.clone(), amount_sompi));
         let abortable = Abortable::default();
         let (wallet_secret, payment_secret) = ctx.ask_wallet_secret(Some(&account)).await?;
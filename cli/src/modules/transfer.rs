@@ -11,18 +11,29 @@ impl Transfer {
         let account = ctx.wallet().account()?;
 
         if argv.len() < 2 {
-            tprintln!(ctx, "usage: transfer <account> <amount> <priority fee>");
+            tprintln!(ctx, "usage: transfer <account|contact> <amount> <priority fee>");
             return Ok(());
         }
 
-        let target_account = argv.get(0).unwrap();
-        let target_account = ctx.find_accounts_by_name_or_id(target_account).await?;
-        if target_account.id() == account.id() {
-            return Err("Cannot transfer to the same account".into());
-        }
+        let target = argv.get(0).unwrap();
+        let target_address = match ctx.find_accounts_by_name_or_id(target).await {
+            Ok(target_account) => {
+                if target_account.id() == account.id() {
+                    return Err("Cannot transfer to the same account".into());
+                }
+                target_account.receive_address()?
+            }
+            Err(err @ Error::AccountNotFound(_)) => {
+                // fall back to address book contacts
+                if ctx.wallet().get_address_book_entry(target).await?.is_none() {
+                    return Err(err);
+                }
+                ctx.wallet().resolve_address_book_alias(target).await?
+            }
+            Err(err) => return Err(err),
+        };
         let amount_sompi = try_parse_required_nonzero_kaspa_as_sompi_u64(argv.get(1))?;
         let priority_fee_sompi = try_parse_optional_kaspa_as_sompi_i64(argv.get(2))?.unwrap_or(0);
-        let target_address = target_account.receive_address()?;
         let (wallet_secret, payment_secret) = ctx.ask_wallet_secret(Some(&account)).await?;
 
         let abortable = Abortable::default();
@@ -0,0 +1,50 @@
+use crate::imports::*;
+use kaspa_wallet_core::tx::PaymentDestination;
+
+#[derive(Default, Handler)]
+#[help("Create unsigned transactions for external signing")]
+pub struct Unsigned;
+
+impl Unsigned {
+    async fn main(self: Arc<Self>, ctx: &Arc<dyn Context>, argv: Vec<String>, _cmd: &str) -> Result<()> {
+        let ctx = ctx.clone().downcast_arc::<KaspaCli>()?;
+        let account = ctx.wallet().account()?;
+
+        if argv.len() < 2 {
+            tprintln!(ctx, "usage: unsigned <address|contact> <amount> <priority fee>");
+            return Ok(());
+        }
+
+        let target = argv.get(0).unwrap();
+        let address = match Address::try_from(target.as_str()) {
+            Ok(address) => address,
+            Err(_) => ctx.wallet().resolve_address_book_alias(target).await?,
+        };
+        let amount_sompi = try_parse_required_nonzero_kaspa_as_sompi_u64(argv.get(1))?;
+        let priority_fee_sompi = try_parse_optional_kaspa_as_sompi_i64(argv.get(2))?.unwrap_or(0);
+        let outputs = PaymentOutputs::from((address, amount_sompi));
+        let abortable = Abortable::default();
+
+        let destination: PaymentDestination = outputs.into();
+        let (summary, transactions) =
+            account.create_unsigned_transactions(destination, priority_fee_sompi.into(), None, &abortable).await?;
+
+        // each transaction is emitted along with the UTXO entries
+        // it spends, as required by the external signer
+        for transaction in transactions {
+            let signable = transaction.signable_transaction();
+            let entries = signable
+                .entries
+                .iter()
+                .cloned()
+                .map(|entry| entry.ok_or_else(|| Error::Custom("missing UTXO entry of a transaction input".into())))
+                .collect::<Result<Vec<_>>>()?;
+            let json = serde_json::json!({ "transaction": signable.tx.as_ref(), "entries": entries });
+            tprintln!(ctx, "{}", serde_json::to_string_pretty(&json)?);
+        }
+
+        tprintln!(ctx, "Unsigned - {summary}");
+
+        Ok(())
+    }
+}
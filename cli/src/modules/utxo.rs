@@ -0,0 +1,163 @@
+use crate::imports::*;
+use kaspa_consensus_core::tx::TransactionId;
+use kaspa_wallet_core::runtime::Account;
+use kaspa_wallet_core::utxo::{UtxoEntryId, UtxoEntryReference};
+
+#[derive(Default, Handler)]
+#[help("UTXO management (coin control)")]
+pub struct Utxo;
+
+impl Utxo {
+    async fn main(self: Arc<Self>, ctx: &Arc<dyn Context>, mut argv: Vec<String>, _cmd: &str) -> Result<()> {
+        let ctx = ctx.clone().downcast_arc::<KaspaCli>()?;
+
+        if argv.is_empty() {
+            return self.display_help(ctx, argv).await;
+        }
+
+        let account = ctx.account().await?;
+
+        match argv.remove(0).as_str() {
+            "list" => {
+                self.list(&ctx, &account)?;
+            }
+            "freeze" | "unfreeze" if argv.is_empty() => {
+                tprintln!(ctx, "usage: 'utxo freeze <txid:index> [<txid:index> ...]' or 'utxo unfreeze <txid:index> [...]'");
+            }
+            "freeze" => {
+                let utxos = parse_utxo_ids(&argv)?;
+                account.freeze_utxos(&utxos, true).await?;
+                tprintln!(ctx, "frozen {} UTXO(s)", utxos.len());
+            }
+            "unfreeze" => {
+                let utxos = parse_utxo_ids(&argv)?;
+                account.freeze_utxos(&utxos, false).await?;
+                tprintln!(ctx, "unfrozen {} UTXO(s)", utxos.len());
+            }
+            "spend" => {
+                if argv.len() < 3 {
+                    tprintln!(ctx, "usage: 'utxo spend <txid:index>[,<txid:index>...] <address|contact> <amount> [<priority fee>]'");
+                    return Ok(());
+                }
+
+                let utxos = parse_utxo_ids(&argv.remove(0).split(',').map(String::from).collect::<Vec<_>>())?;
+                let target = argv.get(0).unwrap();
+                let address = match Address::try_from(target.as_str()) {
+                    Ok(address) => address,
+                    Err(_) => ctx.wallet().resolve_address_book_alias(target).await?,
+                };
+                let amount_sompi = try_parse_required_nonzero_kaspa_as_sompi_u64(argv.get(1))?;
+                let priority_fee_sompi = try_parse_optional_kaspa_as_sompi_i64(argv.get(2))?.unwrap_or(0);
+                let outputs = PaymentOutputs::from((address, amount_sompi));
+                let abortable = Abortable::default();
+                let (wallet_secret, payment_secret) = ctx.ask_wallet_secret(Some(&account)).await?;
+
+                let (summary, _ids) = account
+                    .clone()
+                    .send_with_utxos(
+                        &utxos,
+                        outputs.into(),
+                        priority_fee_sompi.into(),
+                        None,
+                        wallet_secret,
+                        payment_secret,
+                        &abortable,
+                        None,
+                    )
+                    .await?;
+
+                tprintln!(ctx, "Send - {summary}");
+            }
+            v => {
+                tprintln!(ctx, "unknown command: '{v}'\r\n");
+                return self.display_help(ctx, argv).await;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn list(self: &Arc<Self>, ctx: &Arc<KaspaCli>, account: &Arc<dyn Account>) -> Result<()> {
+        let network_type = NetworkType::from(ctx.wallet().network_id()?);
+        let utxo_context = account.utxo_context();
+
+        let mature = utxo_context.mature_utxos();
+        let pending = utxo_context.pending_utxos();
+        if mature.is_empty() && pending.is_empty() {
+            tprintln!(ctx, "no UTXOs found");
+            return Ok(());
+        }
+
+        let display = |entry: &UtxoEntryReference, status: &str| {
+            let status = if utxo_context.is_frozen(entry.id_as_ref()) { format!("{status}, frozen") } else { status.to_string() };
+            let origin = if entry.is_coinbase() { "coinbase" } else { "transaction" };
+            let address = entry.utxo.address.as_ref().map(|address| address.to_string()).unwrap_or_default();
+            tprintln!(
+                ctx,
+                "{} {} ({status})",
+                style(entry.id()).dim(),
+                sompi_to_kaspa_string_with_suffix(entry.amount(), &network_type)
+            );
+            tprintln!(ctx, "    {origin} at DAA score {} to {}", entry.block_daa_score(), style(address).blue());
+        };
+
+        mature.iter().for_each(|entry| display(entry, "mature"));
+        pending.iter().for_each(|entry| display(entry, "pending"));
+
+        Ok(())
+    }
+
+    async fn display_help(self: Arc<Self>, ctx: Arc<KaspaCli>, _argv: Vec<String>) -> Result<()> {
+        ctx.term().help(
+            &[
+                ("list", "List UTXOs of the selected account with their amounts, maturity and origin"),
+                ("freeze <txid:index> [...]", "Exclude UTXOs from automatic selection when sending"),
+                ("unfreeze <txid:index> [...]", "Return frozen UTXOs to automatic selection"),
+                (
+                    "spend <txid:index>[,...] <address|contact> <amount> [<priority fee>]",
+                    "Send funds using only the specified UTXOs (frozen UTXOs are accepted)",
+                ),
+            ],
+            None,
+        )?;
+
+        Ok(())
+    }
+}
+
+/// Parses UTXO ids in the `<txid>:<index>` (or `<txid>-<index>`) format
+fn parse_utxo_ids(args: &[String]) -> Result<Vec<UtxoEntryId>> {
+    args.iter()
+        .map(|arg| {
+            let (transaction_id, index) =
+                arg.trim().rsplit_once([':', '-']).ok_or_else(|| Error::custom(format!("invalid UTXO id '{arg}'")))?;
+            let transaction_id = transaction_id
+                .parse::<TransactionId>()
+                .map_err(|err| Error::custom(format!("invalid transaction id in '{arg}': {err}")))?;
+            let index = index.parse::<u32>().map_err(|err| Error::custom(format!("invalid index in '{arg}': {err}")))?;
+            Ok(UtxoEntryId::new(transaction_id, index))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TXID: &str = "8fbd05ee4f4a8f0b6c2e3f1a3e0a2f1ab2d1e0f9c8b7a6958473625140302010";
+
+    #[test]
+    fn test_parse_utxo_ids() {
+        let transaction_id = TXID.parse::<TransactionId>().unwrap();
+        let args = [format!("{TXID}:0"), format!(" {TXID}-7 "), format!("{TXID}:4294967295")].map(String::from);
+        assert_eq!(
+            parse_utxo_ids(&args).unwrap(),
+            vec![UtxoEntryId::new(transaction_id, 0), UtxoEntryId::new(transaction_id, 7), UtxoEntryId::new(transaction_id, u32::MAX)]
+        );
+        assert!(parse_utxo_ids(&[]).unwrap().is_empty());
+
+        for invalid in [TXID.to_string(), format!("{TXID}:"), format!("{TXID}:x"), format!("{TXID}:4294967296"), "abc:0".to_string()] {
+            assert!(parse_utxo_ids(&[invalid.clone()]).is_err(), "'{invalid}' should be rejected");
+        }
+    }
+}
@@ -1,5 +1,7 @@
 use crate::imports::*;
 use crate::wizards;
+use kaspa_wallet_core::storage::Backup;
+use workflow_store::fs;
 
 #[derive(Default, Handler)]
 #[help("Wallet management operations")]
@@ -48,6 +50,101 @@ impl Wallet {
             "close" => {
                 ctx.wallet().close().await?;
             }
+            "backup" => {
+                if argv.len() != 1 {
+                    tprintln!(ctx, "usage: 'wallet backup <filename>'");
+                    return Ok(());
+                }
+                let filename = argv.remove(0);
+                let (wallet_secret, _) = ctx.ask_wallet_secret(None).await?;
+                let backup = ctx.wallet().create_backup(wallet_secret).await?;
+                fs::write_string(&fs::resolve_path(&filename)?, &backup.try_to_json()?).await?;
+                tprintln!(ctx, "wallet backup saved to '{filename}' (encrypted with the wallet password)");
+            }
+            "restore" => {
+                if argv.is_empty() {
+                    tprintln!(ctx, "usage: 'wallet restore <filename> [<name>]'");
+                    return Ok(());
+                }
+                let filename = argv.remove(0);
+                let name = argv.first().map(|name| name.trim().to_string());
+                if name.as_deref() == Some("wallet") {
+                    return Err(Error::custom("Wallet name cannot be 'wallet'"));
+                }
+
+                let backup = Backup::try_from_json(&fs::read_to_string(&fs::resolve_path(&filename)?).await?)?;
+                let term = ctx.term();
+                let backup_secret = Secret::new(term.ask(true, "Enter backup (wallet) password: ").await?.trim().as_bytes().to_vec());
+                let wallet_secret = Secret::new(
+                    term.ask(true, "Enter new wallet password (optional, press <enter> to keep): ").await?.trim().as_bytes().to_vec(),
+                );
+                let wallet_secret = if wallet_secret.as_ref().is_empty() {
+                    None
+                } else {
+                    let wallet_secret_validate =
+                        Secret::new(term.ask(true, "Re-enter new wallet password: ").await?.trim().as_bytes().to_vec());
+                    if wallet_secret_validate.as_ref() != wallet_secret.as_ref() {
+                        return Err(Error::WalletSecretMatch);
+                    }
+                    Some(wallet_secret)
+                };
+
+                let descriptor = ctx.wallet().restore_backup(&backup, backup_secret, name, wallet_secret, false).await?;
+                if let Some(descriptor) = descriptor {
+                    tprintln!(ctx, "wallet restored to '{descriptor}'");
+                }
+            }
+            "password" => {
+                let term = ctx.term();
+                let (wallet_secret, _) = ctx.ask_wallet_secret(None).await?;
+                let new_wallet_secret = Secret::new(term.ask(true, "Enter new wallet password: ").await?.trim().as_bytes().to_vec());
+                if new_wallet_secret.as_ref().is_empty() {
+                    return Err(Error::WalletSecretRequired);
+                }
+                let new_wallet_secret_validate =
+                    Secret::new(term.ask(true, "Re-enter new wallet password: ").await?.trim().as_bytes().to_vec());
+                if new_wallet_secret_validate.as_ref() != new_wallet_secret.as_ref() {
+                    return Err(Error::WalletSecretMatch);
+                }
+
+                ctx.wallet().change_wallet_secret(wallet_secret, new_wallet_secret).await?;
+                tprintln!(ctx, "wallet password changed");
+            }
+            "payment-password" => {
+                let term = ctx.term();
+                let prv_key_data_info = ctx.select_private_key().await?;
+                let (wallet_secret, _) = ctx.ask_wallet_secret(None).await?;
+                let payment_secret = if prv_key_data_info.is_encrypted {
+                    Some(Secret::new(term.ask(true, "Enter current payment password: ").await?.trim().as_bytes().to_vec()))
+                } else {
+                    None
+                };
+                let new_payment_secret = Secret::new(
+                    term.ask(true, "Enter new payment password (press <enter> to remove the payment password): ")
+                        .await?
+                        .trim()
+                        .as_bytes()
+                        .to_vec(),
+                );
+                let new_payment_secret = if new_payment_secret.as_ref().is_empty() {
+                    None
+                } else {
+                    let new_payment_secret_validate =
+                        Secret::new(term.ask(true, "Re-enter new payment password: ").await?.trim().as_bytes().to_vec());
+                    if new_payment_secret_validate.as_ref() != new_payment_secret.as_ref() {
+                        return Err(Error::PaymentSecretMatch);
+                    }
+                    Some(new_payment_secret)
+                };
+
+                let removed = new_payment_secret.is_none();
+                ctx.wallet().change_payment_secret(wallet_secret, &prv_key_data_info.id, payment_secret, new_payment_secret).await?;
+                if removed {
+                    tprintln!(ctx, "payment password removed from private key {}", prv_key_data_info.id);
+                } else {
+                    tprintln!(ctx, "payment password of private key {} changed", prv_key_data_info.id);
+                }
+            }
             "hint" => {
                 if !argv.is_empty() {
                     let re = regex::Regex::new(r"wallet\s+hint\s+").unwrap();
@@ -80,6 +177,10 @@ impl Wallet {
                 ("open [<name>]", "Open an existing wallet (shorthand: 'open [<name>]')"),
                 ("close", "Close an opened wallet (shorthand: 'close')"),
                 ("hint", "Change the wallet phishing hint"),
+                ("password", "Change the wallet password"),
+                ("payment-password", "Add, change or remove the payment password of a private key"),
+                ("backup <filename>", "Save an encrypted backup of the wallet (keys, accounts, address book and history)"),
+                ("restore <filename> [<name>]", "Restore a wallet backup as a new wallet, optionally changing the wallet password"),
             ],
             None,
         )?;
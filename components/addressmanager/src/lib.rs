@@ -2,7 +2,7 @@ mod stores;
 
 extern crate self as address_manager;
 
-use std::{collections::HashSet, sync::Arc};
+use std::{collections::HashSet, net::IpAddr, sync::Arc};
 
 use itertools::Itertools;
 use kaspa_consensus_core::config::Config;
@@ -46,54 +46,68 @@ impl AddressManager {
                 // An external IP was passed, we will try to bind that if it's valid
                 if local_net_address.is_publicly_routable() {
                     info!("External ip {} added to store", local_net_address);
-                    self.local_net_addresses.push(NetAddress { ip: local_net_address, port: self.config.default_p2p_port() });
+                    self.add_local_address(NetAddress { ip: local_net_address, port: self.config.default_p2p_port() });
                 } else {
                     debug!("Non-publicly routable external ip {} not added to store", local_net_address);
                 }
             }
             None => {
-                // If listen_address === 0.0.0.0, bind all interfaces
-                // else, bind whatever was passed as listen address (if routable)
-                let listen_address = self.config.p2p_listen_address.normalize(self.config.default_p2p_port());
-
-                if listen_address.ip.is_unspecified() {
-                    let network_interfaces = list_afinet_netifas();
-
-                    if let Ok(network_interfaces) = network_interfaces {
-                        for (_, ip) in network_interfaces.iter() {
-                            let curr_ip = IpAddress::new(*ip);
-
-                            // TODO: Add Check IPv4 or IPv6 match from Go code
-                            if curr_ip.is_publicly_routable() {
-                                info!("Publicly routable local address {} added to store", curr_ip);
-                                self.local_net_addresses.push(NetAddress { ip: curr_ip, port: self.config.default_p2p_port() });
-                            } else {
-                                debug!("Non-publicly routable interface address {} not added to store", curr_ip);
+                for listen_address in self.config.p2p_listen_addresses.clone() {
+                    // If listen_address === 0.0.0.0 (or ::), bind all interfaces of the same IP family
+                    // else, bind whatever was passed as listen address (if routable)
+                    let listen_address = listen_address.normalize(self.config.default_p2p_port());
+
+                    if listen_address.ip.is_unspecified() {
+                        let network_interfaces = list_afinet_netifas();
+
+                        if let Ok(network_interfaces) = network_interfaces {
+                            for (_, ip) in network_interfaces.iter() {
+                                let curr_ip = IpAddress::new(*ip);
+
+                                if curr_ip.is_ipv4() != listen_address.ip.is_ipv4() {
+                                    continue;
+                                }
+                                if curr_ip.is_publicly_routable() {
+                                    info!("Publicly routable local address {} added to store", curr_ip);
+                                    self.add_local_address(NetAddress { ip: curr_ip, port: listen_address.port });
+                                } else {
+                                    debug!("Non-publicly routable interface address {} not added to store", curr_ip);
+                                }
                             }
+                        } else {
+                            warn!("Error getting network interfaces: {:?}", network_interfaces);
                         }
+                    } else if listen_address.ip.is_publicly_routable() {
+                        info!("Publicly routable P2P listen address {} added to store", listen_address.ip);
+                        self.add_local_address(listen_address);
                     } else {
-                        warn!("Error getting network interfaces: {:?}", network_interfaces);
+                        debug!("Non-publicly routable listen address {} not added to store.", listen_address.ip);
                     }
-                } else if listen_address.ip.is_publicly_routable() {
-                    info!("Publicly routable P2P listen address {} added to store", listen_address.ip);
-                    self.local_net_addresses.push(listen_address);
-                } else {
-                    debug!("Non-publicly routable listen address {} not added to store.", listen_address.ip);
                 }
             }
         }
     }
 
-    pub fn best_local_address(&mut self) -> Option<NetAddress> {
-        if self.local_net_addresses.is_empty() {
-            None
-        } else {
-            // TODO: Add logic for finding the best as a function of a peer remote address.
-            // for now, returning the first one
-            Some(self.local_net_addresses[0])
+    fn add_local_address(&mut self, address: NetAddress) {
+        if !self.local_net_addresses.contains(&address) {
+            self.local_net_addresses.push(address);
         }
     }
 
+    /// Returns the local address to advertise to a peer with IP `peer_ip`, preferring an address of the same IP family
+    pub fn best_local_address(&mut self, peer_ip: IpAddr) -> Option<NetAddress> {
+        let peer_is_ipv4 = match peer_ip {
+            IpAddr::V4(_) => true,
+            // A peer connected to a dual-stack listener appears as an IPv4-mapped IPv6 address
+            IpAddr::V6(ip) => ip.to_ipv4_mapped().is_some(),
+        };
+        self.local_net_addresses
+            .iter()
+            .find(|address| address.ip.is_ipv4() == peer_is_ipv4)
+            .or_else(|| self.local_net_addresses.first())
+            .copied()
+    }
+
     pub fn add_address(&mut self, address: NetAddress) {
         if address.ip.is_loopback() || address.ip.is_unspecified() {
             debug!("[Address manager] skipping local address {}", address.ip);
@@ -360,7 +374,7 @@ mod address_store_with_cache {
         use kaspa_consensus_core::config::{params::SIMNET_PARAMS, Config};
         use kaspa_database::create_temp_db;
         use kaspa_database::prelude::ConnBuilder;
-        use kaspa_utils::networking::IpAddress;
+        use kaspa_utils::networking::{ContextualNetAddress, IpAddress};
         use statest::ks::KSTest;
         use statrs::distribution::Uniform;
         use std::net::{IpAddr, Ipv6Addr};
@@ -377,6 +391,26 @@ mod address_store_with_cache {
             assert_eq!(iter.count(), 0);
         }
 
+        #[test]
+        fn test_best_local_address() {
+            let db = create_temp_db!(ConnBuilder::default());
+            let mut config = Config::new(SIMNET_PARAMS);
+            config.p2p_listen_addresses = vec![
+                ContextualNetAddress::from_str("10.0.0.1").unwrap(),
+                ContextualNetAddress::from_str("1.2.3.4").unwrap(),
+                ContextualNetAddress::from_str("[2a00:1450::1]:16000").unwrap(),
+            ];
+            let am = AddressManager::new(Arc::new(config), db.1);
+            let mut am_guard = am.lock();
+
+            // The non-routable listen address is not advertised
+            let ipv4_address = NetAddress::from_str(&format!("1.2.3.4:{}", SIMNET_PARAMS.default_p2p_port())).unwrap();
+            let ipv6_address = NetAddress::from_str("[2a00:1450::1]:16000").unwrap();
+            assert_eq!(am_guard.best_local_address(IpAddr::from_str("5.6.7.8").unwrap()), Some(ipv4_address));
+            assert_eq!(am_guard.best_local_address(IpAddr::from_str("::ffff:5.6.7.8").unwrap()), Some(ipv4_address));
+            assert_eq!(am_guard.best_local_address(IpAddr::from_str("2a00:1450::2").unwrap()), Some(ipv6_address));
+        }
+
         #[test]
         fn test_network_distribution_weighting() {
             kaspa_core::log::try_init_logger("info");
@@ -0,0 +1,158 @@
+use std::{
+    io,
+    net::{SocketAddr, ToSocketAddrs},
+    sync::Arc,
+};
+
+use kaspa_addressmanager::NetAddress;
+use kaspa_core::{info, warn};
+use rand::{seq::SliceRandom, thread_rng};
+
+/// Resolves a DNS seeder hostname into a list of socket addresses
+pub trait DnsResolver: Send + Sync {
+    fn resolve(&self, host: &str, port: u16) -> io::Result<Vec<SocketAddr>>;
+}
+
+/// A [`DnsResolver`] relying on the resolver of the operating system
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemDnsResolver;
+
+impl DnsResolver for SystemDnsResolver {
+    fn resolve(&self, host: &str, port: u16) -> io::Result<Vec<SocketAddr>> {
+        Ok((host, port).to_socket_addrs()?.collect())
+    }
+}
+
+/// Queries a list of DNS seeders for peer addresses
+pub struct DnsSeeder {
+    seeders: Vec<String>,
+    default_port: u16,
+    resolver: Arc<dyn DnsResolver>,
+}
+
+impl DnsSeeder {
+    pub fn new(seeders: Vec<String>, default_port: u16) -> Self {
+        Self::with_resolver(seeders, default_port, Arc::new(SystemDnsResolver))
+    }
+
+    pub fn with_resolver(seeders: Vec<String>, default_port: u16, resolver: Arc<dyn DnsResolver>) -> Self {
+        Self { seeders, default_port, resolver }
+    }
+
+    /// A seeder with no DNS seeders, i.e., DNS seeding is disabled
+    pub fn disabled() -> Self {
+        Self::new(vec![], 0)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.seeders.is_empty()
+    }
+
+    /// Queries the seeders in random order until at least `min_addresses_to_fetch` addresses were retrieved
+    /// or all seeders were queried.
+    ///
+    /// Note: this is a blocking i/o operation
+    pub fn seed(&self, mut min_addresses_to_fetch: usize) -> Vec<NetAddress> {
+        let mut addresses = Vec::new();
+        for seeder in self.seeders.choose_multiple(&mut thread_rng(), self.seeders.len()) {
+            info!("Querying DNS seeder {}", seeder);
+            // Since the DNS lookup protocol doesn't come with a port, we must assume that the default port is used.
+            let addrs = match self.resolver.resolve(seeder, self.default_port) {
+                Ok(addrs) => addrs,
+                Err(e) => {
+                    warn!("Error connecting to DNS seeder {}: {}", seeder, e);
+                    continue;
+                }
+            };
+
+            let addrs_len = addrs.len();
+            info!("Retrieved {} addresses from DNS seeder {}", addrs_len, seeder);
+            addresses.extend(addrs.into_iter().map(|addr| NetAddress::new(addr.ip().into(), addr.port())));
+
+            if addrs_len >= min_addresses_to_fetch {
+                break;
+            } else {
+                min_addresses_to_fetch -= addrs_len;
+            }
+        }
+        addresses
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use parking_lot::Mutex;
+    use std::{
+        collections::{HashMap, HashSet},
+        net::{IpAddr, Ipv4Addr},
+    };
+
+    /// A local stand-in for DNS, resolving hosts from a fixed table and recording the queried hosts
+    struct LocalResolver {
+        hosts: HashMap<String, Vec<IpAddr>>,
+        queried: Mutex<Vec<String>>,
+    }
+
+    impl LocalResolver {
+        fn new(hosts: &[(&str, &[[u8; 4]])]) -> Self {
+            Self {
+                hosts: hosts
+                    .iter()
+                    .map(|(host, ips)| (host.to_string(), ips.iter().map(|&ip| IpAddr::V4(Ipv4Addr::from(ip))).collect()))
+                    .collect(),
+                queried: Default::default(),
+            }
+        }
+
+        /// Returns the hosts queried since the last call, in query order
+        fn take_queried(&self) -> Vec<String> {
+            std::mem::take(&mut *self.queried.lock())
+        }
+
+        fn addresses(&self, host: &str, port: u16) -> Vec<NetAddress> {
+            self.hosts[host].iter().map(|&ip| NetAddress::new(ip.into(), port)).collect()
+        }
+    }
+
+    impl DnsResolver for LocalResolver {
+        fn resolve(&self, host: &str, port: u16) -> io::Result<Vec<SocketAddr>> {
+            self.queried.lock().push(host.to_string());
+            match self.hosts.get(host) {
+                Some(ips) => Ok(ips.iter().map(|&ip| SocketAddr::new(ip, port)).collect()),
+                None => Err(io::Error::new(io::ErrorKind::NotFound, format!("unknown host {host}"))),
+            }
+        }
+    }
+
+    #[test]
+    fn test_dns_seed() {
+        let resolver = Arc::new(LocalResolver::new(&[
+            ("seeder1.local", &[[1, 1, 1, 1], [1, 1, 1, 2]]),
+            ("seeder2.local", &[[2, 2, 2, 1], [2, 2, 2, 2], [2, 2, 2, 3]]),
+        ]));
+
+        // All seeders must be queried when the requested amount cannot be satisfied, failing seeders are skipped
+        let seeders = vec!["seeder1.local".to_string(), "missing.local".to_string(), "seeder2.local".to_string()];
+        let seeder = DnsSeeder::with_resolver(seeders, 16111, resolver.clone());
+        let addresses = seeder.seed(10);
+        let mut queried = resolver.take_queried();
+        queried.sort();
+        assert_eq!(queried, vec!["missing.local", "seeder1.local", "seeder2.local"]);
+        let expected = [resolver.addresses("seeder1.local", 16111), resolver.addresses("seeder2.local", 16111)].concat();
+        assert_eq!(addresses.len(), expected.len());
+        assert_eq!(addresses.into_iter().collect::<HashSet<_>>(), expected.into_iter().collect::<HashSet<_>>());
+
+        // A single seeder is enough to satisfy the request, so querying stops after the first one
+        let seeder = DnsSeeder::with_resolver(vec!["seeder1.local".to_string(), "seeder2.local".to_string()], 16111, resolver.clone());
+        let addresses = seeder.seed(1);
+        let queried = resolver.take_queried();
+        assert_eq!(queried.len(), 1);
+        assert_eq!(addresses, resolver.addresses(&queried[0], 16111));
+
+        let seeder = DnsSeeder::with_resolver(vec![], 16111, resolver.clone());
+        assert!(seeder.is_empty());
+        assert!(seeder.seed(10).is_empty());
+        assert!(resolver.take_queried().is_empty());
+    }
+}
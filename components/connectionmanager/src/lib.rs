@@ -1,11 +1,15 @@
+pub mod dns;
+
 use std::{
     cmp::min,
     collections::{HashMap, HashSet},
-    net::{IpAddr, SocketAddr, ToSocketAddrs},
+    net::{IpAddr, SocketAddr},
     sync::Arc,
     time::{Duration, SystemTime},
 };
 
+use async_trait::async_trait;
+use dns::DnsSeeder;
 use duration_string::DurationString;
 use futures_util::future::join_all;
 use itertools::Itertools;
@@ -24,12 +28,24 @@ use tokio::{
     time::{interval, MissedTickBehavior},
 };
 
+/// A source of peer addresses which is queried over the network, for instance
+/// the `get_peer_addresses` RPC method of another node
+#[async_trait]
+pub trait AddressSeeder: Send + Sync {
+    /// A description of the seed source, used for logging
+    fn name(&self) -> String;
+
+    async fn seed(&self) -> Result<Vec<NetAddress>, String>;
+}
+
+pub type DynAddressSeeder = Arc<dyn AddressSeeder>;
+
 pub struct ConnectionManager {
     p2p_adaptor: Arc<kaspa_p2p_lib::Adaptor>,
     outbound_target: usize,
     inbound_limit: usize,
-    dns_seeders: &'static [&'static str],
-    default_port: u16,
+    dns_seeder: Arc<DnsSeeder>,
+    address_seeder: Option<DynAddressSeeder>,
     address_manager: Arc<ParkingLotMutex<AddressManager>>,
     connection_requests: TokioMutex<HashMap<SocketAddr, ConnectionRequest>>,
     force_next_iteration: UnboundedSender<()>,
@@ -54,8 +70,8 @@ impl ConnectionManager {
         p2p_adaptor: Arc<kaspa_p2p_lib::Adaptor>,
         outbound_target: usize,
         inbound_limit: usize,
-        dns_seeders: &'static [&'static str],
-        default_port: u16,
+        dns_seeder: Arc<DnsSeeder>,
+        address_seeder: Option<DynAddressSeeder>,
         address_manager: Arc<ParkingLotMutex<AddressManager>>,
     ) -> Arc<Self> {
         let (tx, rx) = unbounded_channel::<()>();
@@ -67,8 +83,8 @@ impl ConnectionManager {
             connection_requests: Default::default(),
             force_next_iteration: tx,
             shutdown_signal: SingleTrigger::new(),
-            dns_seeders,
-            default_port,
+            dns_seeder,
+            address_seeder,
         });
         manager.clone().start_event_loop(rx);
         manager.force_next_iteration.send(()).unwrap();
@@ -221,13 +237,40 @@ impl ConnectionManager {
             }
         }
 
-        if missing_connections > 0 && !self.dns_seeders.is_empty() {
-            let cmgr = self.clone();
+        if missing_connections == 0 {
+            return;
+        }
+
+        let mut seeded = 0;
+        if !self.dns_seeder.is_empty() {
+            let dns_seeder = self.dns_seeder.clone();
             // DNS lookup is a blocking i/o operation, so we spawn it as a blocking task
-            let _ = tokio::task::spawn_blocking(move || {
-                cmgr.dns_seed(missing_connections); //TODO: Consider putting a number higher than `missing_connections`.
-            })
-            .await;
+            //TODO: Consider putting a number higher than `missing_connections`.
+            if let Ok(addresses) = tokio::task::spawn_blocking(move || dns_seeder.seed(missing_connections)).await {
+                seeded = addresses.len();
+                self.add_seeded_addresses(addresses);
+            }
+        }
+
+        // The address seeder is a fallback, only queried when DNS seeding yields no addresses
+        if seeded == 0 {
+            if let Some(address_seeder) = self.address_seeder.as_ref() {
+                info!("Querying peer addresses from {}", address_seeder.name());
+                match address_seeder.seed().await {
+                    Ok(addresses) => {
+                        info!("Retrieved {} addresses from {}", addresses.len(), address_seeder.name());
+                        self.add_seeded_addresses(addresses);
+                    }
+                    Err(err) => warn!("Error querying peer addresses from {}: {}", address_seeder.name(), err),
+                }
+            }
+        }
+    }
+
+    fn add_seeded_addresses(&self, addresses: Vec<NetAddress>) {
+        let mut amgr_lock = self.address_manager.lock();
+        for address in addresses {
+            amgr_lock.add_address(address);
         }
     }
 
@@ -246,34 +289,6 @@ impl ConnectionManager {
         join_all(futures).await;
     }
 
-    fn dns_seed(self: &Arc<Self>, mut min_addresses_to_fetch: usize) {
-        let shuffled_dns_seeders = self.dns_seeders.choose_multiple(&mut thread_rng(), self.dns_seeders.len());
-        for &seeder in shuffled_dns_seeders {
-            info!("Querying DNS seeder {}", seeder);
-            // Since the DNS lookup protocol doesn't come with a port, we must assume that the default port is used.
-            let addrs = match (seeder, self.default_port).to_socket_addrs() {
-                Ok(addrs) => addrs,
-                Err(e) => {
-                    warn!("Error connecting to DNS seeder {}: {}", seeder, e);
-                    continue;
-                }
-            };
-
-            let addrs_len = addrs.len();
-            info!("Retrieved {} addresses from DNS seeder {}", addrs_len, seeder);
-            let mut amgr_lock = self.address_manager.lock();
-            for addr in addrs {
-                amgr_lock.add_address(NetAddress::new(addr.ip().into(), addr.port()));
-            }
-
-            if addrs_len >= min_addresses_to_fetch {
-                break;
-            } else {
-                min_addresses_to_fetch -= addrs_len;
-            }
-        }
-    }
-
     /// Bans the given IP and disconnects from all the peers with that IP.
     ///
     /// _GO-KASPAD: BanByIP_
@@ -10,9 +10,11 @@ use kaspa_consensus_core::{
     blockstatus::BlockStatus,
     errors::consensus::ConsensusResult,
     header::Header,
+    pipeline_queues::PipelineQueueDepths,
     pruning::{PruningPointProof, PruningPointTrustedData, PruningPointsList},
+    store_stats::StoreStats,
     trusted::{ExternalGhostdagData, TrustedBlock},
-    tx::{Transaction, TransactionOutpoint, UtxoEntry},
+    tx::{Transaction, TransactionId, TransactionOutpoint, UtxoEntry},
     BlockHashSet, ChainPath, Hash,
 };
 use kaspa_utils::sync::rwlock::*;
@@ -95,6 +97,12 @@ impl ConsensusInstance {
         let g = self.session_lock.read_owned().await;
         ConsensusSessionOwned::new(g, self.consensus.clone())
     }
+
+    /// Returns the depths of the consensus pipeline queues. No session is acquired, so this remains available
+    /// for diagnosing a pipeline stalled while a session is held
+    pub fn pipeline_queue_depths(&self) -> PipelineQueueDepths {
+        self.consensus.get_pipeline_queue_depths()
+    }
 }
 
 pub struct ConsensusSessionBlocking<'a> {
@@ -212,6 +220,14 @@ impl ConsensusSessionOwned {
         self.clone().spawn_blocking(move |c| c.get_virtual_utxos(from_outpoint, chunk_size, skip_first)).await
     }
 
+    pub async fn async_get_virtual_utxos_by_outpoints(&self, outpoints: Vec<TransactionOutpoint>) -> Vec<Option<UtxoEntry>> {
+        self.clone().spawn_blocking(move |c| c.get_virtual_utxos_by_outpoints(&outpoints)).await
+    }
+
+    pub async fn async_get_store_stats(&self) -> ConsensusResult<Vec<StoreStats>> {
+        self.clone().spawn_blocking(|c| c.get_store_stats()).await
+    }
+
     pub async fn async_get_tips(&self) -> Vec<Hash> {
         self.clone().spawn_blocking(|c| c.get_tips()).await
     }
@@ -232,10 +248,18 @@ impl ConsensusSessionOwned {
         self.clone().spawn_blocking(move |c| c.get_header(hash)).await
     }
 
+    pub async fn async_get_headers(&self, start_hash: Hash, limit: usize, is_ascending: bool) -> ConsensusResult<Vec<Arc<Header>>> {
+        self.clone().spawn_blocking(move |c| c.get_headers(start_hash, limit, is_ascending)).await
+    }
+
     pub async fn async_get_headers_selected_tip(&self) -> Hash {
         self.clone().spawn_blocking(|c| c.get_headers_selected_tip()).await
     }
 
+    pub async fn async_get_daa_score_timestamp_estimates(&self, daa_scores: Vec<u64>) -> Vec<u64> {
+        self.clone().spawn_blocking(move |c| c.get_daa_score_timestamp_estimates(daa_scores)).await
+    }
+
     /// Returns the anticone of block `hash` from the POV of `context`, i.e. `anticone(hash) ∩ past(context)`.
     /// Since this might be an expensive operation for deep blocks, we allow the caller to specify a limit
     /// `max_traversal_allowed` on the maximum amount of blocks to traverse for obtaining the answer
@@ -248,8 +272,8 @@ impl ConsensusSessionOwned {
         self.clone().spawn_blocking(move |c| c.get_anticone_from_pov(hash, context, max_traversal_allowed)).await
     }
 
-    pub async fn async_get_anticone(&self, hash: Hash) -> ConsensusResult<Vec<Hash>> {
-        self.clone().spawn_blocking(move |c| c.get_anticone(hash)).await
+    pub async fn async_get_anticone(&self, hash: Hash, max_traversal_allowed: Option<u64>) -> ConsensusResult<Vec<Hash>> {
+        self.clone().spawn_blocking(move |c| c.get_anticone(hash, max_traversal_allowed)).await
     }
 
     pub async fn async_get_pruning_point_proof(&self) -> Arc<PruningPointProof> {
@@ -315,6 +339,10 @@ impl ConsensusSessionOwned {
         self.clone().spawn_blocking(move |c| c.is_chain_block(hash)).await
     }
 
+    pub async fn async_get_accepted_transaction_ids(&self, hash: Hash) -> ConsensusResult<Vec<TransactionId>> {
+        self.clone().spawn_blocking(move |c| c.get_accepted_transaction_ids(hash)).await
+    }
+
     pub async fn async_get_pruning_point_utxos(
         &self,
         expected_pruning_point: Hash,
@@ -366,6 +394,10 @@ impl ConsensusSessionOwned {
     pub async fn async_finality_point(&self) -> Hash {
         self.clone().spawn_blocking(move |c| c.finality_point()).await
     }
+
+    pub async fn async_resolve_finality_conflict(&self, finality_block_hash: Hash) -> ConsensusResult<()> {
+        self.clone().spawn_blocking(move |c| c.resolve_finality_conflict(finality_block_hash)).await
+    }
 }
 
 pub type ConsensusProxy = ConsensusSessionOwned;
@@ -16,9 +16,11 @@ use crate::{
         tx::TxResult,
     },
     header::Header,
+    pipeline_queues::PipelineQueueDepths,
     pruning::{PruningPointProof, PruningPointTrustedData, PruningPointsList},
+    store_stats::StoreStats,
     trusted::{ExternalGhostdagData, TrustedBlock},
-    tx::{MutableTransaction, Transaction, TransactionOutpoint, UtxoEntry},
+    tx::{MutableTransaction, Transaction, TransactionId, TransactionOutpoint, UtxoEntry},
     BlockHashSet, ChainPath,
 };
 use kaspa_hashes::Hash;
@@ -89,6 +91,15 @@ pub trait ConsensusApi: Send + Sync {
         unimplemented!()
     }
 
+    fn get_pipeline_queue_depths(&self) -> PipelineQueueDepths {
+        unimplemented!()
+    }
+
+    /// Returns approximate storage and cache statistics of the consensus stores, ordered by store prefix
+    fn get_store_stats(&self) -> ConsensusResult<Vec<StoreStats>> {
+        unimplemented!()
+    }
+
     fn get_virtual_chain_from_block(&self, hash: Hash) -> ConsensusResult<ChainPath> {
         unimplemented!()
     }
@@ -106,6 +117,12 @@ pub trait ConsensusApi: Send + Sync {
         unimplemented!()
     }
 
+    /// Returns the virtual UTXO entry of each outpoint, or `None` if the outpoint is spent or unknown.
+    /// All the entries are read from the same virtual UTXO set snapshot.
+    fn get_virtual_utxos_by_outpoints(&self, outpoints: &[TransactionOutpoint]) -> Vec<Option<UtxoEntry>> {
+        unimplemented!()
+    }
+
     fn get_tips(&self) -> Vec<Hash> {
         unimplemented!()
     }
@@ -150,10 +167,23 @@ pub trait ConsensusApi: Send + Sync {
         unimplemented!()
     }
 
+    /// Returns up to `limit` headers starting at (and including) `start_hash`. Descending order follows the
+    /// selected parent chain of `start_hash`, ascending order follows the virtual selected chain from the first
+    /// chain block in the future of `start_hash` up to the sink.
+    fn get_headers(&self, start_hash: Hash, limit: usize, is_ascending: bool) -> ConsensusResult<Vec<Arc<Header>>> {
+        unimplemented!()
+    }
+
     fn get_headers_selected_tip(&self) -> Hash {
         unimplemented!()
     }
 
+    /// Returns an estimate of the timestamp of each DAA score, i.e., the timestamp of the last selected chain block
+    /// not above it. For DAA scores below the retained chain data, the estimate is interpolated from persistent samples.
+    fn get_daa_score_timestamp_estimates(&self, daa_scores: Vec<u64>) -> Vec<u64> {
+        unimplemented!()
+    }
+
     /// Returns the anticone of block `hash` from the POV of `context`, i.e. `anticone(hash) ∩ past(context)`.
     /// Since this might be an expensive operation for deep blocks, we allow the caller to specify a limit
     /// `max_traversal_allowed` on the maximum amount of blocks to traverse for obtaining the answer
@@ -161,7 +191,7 @@ pub trait ConsensusApi: Send + Sync {
         unimplemented!()
     }
 
-    fn get_anticone(&self, hash: Hash) -> ConsensusResult<Vec<Hash>> {
+    fn get_anticone(&self, hash: Hash, max_traversal_allowed: Option<u64>) -> ConsensusResult<Vec<Hash>> {
         unimplemented!()
     }
 
@@ -224,6 +254,11 @@ pub trait ConsensusApi: Send + Sync {
         unimplemented!()
     }
 
+    /// Returns the sorted ids of the transactions accepted by the chain block `hash`, i.e., the leaves of its `accepted_id_merkle_root`
+    fn get_accepted_transaction_ids(&self, hash: Hash) -> ConsensusResult<Vec<TransactionId>> {
+        unimplemented!()
+    }
+
     fn get_pruning_point_utxos(
         &self,
         expected_pruning_point: Hash,
@@ -272,6 +307,11 @@ pub trait ConsensusApi: Send + Sync {
     fn finality_point(&self) -> Hash {
         unimplemented!()
     }
+
+    /// Resolves pending finality conflicts in favor of the branch containing `finality_block_hash`
+    fn resolve_finality_conflict(&self, finality_block_hash: Hash) -> ConsensusResult<()> {
+        unimplemented!()
+    }
 }
 
 pub type DynConsensus = Arc<dyn ConsensusApi>;
@@ -52,10 +52,28 @@ pub struct Config {
     /// Allow mainnet mining. Until a stable Beta version we keep this option off by default
     pub enable_mainnet_mining: bool,
 
+    /// Do not request or accept transactions from remote peers, and advertise so during the handshake
+    pub blocks_only: bool,
+
+    /// Reject transactions submitted via RPC
+    pub disable_rpc_submit: bool,
+
+    /// Maximum number of RPC connections from a single IP address (0 for no limit)
+    pub rpc_max_connections_per_ip: usize,
+
+    /// Maximum number of requests per second issued by a single RPC connection (0 for no limit)
+    pub rpc_max_requests_per_second: u32,
+
+    /// Maximum number of requests of a single RPC connection processed concurrently (0 for no limit)
+    pub rpc_max_concurrent_requests: usize,
+
+    /// Maximum number of notification subscriptions held by a single RPC connection (0 for no limit)
+    pub rpc_max_subscriptions: usize,
+
     pub user_agent_comments: Vec<String>,
 
-    // If undefined, sets it to 0.0.0.0
-    pub p2p_listen_address: ContextualNetAddress,
+    // If undefined, sets it to [0.0.0.0]
+    pub p2p_listen_addresses: Vec<ContextualNetAddress>,
 
     pub externalip: Option<IpAddress>,
 
@@ -79,9 +97,15 @@ impl Config {
             unsafe_rpc: false,
             enable_unsynced_mining: false,
             enable_mainnet_mining: false,
+            blocks_only: false,
+            disable_rpc_submit: false,
+            rpc_max_connections_per_ip: 0,
+            rpc_max_requests_per_second: 0,
+            rpc_max_concurrent_requests: 0,
+            rpc_max_subscriptions: 0,
             user_agent_comments: Default::default(),
             externalip: None,
-            p2p_listen_address: ContextualNetAddress::unspecified(),
+            p2p_listen_addresses: vec![ContextualNetAddress::unspecified()],
 
             #[cfg(feature = "devnet-prealloc")]
             initial_utxo_set: Default::default(),
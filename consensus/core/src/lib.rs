@@ -23,8 +23,10 @@ pub mod mass;
 pub mod merkle;
 pub mod muhash;
 pub mod network;
+pub mod pipeline_queues;
 pub mod pruning;
 pub mod sign;
+pub mod store_stats;
 pub mod subnets;
 pub mod trusted;
 pub mod tx;
@@ -1,11 +1,28 @@
-use crate::{hashing, tx::Transaction};
+use crate::{
+    hashing,
+    tx::{Transaction, TransactionId},
+};
 use kaspa_hashes::Hash;
-use kaspa_merkle::calc_merkle_root;
+use kaspa_merkle::{calc_merkle_root, create_merkle_proof, MerkleProof};
 
 pub fn calc_hash_merkle_root<'a>(txs: impl ExactSizeIterator<Item = &'a Transaction>) -> Hash {
     calc_merkle_root(txs.map(hashing::tx::hash))
 }
 
+/// Builds the proof of inclusion of the transaction with id `tx_id` in the `hash_merkle_root` of a block holding `txs`.
+/// The proof leaf is the transaction hash.
+pub fn create_hash_merkle_proof(txs: &[Transaction], tx_id: TransactionId) -> Option<MerkleProof> {
+    let index = txs.iter().position(|tx| tx.id() == tx_id)?;
+    create_merkle_proof(txs.iter().map(hashing::tx::hash), index)
+}
+
+/// Builds the proof of inclusion of `tx_id` in an `accepted_id_merkle_root` committing to the sorted `accepted_tx_ids`.
+/// The proof leaf is the transaction id.
+pub fn create_accepted_id_merkle_proof(accepted_tx_ids: &[TransactionId], tx_id: TransactionId) -> Option<MerkleProof> {
+    let index = accepted_tx_ids.binary_search(&tx_id).ok()?;
+    create_merkle_proof(accepted_tx_ids.iter().copied(), index)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::merkle::calc_hash_merkle_root;
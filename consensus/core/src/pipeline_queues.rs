@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+/// The number of messages waiting in the queue of each consensus pipeline processor
+#[derive(Clone, Debug, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct PipelineQueueDepths {
+    pub header_processor: usize,
+    pub body_processor: usize,
+    pub virtual_processor: usize,
+    pub pruning_processor: usize,
+}
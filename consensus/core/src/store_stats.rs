@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+/// Approximate storage and cache statistics of a single consensus store
+#[derive(Clone, Debug, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct StoreStats {
+    /// The database prefix identifying the store
+    pub prefix: u8,
+    pub name: String,
+    /// Approximate size in bytes of the data persisted to disk
+    pub size: u64,
+    /// Approximate number of live entries persisted to disk
+    pub entries: u64,
+    pub cache_hits: u64,
+    pub cache_misses: u64,
+}
@@ -15,12 +15,14 @@ use crate::{
         stores::{
             acceptance_data::AcceptanceDataStoreReader,
             block_transactions::BlockTransactionsStoreReader,
+            daa_score_timestamps::{daa_score_sample_bucket, DaaScoreTimestamp, DaaScoreTimestampStoreReader},
             ghostdag::{GhostdagData, GhostdagStoreReader},
             headers::HeaderStoreReader,
             headers_selected_tip::HeadersSelectedTipStoreReader,
             past_pruning_points::PastPruningPointsStoreReader,
             pruning::PruningStoreReader,
             relations::RelationsStoreReader,
+            selected_chain::SelectedChainStoreReader,
             statuses::StatusesStoreReader,
             tips::TipsStoreReader,
             utxo_set::{UtxoSetStore, UtxoSetStoreReader},
@@ -54,9 +56,11 @@ use kaspa_consensus_core::{
     errors::{difficulty::DifficultyError, pruning::PruningImportError},
     header::Header,
     muhash::MuHashExtensions,
+    pipeline_queues::PipelineQueueDepths,
     pruning::{PruningPointProof, PruningPointTrustedData, PruningPointsList},
+    store_stats::StoreStats,
     trusted::{ExternalGhostdagData, TrustedBlock},
-    tx::{MutableTransaction, Transaction, TransactionOutpoint, UtxoEntry},
+    tx::{MutableTransaction, Transaction, TransactionId, TransactionOutpoint, UtxoEntry},
     BlockHashSet, ChainPath,
 };
 use kaspa_consensus_notify::root::ConsensusNotificationRoot;
@@ -67,7 +71,7 @@ use crossbeam_channel::{
 use itertools::Itertools;
 use kaspa_consensusmanager::{SessionLock, SessionReadGuard};
 
-use kaspa_database::prelude::StoreResultExtensions;
+use kaspa_database::{prelude::StoreResultExtensions, stats::store_prefix_stats};
 use kaspa_hashes::Hash;
 use kaspa_muhash::MuHash;
 
@@ -350,6 +354,31 @@ impl Consensus {
         };
         Ok(self.services.window_manager.estimate_network_hashes_per_second(window)?)
     }
+
+    /// Estimates the timestamp of a DAA score which is below the retained selected chain data by linearly interpolating
+    /// between the surrounding DAA score samples. `upper_bound` is the lowest retained chain block, used if no upper sample exists
+    fn estimate_timestamp_from_daa_score_samples(&self, daa_score: u64, upper_bound: DaaScoreTimestamp) -> u64 {
+        let sample = |bucket| self.daa_score_timestamp_store.get(bucket).unwrap_option();
+        let bucket = daa_score_sample_bucket(daa_score);
+        let (lower, upper) = match sample(bucket) {
+            Some(current) if current.daa_score <= daa_score => (Some(current), sample(bucket + 1)),
+            current => (bucket.checked_sub(1).and_then(sample), current),
+        };
+        // Samples are missing for data pruned before the node synced, in which case genesis is the best known lower bound
+        let lower =
+            lower.unwrap_or(DaaScoreTimestamp { daa_score: self.config.genesis.daa_score, timestamp: self.config.genesis.timestamp });
+        let upper = upper.unwrap_or(upper_bound);
+
+        if daa_score <= lower.daa_score || upper.daa_score <= lower.daa_score {
+            return lower.timestamp;
+        }
+        if daa_score >= upper.daa_score {
+            return upper.timestamp;
+        }
+        let elapsed = upper.timestamp.saturating_sub(lower.timestamp) as u128 * (daa_score - lower.daa_score) as u128
+            / (upper.daa_score - lower.daa_score) as u128;
+        lower.timestamp + elapsed as u64
+    }
 }
 
 impl ConsensusApi for Consensus {
@@ -431,6 +460,30 @@ impl ConsensusApi for Consensus {
         BlockCount { header_count: count, block_count: count }
     }
 
+    fn get_pipeline_queue_depths(&self) -> PipelineQueueDepths {
+        PipelineQueueDepths {
+            header_processor: self.header_processor.queue_len(),
+            body_processor: self.body_processor.queue_len(),
+            virtual_processor: self.virtual_processor.queue_len(),
+            pruning_processor: self.pruning_processor.queue_len(),
+        }
+    }
+
+    fn get_store_stats(&self) -> ConsensusResult<Vec<StoreStats>> {
+        let stats = store_prefix_stats(&self.db).map_err(|_| ConsensusError::General("failed reading the consensus DB files"))?;
+        Ok(stats
+            .into_iter()
+            .map(|s| StoreStats {
+                prefix: s.prefix,
+                name: s.name,
+                size: s.size,
+                entries: s.entries,
+                cache_hits: s.cache_hits,
+                cache_misses: s.cache_misses,
+            })
+            .collect())
+    }
+
     fn is_nearly_synced(&self) -> bool {
         // See comment within `config.is_nearly_synced`
         let sink = self.get_sink();
@@ -462,6 +515,14 @@ impl ConsensusApi for Consensus {
         iter.map(|item| item.unwrap()).collect()
     }
 
+    fn get_virtual_utxos_by_outpoints(&self, outpoints: &[TransactionOutpoint]) -> Vec<Option<UtxoEntry>> {
+        let virtual_stores = self.virtual_stores.read();
+        outpoints
+            .iter()
+            .map(|outpoint| virtual_stores.utxo_set.get(outpoint).unwrap_option().map(|entry| entry.as_ref().clone()))
+            .collect()
+    }
+
     fn get_tips(&self) -> Vec<Hash> {
         self.body_tips().iter().copied().collect_vec()
     }
@@ -562,21 +623,110 @@ impl ConsensusApi for Consensus {
         Ok(self.headers_store.get_header(hash).unwrap())
     }
 
+    fn get_headers(&self, start_hash: Hash, limit: usize, is_ascending: bool) -> ConsensusResult<Vec<Arc<Header>>> {
+        self.validate_block_exists(start_hash)?;
+        if limit == 0 {
+            return Ok(vec![]);
+        }
+
+        let mut headers = vec![self.headers_store.get_header(start_hash).unwrap()];
+        if is_ascending {
+            // Keep the pruning point and selected chain read guards so that the chain is not modified while traversed
+            let pruning_point_read = self.pruning_point_store.read();
+            let selected_chain_read = self.selected_chain_store.read();
+            let (tip_index, _) = selected_chain_read.get_tip().unwrap();
+            let first_index = match selected_chain_read.get_by_hash(start_hash).unwrap_option() {
+                Some(index) => index + 1,
+                None => {
+                    // Binary search for the first chain block in the future of `start_hash`. The search is
+                    // valid since a chain block being in the future of `start_hash` implies its chain descendants are too
+                    let (mut low, mut high) =
+                        (selected_chain_read.get_by_hash(pruning_point_read.pruning_point().unwrap()).unwrap(), tip_index + 1);
+                    while low < high {
+                        let mid = low + (high - low) / 2;
+                        let chain_block = selected_chain_read.get_by_index(mid).unwrap();
+                        if self.services.reachability_service.is_dag_ancestor_of(start_hash, chain_block) {
+                            high = mid;
+                        } else {
+                            low = mid + 1;
+                        }
+                    }
+                    low
+                }
+            };
+            headers.extend(
+                (first_index..=tip_index)
+                    .take(limit - 1)
+                    .map(|index| self.headers_store.get_header(selected_chain_read.get_by_index(index).unwrap()).unwrap()),
+            );
+        } else {
+            let mut current = start_hash;
+            while headers.len() < limit {
+                // Stop at the point where the selected parent data or header is missing, i.e., below the pruning point
+                let Some(parent) = self.ghostdag_primary_store.get_selected_parent(current).unwrap_option() else {
+                    break;
+                };
+                let Some(header) = self.headers_store.get_header(parent).unwrap_option() else {
+                    break;
+                };
+                headers.push(header);
+                current = parent;
+            }
+        }
+        Ok(headers)
+    }
+
     fn get_headers_selected_tip(&self) -> Hash {
         self.headers_selected_tip_store.read().get().unwrap().hash
     }
 
+    fn get_daa_score_timestamp_estimates(&self, daa_scores: Vec<u64>) -> Vec<u64> {
+        // Keep the pruning point and selected chain read guards so that the chain is not modified or pruned while searched
+        let pruning_point_read = self.pruning_point_store.read();
+        let selected_chain_read = self.selected_chain_store.read();
+        let (tip_index, _) = selected_chain_read.get_tip().unwrap();
+        // The selected chain index is retained from the pruning point and up, or entirely on archival nodes
+        let low_index = if self.config.is_archival && selected_chain_read.get_by_index(0).unwrap_option().is_some() {
+            0
+        } else {
+            selected_chain_read.get_by_hash(pruning_point_read.pruning_point().unwrap()).unwrap()
+        };
+        let chain_data = |index| self.headers_store.get_compact_header_data(selected_chain_read.get_by_index(index).unwrap()).unwrap();
+        let lowest = chain_data(low_index);
+
+        daa_scores
+            .into_iter()
+            .map(|daa_score| {
+                if daa_score < lowest.daa_score {
+                    let upper_bound = DaaScoreTimestamp { daa_score: lowest.daa_score, timestamp: lowest.timestamp };
+                    return self.estimate_timestamp_from_daa_score_samples(daa_score, upper_bound);
+                }
+                // Binary search for the last chain block with a DAA score not above `daa_score`. Scores beyond the tip map to it
+                let (mut low, mut high) = (low_index, tip_index);
+                while low < high {
+                    let mid = low + (high - low + 1) / 2;
+                    if chain_data(mid).daa_score <= daa_score {
+                        low = mid;
+                    } else {
+                        high = mid - 1;
+                    }
+                }
+                chain_data(low).timestamp
+            })
+            .collect()
+    }
+
     fn get_anticone_from_pov(&self, hash: Hash, context: Hash, max_traversal_allowed: Option<u64>) -> ConsensusResult<Vec<Hash>> {
         self.validate_block_exists(hash)?;
         Ok(self.services.dag_traversal_manager.anticone(hash, std::iter::once(context), max_traversal_allowed)?)
     }
 
-    fn get_anticone(&self, hash: Hash) -> ConsensusResult<Vec<Hash>> {
+    fn get_anticone(&self, hash: Hash, max_traversal_allowed: Option<u64>) -> ConsensusResult<Vec<Hash>> {
         self.validate_block_exists(hash)?;
         Ok(self.services.dag_traversal_manager.anticone(
             hash,
             self.virtual_stores.read().state.get().unwrap().parents.iter().copied(),
-            None,
+            max_traversal_allowed,
         )?)
     }
 
@@ -680,6 +830,28 @@ impl ConsensusApi for Consensus {
         self.is_chain_ancestor_of(hash, self.get_sink())
     }
 
+    fn get_accepted_transaction_ids(&self, hash: Hash) -> ConsensusResult<Vec<TransactionId>> {
+        // Acceptance data of blocks which were reorged out of the chain is stale, hence we require a current chain block
+        if !self.is_chain_block(hash)? {
+            return Err(ConsensusError::General("the block is not on the virtual selected chain"));
+        }
+        let acceptance_data = self.acceptance_data_store.get(hash).unwrap_option().ok_or(ConsensusError::MissingData(hash))?;
+        // The coinbase of the selected parent is accepted as well, see `VirtualStateProcessor::calculate_utxo_state`
+        let selected_parent = self.ghostdag_primary_store.get_selected_parent(hash).unwrap();
+        let selected_parent_coinbase =
+            self.block_transactions_store.get(selected_parent).unwrap_option().ok_or(ConsensusError::MissingData(selected_parent))?[0]
+                .id();
+        let mut accepted_tx_ids = once(selected_parent_coinbase)
+            .chain(
+                acceptance_data
+                    .iter()
+                    .flat_map(|mergeset_block| mergeset_block.accepted_transactions.iter().map(|entry| entry.transaction_id)),
+            )
+            .collect_vec();
+        accepted_tx_ids.sort();
+        Ok(accepted_tx_ids)
+    }
+
     fn get_missing_block_body_hashes(&self, high: Hash) -> ConsensusResult<Vec<Hash>> {
         self.validate_block_exists(high)?;
         Ok(self.services.sync_manager.get_missing_block_body_hashes(high)?)
@@ -760,4 +932,9 @@ impl ConsensusApi for Consensus {
         self.virtual_processor
             .virtual_finality_point(&self.virtual_stores.read().state.get().unwrap().ghostdag_data, self.pruning_point())
     }
+
+    fn resolve_finality_conflict(&self, finality_block_hash: Hash) -> ConsensusResult<()> {
+        self.validate_block_exists(finality_block_hash)?;
+        self.virtual_processor.resolve_finality_conflict(finality_block_hash)
+    }
 }
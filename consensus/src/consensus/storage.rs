@@ -5,7 +5,9 @@ use crate::{
         block_transactions::DbBlockTransactionsStore,
         block_window_cache::BlockWindowCacheStore,
         daa::DbDaaStore,
+        daa_score_timestamps::DbDaaScoreTimestampStore,
         depth::DbDepthStore,
+        finality_conflicts::DbFinalityConflictsStore,
         ghostdag::{DbGhostdagStore, GhostdagData},
         headers::DbHeadersStore,
         headers_selected_tip::DbHeadersSelectedTipStore,
@@ -49,6 +51,7 @@ pub struct ConsensusStorage {
     pub pruning_utxoset_stores: Arc<RwLock<PruningUtxosetStores>>,
     pub virtual_stores: Arc<RwLock<VirtualStores>>,
     pub selected_chain_store: Arc<RwLock<DbSelectedChainStore>>,
+    pub finality_conflicts_store: Arc<RwLock<DbFinalityConflictsStore>>,
 
     // Append-only stores
     pub ghostdag_stores: Arc<Vec<Arc<DbGhostdagStore>>>,
@@ -56,6 +59,7 @@ pub struct ConsensusStorage {
     pub headers_store: Arc<DbHeadersStore>,
     pub block_transactions_store: Arc<DbBlockTransactionsStore>,
     pub past_pruning_points_store: Arc<DbPastPruningPointsStore>,
+    pub daa_score_timestamp_store: Arc<DbDaaScoreTimestampStore>,
     pub daa_excluded_store: Arc<DbDaaStore>,
     pub depth_store: Arc<DbDepthStore>,
 
@@ -140,6 +144,7 @@ impl ConsensusStorage {
         // Pruning
         let pruning_point_store = Arc::new(RwLock::new(DbPruningStore::new(db.clone())));
         let past_pruning_points_store = Arc::new(DbPastPruningPointsStore::new(db.clone(), 4));
+        let daa_score_timestamp_store = Arc::new(DbDaaScoreTimestampStore::new(db.clone(), noise(perf_params.header_data_cache_size)));
         let pruning_utxoset_stores =
             Arc::new(RwLock::new(PruningUtxosetStores::new(db.clone(), noise(perf_params.utxo_set_cache_size))));
 
@@ -153,6 +158,9 @@ impl ConsensusStorage {
         let headers_selected_tip_store = Arc::new(RwLock::new(DbHeadersSelectedTipStore::new(db.clone())));
         let body_tips_store = Arc::new(RwLock::new(DbTipsStore::new(db.clone())));
 
+        // Finality conflicts
+        let finality_conflicts_store = Arc::new(RwLock::new(DbFinalityConflictsStore::new(db.clone())));
+
         // Block windows
         let block_window_cache_for_difficulty = Arc::new(BlockWindowCacheStore::new(noise(perf_params.block_window_cache_size)));
         let block_window_cache_for_past_median_time = Arc::new(BlockWindowCacheStore::new(noise(perf_params.block_window_cache_size)));
@@ -180,8 +188,10 @@ impl ConsensusStorage {
             pruning_utxoset_stores,
             virtual_stores,
             selected_chain_store,
+            finality_conflicts_store,
             acceptance_data_store,
             past_pruning_points_store,
+            daa_score_timestamp_store,
             daa_excluded_store,
             depth_store,
             utxo_diffs_store,
@@ -0,0 +1,68 @@
+use std::sync::Arc;
+
+use kaspa_database::prelude::DB;
+use kaspa_database::prelude::{BatchDbWriter, CachedDbAccess};
+use kaspa_database::prelude::{StoreError, StoreResult};
+use kaspa_database::registry::DatabaseStorePrefixes;
+use rocksdb::WriteBatch;
+use serde::{Deserialize, Serialize};
+
+use super::U64Key;
+
+/// The DAA score range covered by each sample. Samples are taken from selected chain blocks which are about to be
+/// pruned, thus providing a compact approximation of the DAA score to time mapping for pruned data.
+pub const DAA_SCORE_TIMESTAMP_SAMPLE_INTERVAL: u64 = 1000;
+
+/// Maps a DAA score to the index of the sample bucket it belongs to
+pub fn daa_score_sample_bucket(daa_score: u64) -> u64 {
+    daa_score / DAA_SCORE_TIMESTAMP_SAMPLE_INTERVAL
+}
+
+/// The DAA score and timestamp of a selected chain block
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DaaScoreTimestamp {
+    pub daa_score: u64,
+    pub timestamp: u64,
+}
+
+/// Reader API for `DaaScoreTimestampStore`.
+pub trait DaaScoreTimestampStoreReader {
+    /// Returns the sample of the lowest selected chain block within `bucket`
+    fn get(&self, bucket: u64) -> StoreResult<DaaScoreTimestamp>;
+    fn has(&self, bucket: u64) -> StoreResult<bool>;
+}
+
+/// A DB + cache implementation of `DaaScoreTimestampStore` trait, with concurrency support.
+#[derive(Clone)]
+pub struct DbDaaScoreTimestampStore {
+    db: Arc<DB>,
+    access: CachedDbAccess<U64Key, DaaScoreTimestamp>,
+}
+
+impl DbDaaScoreTimestampStore {
+    pub fn new(db: Arc<DB>, cache_size: u64) -> Self {
+        Self { db: Arc::clone(&db), access: CachedDbAccess::new(db, cache_size, DatabaseStorePrefixes::DaaScoreTimestamps.into()) }
+    }
+
+    pub fn clone_with_new_cache(&self, cache_size: u64) -> Self {
+        Self::new(Arc::clone(&self.db), cache_size)
+    }
+
+    pub fn insert_batch(&self, batch: &mut WriteBatch, bucket: u64, sample: DaaScoreTimestamp) -> Result<(), StoreError> {
+        if self.access.has(bucket.into())? {
+            return Err(StoreError::KeyAlreadyExists(bucket.to_string()));
+        }
+        self.access.write(BatchDbWriter::new(batch), bucket.into(), sample)?;
+        Ok(())
+    }
+}
+
+impl DaaScoreTimestampStoreReader for DbDaaScoreTimestampStore {
+    fn get(&self, bucket: u64) -> StoreResult<DaaScoreTimestamp> {
+        self.access.read(bucket.into())
+    }
+
+    fn has(&self, bucket: u64) -> StoreResult<bool> {
+        self.access.has(bucket.into())
+    }
+}
@@ -0,0 +1,79 @@
+use std::sync::Arc;
+
+use kaspa_consensus_core::BlockHashMap;
+use kaspa_database::prelude::StoreResult;
+use kaspa_database::prelude::StoreResultExtensions;
+use kaspa_database::prelude::DB;
+use kaspa_database::prelude::{BatchDbWriter, CachedDbItem, DirectDbWriter};
+use kaspa_database::registry::DatabaseStorePrefixes;
+use kaspa_hashes::Hash;
+use rocksdb::WriteBatch;
+use serde::{Deserialize, Serialize};
+
+/// Maximum number of blocks recorded as violating finality
+pub const MAX_FINALITY_CONFLICT_BLOCKS: usize = 64;
+
+/// Pending finality conflicts along with the operator resolution, if any
+#[derive(Clone, Default, Serialize, Deserialize)]
+pub struct FinalityConflicts {
+    /// Blocks violating finality mapped to the finality point they violate. Only the latest known
+    /// violating blocks of each conflicting branch are kept, up to [`MAX_FINALITY_CONFLICT_BLOCKS`]
+    pub violating_blocks: BlockHashMap<Hash>,
+    /// Block chosen by the operator to resolve the conflicts. While set, the virtual
+    /// chain is restricted to the chain future of this block until it becomes final
+    pub resolution: Option<Hash>,
+}
+
+/// Reader API for `FinalityConflictsStore`.
+pub trait FinalityConflictsStoreReader {
+    fn get(&self) -> StoreResult<Arc<FinalityConflicts>>;
+}
+
+pub trait FinalityConflictsStore: FinalityConflictsStoreReader {
+    fn set_batch(&mut self, batch: &mut WriteBatch, conflicts: FinalityConflicts) -> StoreResult<()>;
+    fn set_resolution(&mut self, resolution: Option<Hash>) -> StoreResult<()>;
+    fn clear_violating_blocks(&mut self) -> StoreResult<()>;
+}
+
+/// A DB + cache implementation of `FinalityConflictsStore` trait
+#[derive(Clone)]
+pub struct DbFinalityConflictsStore {
+    db: Arc<DB>,
+    access: CachedDbItem<Arc<FinalityConflicts>>,
+}
+
+impl DbFinalityConflictsStore {
+    pub fn new(db: Arc<DB>) -> Self {
+        Self { db: Arc::clone(&db), access: CachedDbItem::new(db, DatabaseStorePrefixes::FinalityConflicts.into()) }
+    }
+
+    pub fn clone_with_new_cache(&self) -> Self {
+        Self::new(Arc::clone(&self.db))
+    }
+
+    fn update(&mut self, op: impl Fn(&mut FinalityConflicts)) -> StoreResult<()> {
+        let mut conflicts = self.get()?;
+        op(Arc::make_mut(&mut conflicts));
+        self.access.write(DirectDbWriter::new(&self.db), &conflicts)
+    }
+}
+
+impl FinalityConflictsStoreReader for DbFinalityConflictsStore {
+    fn get(&self) -> StoreResult<Arc<FinalityConflicts>> {
+        Ok(self.access.read().unwrap_option().unwrap_or_default())
+    }
+}
+
+impl FinalityConflictsStore for DbFinalityConflictsStore {
+    fn set_batch(&mut self, batch: &mut WriteBatch, conflicts: FinalityConflicts) -> StoreResult<()> {
+        self.access.write(BatchDbWriter::new(batch), &Arc::new(conflicts))
+    }
+
+    fn set_resolution(&mut self, resolution: Option<Hash>) -> StoreResult<()> {
+        self.update(|conflicts| conflicts.resolution = resolution)
+    }
+
+    fn clear_violating_blocks(&mut self) -> StoreResult<()> {
+        self.update(|conflicts| conflicts.violating_blocks.clear())
+    }
+}
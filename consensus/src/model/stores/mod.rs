@@ -2,11 +2,13 @@ pub mod acceptance_data;
 pub mod block_transactions;
 pub mod block_window_cache;
 pub mod daa;
+pub mod daa_score_timestamps;
 pub mod selected_chain;
 use std::{fmt::Display, mem::size_of};
 
 pub use kaspa_database;
 pub mod depth;
+pub mod finality_conflicts;
 pub mod ghostdag;
 pub mod headers;
 pub mod headers_selected_tip;
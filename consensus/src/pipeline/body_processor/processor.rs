@@ -36,7 +36,10 @@ use kaspa_notify::notifier::Notify;
 use parking_lot::RwLock;
 use rayon::ThreadPool;
 use rocksdb::WriteBatch;
-use std::sync::{atomic::Ordering, Arc};
+use std::{
+    sync::{atomic::Ordering, Arc},
+    time::Instant,
+};
 
 pub struct BlockBodyProcessor {
     // Channels
@@ -129,6 +132,11 @@ impl BlockBodyProcessor {
         }
     }
 
+    /// Returns the number of messages waiting in the processor queue
+    pub fn queue_len(&self) -> usize {
+        self.receiver.len()
+    }
+
     pub fn worker(self: &Arc<BlockBodyProcessor>) {
         while let Ok(msg) = self.receiver.recv() {
             match msg {
@@ -172,6 +180,7 @@ impl BlockBodyProcessor {
     }
 
     fn process_body(self: &Arc<BlockBodyProcessor>, block: &Block, is_trusted: bool) -> BlockProcessResult<BlockStatus> {
+        let start = Instant::now();
         let _prune_guard = self.pruning_lock.blocking_read();
         let status = self.statuses_store.read().get(block.hash()).unwrap();
         match status {
@@ -213,6 +222,7 @@ impl BlockBodyProcessor {
         self.counters.body_counts.fetch_add(1, Ordering::Relaxed);
         self.counters.txs_counts.fetch_add(block.transactions.len() as u64, Ordering::Relaxed);
         self.counters.mass_counts.fetch_add(mass, Ordering::Relaxed);
+        self.counters.body_processing_micros.fetch_add(start.elapsed().as_micros() as u64, Ordering::Relaxed);
         Ok(BlockStatus::StatusUTXOPendingVerification)
     }
 
@@ -43,7 +43,10 @@ use kaspa_utils::vec::VecExtensions;
 use parking_lot::RwLock;
 use rayon::ThreadPool;
 use rocksdb::WriteBatch;
-use std::sync::{atomic::Ordering, Arc};
+use std::{
+    sync::{atomic::Ordering, Arc},
+    time::Instant,
+};
 
 use super::super::ProcessingCounters;
 
@@ -209,6 +212,11 @@ impl HeaderProcessor {
         }
     }
 
+    /// Returns the number of messages waiting in the processor queue
+    pub fn queue_len(&self) -> usize {
+        self.receiver.len()
+    }
+
     pub fn worker(self: &Arc<HeaderProcessor>) {
         while let Ok(msg) = self.receiver.recv() {
             match msg {
@@ -254,6 +262,7 @@ impl HeaderProcessor {
     }
 
     fn process_header(&self, task: &BlockTask) -> BlockProcessResult<BlockStatus> {
+        let start = Instant::now();
         let _prune_guard = self.pruning_lock.blocking_read();
         let header = &task.block().header;
         let status_option = self.statuses_store.read().get(header.hash).unwrap_option();
@@ -279,6 +288,7 @@ impl HeaderProcessor {
         // Report counters
         self.counters.header_counts.fetch_add(1, Ordering::Relaxed);
         self.counters.dep_counts.fetch_add(header.direct_parents().len() as u64, Ordering::Relaxed);
+        self.counters.header_processing_micros.fetch_add(start.elapsed().as_micros() as u64, Ordering::Relaxed);
 
         Ok(StatusHeaderOnly)
     }
@@ -16,6 +16,14 @@ pub struct ProcessingCounters {
     pub txs_counts: AtomicU64,
     pub chain_block_counts: AtomicU64,
     pub mass_counts: AtomicU64,
+    /// Accumulated time spent processing headers, in microseconds
+    pub header_processing_micros: AtomicU64,
+    /// Accumulated time spent processing bodies, in microseconds
+    pub body_processing_micros: AtomicU64,
+    /// The number of virtual resolution rounds
+    pub virtual_resolve_counts: AtomicU64,
+    /// Accumulated time spent resolving virtual, in microseconds
+    pub virtual_processing_micros: AtomicU64,
 }
 
 impl ProcessingCounters {
@@ -28,6 +36,10 @@ impl ProcessingCounters {
             txs_counts: self.txs_counts.load(Ordering::Relaxed),
             chain_block_counts: self.chain_block_counts.load(Ordering::Relaxed),
             mass_counts: self.mass_counts.load(Ordering::Relaxed),
+            header_processing_micros: self.header_processing_micros.load(Ordering::Relaxed),
+            body_processing_micros: self.body_processing_micros.load(Ordering::Relaxed),
+            virtual_resolve_counts: self.virtual_resolve_counts.load(Ordering::Relaxed),
+            virtual_processing_micros: self.virtual_processing_micros.load(Ordering::Relaxed),
         }
     }
 }
@@ -41,6 +53,10 @@ pub struct ProcessingCountersSnapshot {
     pub txs_counts: u64,
     pub chain_block_counts: u64,
     pub mass_counts: u64,
+    pub header_processing_micros: u64,
+    pub body_processing_micros: u64,
+    pub virtual_resolve_counts: u64,
+    pub virtual_processing_micros: u64,
 }
 
 impl core::ops::Sub for &ProcessingCountersSnapshot {
@@ -55,6 +71,10 @@ impl core::ops::Sub for &ProcessingCountersSnapshot {
             txs_counts: self.txs_counts.checked_sub(rhs.txs_counts).unwrap_or_default(),
             chain_block_counts: self.chain_block_counts.checked_sub(rhs.chain_block_counts).unwrap_or_default(),
             mass_counts: self.mass_counts.checked_sub(rhs.mass_counts).unwrap_or_default(),
+            header_processing_micros: self.header_processing_micros.checked_sub(rhs.header_processing_micros).unwrap_or_default(),
+            body_processing_micros: self.body_processing_micros.checked_sub(rhs.body_processing_micros).unwrap_or_default(),
+            virtual_resolve_counts: self.virtual_resolve_counts.checked_sub(rhs.virtual_resolve_counts).unwrap_or_default(),
+            virtual_processing_micros: self.virtual_processing_micros.checked_sub(rhs.virtual_processing_micros).unwrap_or_default(),
         }
     }
 }
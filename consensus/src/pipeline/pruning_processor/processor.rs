@@ -8,13 +8,14 @@ use crate::{
     model::{
         services::reachability::{MTReachabilityService, ReachabilityService},
         stores::{
+            daa_score_timestamps::{daa_score_sample_bucket, DaaScoreTimestamp, DaaScoreTimestampStoreReader},
             ghostdag::{CompactGhostdagData, GhostdagStoreReader},
             headers::HeaderStoreReader,
             past_pruning_points::PastPruningPointsStoreReader,
             pruning::{PruningStore, PruningStoreReader},
             reachability::{DbReachabilityStore, ReachabilityStoreReader, StagingReachabilityStore},
             relations::StagingRelationsStore,
-            selected_chain::SelectedChainStore,
+            selected_chain::{DbSelectedChainStore, SelectedChainStore, SelectedChainStoreReader},
             statuses::StatusesStoreReader,
             tips::{TipsStore, TipsStoreReader},
             utxo_diffs::UtxoDiffsStoreReader,
@@ -43,7 +44,7 @@ use kaspa_utils::iter::IterExtensions;
 use parking_lot::RwLockUpgradableReadGuard;
 use rocksdb::WriteBatch;
 use std::{
-    collections::VecDeque,
+    collections::{BTreeMap, VecDeque},
     ops::Deref,
     sync::Arc,
     time::{Duration, Instant},
@@ -108,6 +109,11 @@ impl PruningProcessor {
         }
     }
 
+    /// Returns the number of messages waiting in the processor queue
+    pub fn queue_len(&self) -> usize {
+        self.receiver.len()
+    }
+
     pub fn worker(self: &Arc<Self>) {
         let Ok(PruningProcessingMessage::Process { sink_ghostdag_data }) = self.receiver.recv() else {
             return;
@@ -217,6 +223,32 @@ impl PruningProcessor {
         assert_eq!(multiset.finalize(), commitment, "Updated pruning point utxo set does not match the header utxo commitment");
     }
 
+    /// Samples the DAA score and timestamp of the selected chain blocks below `new_pruning_point`, which are about
+    /// to be pruned from the selected chain index. A single sample is kept per DAA score bucket: the lowest chain block
+    /// within the bucket. Buckets sampled by previous pruning rounds are left untouched.
+    fn sample_daa_score_timestamps(&self, batch: &mut WriteBatch, selected_chain: &DbSelectedChainStore, new_pruning_point: Hash) {
+        let mut samples = BTreeMap::new();
+        let mut index = selected_chain.get_by_hash(new_pruning_point).unwrap();
+        while index > 0 {
+            index -= 1;
+            // A missing index signals that data below this point has already been pruned
+            let Some(hash) = selected_chain.get_by_index(index).unwrap_option() else {
+                break;
+            };
+            let header_data = self.headers_store.get_compact_header_data(hash).unwrap();
+            // Chain blocks are traversed in descending order, so the last insert per bucket is the lowest block
+            samples.insert(
+                daa_score_sample_bucket(header_data.daa_score),
+                DaaScoreTimestamp { daa_score: header_data.daa_score, timestamp: header_data.timestamp },
+            );
+        }
+        for (bucket, sample) in samples {
+            if !self.daa_score_timestamp_store.has(bucket).unwrap() {
+                self.daa_score_timestamp_store.insert_batch(batch, bucket, sample).unwrap();
+            }
+        }
+    }
+
     fn prune(&self, new_pruning_point: Hash) {
         if self.config.is_archival {
             warn!("The node is configured as an archival node -- avoiding data pruning. Note this might lead to heavy disk usage.");
@@ -307,6 +339,7 @@ impl PruningProcessor {
 
             // Prune the selected chain index below the pruning point
             let mut selected_chain_write = self.selected_chain_store.write();
+            self.sample_daa_score_timestamps(&mut batch, &selected_chain_write, new_pruning_point);
             selected_chain_write.prune_below_pruning_point(BatchDbWriter::new(&mut batch), new_pruning_point).unwrap();
 
             // Flush the batch to the DB
@@ -18,6 +18,10 @@ use crate::{
             block_transactions::{BlockTransactionsStoreReader, DbBlockTransactionsStore},
             daa::DbDaaStore,
             depth::{DbDepthStore, DepthStoreReader},
+            finality_conflicts::{
+                DbFinalityConflictsStore, FinalityConflicts, FinalityConflictsStore, FinalityConflictsStoreReader,
+                MAX_FINALITY_CONFLICT_BLOCKS,
+            },
             ghostdag::{DbGhostdagStore, GhostdagData, GhostdagStoreReader},
             headers::{DbHeadersStore, HeaderStoreReader},
             past_pruning_points::DbPastPruningPointsStore,
@@ -52,6 +56,7 @@ use kaspa_consensus_core::{
     blockstatus::BlockStatus::{StatusDisqualifiedFromChain, StatusUTXOValid},
     coinbase::MinerData,
     config::genesis::GenesisBlock,
+    errors::consensus::{ConsensusError, ConsensusResult},
     header::Header,
     merkle::calc_hash_merkle_root,
     pruning::PruningPointsList,
@@ -64,8 +69,8 @@ use kaspa_consensus_core::{
 };
 use kaspa_consensus_notify::{
     notification::{
-        Notification, SinkBlueScoreChangedNotification, UtxosChangedNotification, VirtualChainChangedNotification,
-        VirtualDaaScoreChangedNotification,
+        FinalityConflictNotification, FinalityConflictResolvedNotification, Notification, SinkBlueScoreChangedNotification,
+        UtxosChangedNotification, VirtualChainChangedNotification, VirtualDaaScoreChangedNotification,
     },
     root::ConsensusNotificationRoot,
 };
@@ -88,10 +93,27 @@ use std::{
     collections::{BinaryHeap, VecDeque},
     ops::Deref,
     sync::{atomic::Ordering, Arc},
+    time::Instant,
 };
 
 use super::errors::{PruningImportError, PruningImportResult};
 
+/// Changes made to the finality conflicts state during a virtual resolution, committed along with the virtual state
+pub(super) struct FinalityConflictsUpdate {
+    conflicts: FinalityConflicts,
+    /// Blocks reported as violating finality during this resolution
+    reported: Vec<Hash>,
+    /// Block of the branch chosen by an operator resolution completed during this resolution
+    resolved: Option<Hash>,
+    changed: bool,
+}
+
+impl FinalityConflictsUpdate {
+    pub(super) fn new(conflicts: &FinalityConflicts) -> Self {
+        Self { conflicts: conflicts.clone(), reported: Vec::new(), resolved: None, changed: false }
+    }
+}
+
 pub struct VirtualStateProcessor {
     // Channels
     receiver: CrossbeamReceiver<BlockProcessingMessage>,
@@ -121,6 +143,7 @@ pub struct VirtualStateProcessor {
     pub(super) body_tips_store: Arc<RwLock<DbTipsStore>>,
     pub(super) depth_store: Arc<DbDepthStore>,
     pub(super) selected_chain_store: Arc<RwLock<DbSelectedChainStore>>,
+    pub(super) finality_conflicts_store: Arc<RwLock<DbFinalityConflictsStore>>,
 
     // Utxo-related stores
     pub(super) utxo_diffs_store: Arc<DbUtxoDiffsStore>,
@@ -188,6 +211,7 @@ impl VirtualStateProcessor {
             body_tips_store: storage.body_tips_store.clone(),
             depth_store: storage.depth_store.clone(),
             selected_chain_store: storage.selected_chain_store.clone(),
+            finality_conflicts_store: storage.finality_conflicts_store.clone(),
             utxo_diffs_store: storage.utxo_diffs_store.clone(),
             utxo_multisets_store: storage.utxo_multisets_store.clone(),
             acceptance_data_store: storage.acceptance_data_store.clone(),
@@ -211,6 +235,11 @@ impl VirtualStateProcessor {
         }
     }
 
+    /// Returns the number of messages waiting in the processor queue
+    pub fn queue_len(&self) -> usize {
+        self.receiver.len()
+    }
+
     pub fn worker(self: &Arc<Self>) {
         // TEMP: upgrade from prev DB version where the chain was the headers selected chain
         if let Some(virtual_state) = self.virtual_stores.read().state.get().unwrap_option() {
@@ -240,7 +269,10 @@ impl VirtualStateProcessor {
             let messages: Vec<BlockProcessingMessage> = std::iter::once(msg).chain(self.receiver.try_iter()).collect();
             trace!("virtual processor received {} tasks", messages.len());
 
+            let start = Instant::now();
             self.resolve_virtual();
+            self.counters.virtual_resolve_counts.fetch_add(1, Ordering::Relaxed);
+            self.counters.virtual_processing_micros.fetch_add(start.elapsed().as_micros() as u64, Ordering::Relaxed);
 
             let statuses_read = self.statuses_store.read();
             for msg in messages {
@@ -262,17 +294,29 @@ impl VirtualStateProcessor {
         let _prune_guard = self.pruning_lock.blocking_read();
         let pruning_point = self.pruning_point_store.read().pruning_point().unwrap();
         let virtual_read = self.virtual_stores.upgradable_read();
+        let finality_conflicts_read = self.finality_conflicts_store.upgradable_read();
+        let mut finality_conflicts = FinalityConflictsUpdate::new(&finality_conflicts_read.get().unwrap());
         let prev_state = virtual_read.state.get().unwrap();
         let finality_point = self.virtual_finality_point(&prev_state.ghostdag_data, pruning_point);
         let tips = self.body_tips_store.read().get().unwrap().iter().copied().collect_vec();
         let prev_sink = prev_state.ghostdag_data.selected_parent;
         let mut accumulated_diff = prev_state.utxo_diff.clone().to_reversed();
 
-        let (new_sink, virtual_parent_candidates) =
-            self.sink_search_algorithm(&virtual_read, &mut accumulated_diff, prev_sink, tips, finality_point, pruning_point);
+        let (new_sink, virtual_parent_candidates) = self.sink_search_algorithm(
+            &virtual_read,
+            &mut accumulated_diff,
+            prev_sink,
+            tips,
+            finality_point,
+            pruning_point,
+            &mut finality_conflicts,
+        );
         let (virtual_parents, virtual_ghostdag_data) = self.pick_virtual_parents(new_sink, virtual_parent_candidates, pruning_point);
         assert_eq!(virtual_ghostdag_data.selected_parent, new_sink);
 
+        // Track the progress of an operator resolution of finality conflicts
+        self.update_finality_conflict_resolution(&mut finality_conflicts, new_sink, &virtual_ghostdag_data, pruning_point);
+
         let sink_multiset = self.utxo_multisets_store.get(new_sink).unwrap();
         let chain_path = self.dag_traversal_manager.calculate_chain_path(prev_sink, new_sink);
         let new_virtual_state = self
@@ -283,6 +327,7 @@ impl VirtualStateProcessor {
                 sink_multiset,
                 &mut accumulated_diff,
                 &chain_path,
+                Some((finality_conflicts_read, &finality_conflicts)),
             )
             .expect("all possible rule errors are unexpected here");
 
@@ -294,6 +339,16 @@ impl VirtualStateProcessor {
         self.pruning_sender.send(PruningProcessingMessage::Process { sink_ghostdag_data }).unwrap();
 
         // Emit notifications
+        for &violating_block in finality_conflicts.reported.iter() {
+            self.notification_root
+                .notify(Notification::FinalityConflict(FinalityConflictNotification::new(violating_block)))
+                .expect("expecting an open unbounded channel");
+        }
+        if let Some(resolution) = finality_conflicts.resolved {
+            self.notification_root
+                .notify(Notification::FinalityConflictResolved(FinalityConflictResolvedNotification::new(resolution)))
+                .expect("expecting an open unbounded channel");
+        }
         let accumulated_diff = Arc::new(accumulated_diff);
         let virtual_parents = Arc::new(new_virtual_state.parents.clone());
         self.notification_root
@@ -328,6 +383,99 @@ impl VirtualStateProcessor {
         }
     }
 
+    /// Records a block violating finality, replacing the recorded blocks in its past. The block is reported
+    /// as a new conflict unless it extends the branch of a recorded one
+    fn report_finality_conflict(&self, update: &mut FinalityConflictsUpdate, violating_block: Hash, finality_point: Hash) {
+        let violating_blocks = &mut update.conflicts.violating_blocks;
+        if violating_blocks.keys().any(|&block| self.reachability_service.is_dag_ancestor_of(violating_block, block)) {
+            // Already known or in the past of a recorded block
+            return;
+        }
+        let extended = violating_blocks
+            .keys()
+            .copied()
+            .filter(|&block| self.reachability_service.is_dag_ancestor_of(block, violating_block))
+            .collect_vec();
+        if extended.is_empty() && violating_blocks.len() >= MAX_FINALITY_CONFLICT_BLOCKS {
+            debug!("Too many finality conflicts, block {} is not recorded", violating_block);
+            return;
+        }
+        extended.iter().for_each(|block| {
+            violating_blocks.remove(block);
+        });
+        violating_blocks.insert(violating_block, finality_point);
+        update.changed = true;
+        if extended.is_empty() {
+            warn!("Finality Violation Detected. Block {} violates finality and is ignored from Virtual chain.", violating_block);
+            update.reported.push(violating_block);
+        }
+    }
+
+    /// Completes an operator resolution of finality conflicts once the virtual chain has moved to the chosen branch,
+    /// and ends it once the chosen block is final
+    fn update_finality_conflict_resolution(
+        &self,
+        update: &mut FinalityConflictsUpdate,
+        sink: Hash,
+        virtual_ghostdag_data: &GhostdagData,
+        pruning_point: Hash,
+    ) {
+        let Some(resolution) = update.conflicts.resolution else {
+            return;
+        };
+        if !self.reachability_service.is_chain_ancestor_of(resolution, sink) {
+            return;
+        }
+
+        if !update.conflicts.violating_blocks.is_empty() {
+            info!("Finality conflict resolved, the virtual chain was moved to the branch of block {}", resolution);
+            update.conflicts.violating_blocks.clear();
+            update.resolved = Some(resolution);
+            update.changed = true;
+        }
+        let finality_point = self.virtual_finality_point(virtual_ghostdag_data, pruning_point);
+        if self.reachability_service.is_chain_ancestor_of(resolution, finality_point) {
+            // The chosen block is final, hence regular finality rules keep the virtual chain on its branch
+            update.conflicts.resolution = None;
+            update.changed = true;
+        }
+    }
+
+    /// Resolves pending finality conflicts in favor of the branch of `finality_block_hash`.
+    ///
+    /// If the block is on the current virtual chain, the conflicting blocks are dismissed right away.
+    /// Otherwise the block must be in the past of (or be) a block violating finality, in which case the
+    /// virtual chain is moved to its branch during the next virtual resolution.
+    pub fn resolve_finality_conflict(&self, finality_block_hash: Hash) -> ConsensusResult<()> {
+        let pruning_point = self.pruning_point_store.read().pruning_point().unwrap();
+        let sink = self.virtual_stores.read().state.get().unwrap().ghostdag_data.selected_parent;
+        let mut finality_conflicts_write = self.finality_conflicts_store.write();
+        let conflicts = finality_conflicts_write.get().unwrap();
+        if conflicts.violating_blocks.is_empty() {
+            return Err(ConsensusError::General("there is no pending finality conflict"));
+        }
+        if !self.reachability_service.is_chain_ancestor_of(pruning_point, finality_block_hash) {
+            return Err(ConsensusError::NotInFinalityConflict(finality_block_hash));
+        }
+
+        if self.reachability_service.is_chain_ancestor_of(finality_block_hash, sink) {
+            info!("Finality conflict resolved, keeping the current virtual chain containing block {}", finality_block_hash);
+            finality_conflicts_write.clear_violating_blocks().unwrap();
+            finality_conflicts_write.set_resolution(None).unwrap();
+            self.notification_root
+                .notify(Notification::FinalityConflictResolved(FinalityConflictResolvedNotification::new(finality_block_hash)))
+                .expect("expecting an open unbounded channel");
+            return Ok(());
+        }
+
+        if !conflicts.violating_blocks.keys().any(|&block| self.reachability_service.is_dag_ancestor_of(finality_block_hash, block)) {
+            return Err(ConsensusError::NotInFinalityConflict(finality_block_hash));
+        }
+        info!("Resolving finality conflict in favor of the branch of block {}", finality_block_hash);
+        finality_conflicts_write.set_resolution(Some(finality_block_hash)).unwrap();
+        Ok(())
+    }
+
     /// Calculates the UTXO state of `to` starting from the state of `from`.
     /// The provided `diff` is assumed to initially hold the UTXO diff of `from` from virtual.
     /// The function returns the top-most UTXO-valid block on `chain(to)` which is ideally
@@ -436,6 +584,7 @@ impl VirtualStateProcessor {
         selected_parent_multiset: MuHash,
         accumulated_diff: &mut UtxoDiff,
         chain_path: &ChainPath,
+        finality_conflicts: Option<(RwLockUpgradableReadGuard<'_, DbFinalityConflictsStore>, &FinalityConflictsUpdate)>,
     ) -> Result<Arc<VirtualState>, RuleError> {
         let new_virtual_state = self.calculate_virtual_state(
             &virtual_read,
@@ -444,7 +593,7 @@ impl VirtualStateProcessor {
             selected_parent_multiset,
             accumulated_diff,
         )?;
-        self.commit_virtual_state(virtual_read, new_virtual_state.clone(), accumulated_diff, chain_path);
+        self.commit_virtual_state(virtual_read, new_virtual_state.clone(), accumulated_diff, chain_path, finality_conflicts);
         Ok(new_virtual_state)
     }
 
@@ -491,11 +640,20 @@ impl VirtualStateProcessor {
         new_virtual_state: Arc<VirtualState>,
         accumulated_diff: &UtxoDiff,
         chain_path: &ChainPath,
+        finality_conflicts: Option<(RwLockUpgradableReadGuard<'_, DbFinalityConflictsStore>, &FinalityConflictsUpdate)>,
     ) {
         let mut batch = WriteBatch::default();
         let mut virtual_write = RwLockUpgradableReadGuard::upgrade(virtual_read);
         let mut selected_chain_write = self.selected_chain_store.write();
 
+        // Update the finality conflicts state
+        let finality_conflicts_write =
+            finality_conflicts.filter(|(_, update)| update.changed).map(|(finality_conflicts_read, update)| {
+                let mut finality_conflicts_write = RwLockUpgradableReadGuard::upgrade(finality_conflicts_read);
+                finality_conflicts_write.set_batch(&mut batch, update.conflicts.clone()).unwrap();
+                finality_conflicts_write
+            });
+
         // Apply the accumulated diff to the virtual UTXO set
         virtual_write.utxo_set.write_diff_batch(&mut batch, accumulated_diff).unwrap();
 
@@ -511,6 +669,7 @@ impl VirtualStateProcessor {
         // Calling the drops explicitly after the batch is written in order to avoid possible errors.
         drop(virtual_write);
         drop(selected_chain_write);
+        drop(finality_conflicts_write);
     }
 
     /// Returns the max number of tips to consider as virtual parents in a single virtual resolve operation
@@ -535,26 +694,57 @@ impl VirtualStateProcessor {
         tips: Vec<Hash>,
         finality_point: Hash,
         pruning_point: Hash,
+        finality_conflicts: &mut FinalityConflictsUpdate,
     ) -> (Hash, VecDeque<Hash>) {
         // TODO: tests
 
+        // The initial diff point is the previous sink
+        let mut diff_point = prev_sink;
+
+        // An operator resolution of finality conflicts restricts the sink to the chain future of the chosen block
+        if let Some(resolution) = finality_conflicts.conflicts.resolution {
+            if let Some(result) =
+                self.search_sink(stores, diff, &mut diff_point, tips.clone(), resolution, pruning_point, true, finality_conflicts)
+            {
+                return result;
+            }
+            warn!("No UTXO-valid block was found on the branch of block {}, dropping the finality conflict resolution", resolution);
+            finality_conflicts.conflicts.resolution = None;
+            finality_conflicts.changed = true;
+        }
+
+        self.search_sink(stores, diff, &mut diff_point, tips, finality_point, pruning_point, false, finality_conflicts)
+            .expect("valid sink must exist")
+    }
+
+    /// Searches for the top-most UTXO-valid block in the chain future of `finality_point`, see [`Self::sink_search_algorithm`].
+    /// `diff_point` is updated along with `diff` and blocks violating finality are reported as finality conflicts.
+    /// If `restricted` is set, the search does not descend below `finality_point` and returns `None` if no sink is found.
+    #[allow(clippy::too_many_arguments)]
+    fn search_sink(
+        &self,
+        stores: &VirtualStores,
+        diff: &mut UtxoDiff,
+        diff_point: &mut Hash,
+        tips: Vec<Hash>,
+        finality_point: Hash,
+        pruning_point: Hash,
+        restricted: bool,
+        finality_conflicts: &mut FinalityConflictsUpdate,
+    ) -> Option<(Hash, VecDeque<Hash>)> {
         let mut heap = tips
             .into_iter()
             .map(|block| SortableBlock { hash: block, blue_work: self.ghostdag_primary_store.get_blue_work(block).unwrap() })
             .collect::<BinaryHeap<_>>();
 
-        // The initial diff point is the previous sink
-        let mut diff_point = prev_sink;
-
         // We maintain the following invariant: `heap` is an antichain.
         // It holds at step 0 since tips are an antichain, and remains through the loop
         // since we check that every pushed block is not in the past of current heap
         // (and it can't be in the future by induction)
-        loop {
-            let candidate = heap.pop().expect("valid sink must exist").hash;
+        while let Some(SortableBlock { hash: candidate, .. }) = heap.pop() {
             if self.reachability_service.is_chain_ancestor_of(finality_point, candidate) {
-                diff_point = self.calculate_utxo_state_relatively(stores, diff, diff_point, candidate);
-                if diff_point == candidate {
+                *diff_point = self.calculate_utxo_state_relatively(stores, diff, *diff_point, candidate);
+                if *diff_point == candidate {
                     // This indicates that candidate has valid UTXO state and that `diff` represents its diff from virtual
 
                     // All blocks with lower blue work than filtering_root are:
@@ -563,20 +753,25 @@ impl VirtualStateProcessor {
                     // Hence as an optimization we prefer removing such blocks in advance to allow valid tips to be considered.
                     let filtering_root = self.depth_store.merge_depth_root(candidate).unwrap();
                     let filtering_blue_work = self.ghostdag_primary_store.get_blue_work(filtering_root).unwrap_or_default();
-                    return (
+                    return Some((
                         candidate,
                         heap.into_sorted_iter()
                             .take(self.max_virtual_parent_candidates())
                             .take_while(|s| s.blue_work >= filtering_blue_work)
                             .map(|s| s.hash)
                             .collect(),
-                    );
+                    ));
                 } else {
                     debug!("Block candidate {} has invalid UTXO state and is ignored from Virtual chain.", candidate)
                 }
-            } else if finality_point != pruning_point {
-                // `finality_point == pruning_point` indicates we are at IBD start hence no warning required
-                warn!("Finality Violation Detected. Block {} violates finality and is ignored from Virtual chain.", candidate);
+            } else if !restricted && finality_point != pruning_point {
+                // `finality_point == pruning_point` indicates we are at IBD start hence no conflict is reported
+                self.report_finality_conflict(finality_conflicts, candidate, finality_point);
+            }
+            if restricted && (candidate == finality_point || !self.reachability_service.is_dag_ancestor_of(finality_point, candidate))
+            {
+                // A restricted search only considers blocks in the future of `finality_point`
+                continue;
             }
             for parent in self.relations_service.get_parents(candidate).unwrap().iter().copied() {
                 if !self.reachability_service.is_dag_ancestor_of_any(parent, &mut heap.iter().map(|sb| sb.hash)) {
@@ -584,6 +779,7 @@ impl VirtualStateProcessor {
                 }
             }
         }
+        None
     }
 
     /// Picks the virtual parents according to virtual parent selection pruning constrains.
@@ -930,6 +1126,7 @@ impl VirtualStateProcessor {
             imported_utxo_multiset.clone(),
             &mut UtxoDiff::default(),
             &ChainPath::default(),
+            None,
         )?;
 
         Ok(())
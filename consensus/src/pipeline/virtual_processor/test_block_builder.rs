@@ -1,7 +1,8 @@
 use std::{ops::Deref, sync::Arc};
 
 use crate::model::stores::{
-    pruning::PruningStoreReader, utxo_multisets::UtxoMultisetsStoreReader, virtual_state::VirtualStateStoreReader,
+    finality_conflicts::FinalityConflictsStoreReader, pruning::PruningStoreReader, utxo_multisets::UtxoMultisetsStoreReader,
+    virtual_state::VirtualStateStoreReader,
 };
 use kaspa_consensus_core::{
     block::BlockTemplate, blockhash::ORIGIN, coinbase::MinerData, errors::block::RuleError, tx::Transaction,
@@ -9,7 +10,7 @@ use kaspa_consensus_core::{
 };
 use kaspa_hashes::Hash;
 
-use super::VirtualStateProcessor;
+use super::{FinalityConflictsUpdate, VirtualStateProcessor};
 
 /// Wrapper for virtual processor with util methods for building a block with any parent context
 pub struct TestBlockBuilder {
@@ -45,9 +46,18 @@ impl TestBlockBuilder {
         let finality_point = ORIGIN; // No real finality point since we are not actually building virtual here
         let sink = virtual_state.ghostdag_data.selected_parent;
         let mut accumulated_diff = virtual_state.utxo_diff.clone().to_reversed();
+        // Finality conflicts observed from the PoV of this virtual are not recorded
+        let mut finality_conflicts = FinalityConflictsUpdate::new(&self.finality_conflicts_store.read().get().unwrap());
         // Search for the sink block from the PoV of this virtual
-        let (pov_sink, virtual_parent_candidates) =
-            self.sink_search_algorithm(&virtual_read, &mut accumulated_diff, sink, parents, finality_point, pruning_point);
+        let (pov_sink, virtual_parent_candidates) = self.sink_search_algorithm(
+            &virtual_read,
+            &mut accumulated_diff,
+            sink,
+            parents,
+            finality_point,
+            pruning_point,
+            &mut finality_conflicts,
+        );
         let (pov_virtual_parents, pov_virtual_ghostdag_data) =
             self.pick_virtual_parents(pov_sink, virtual_parent_candidates, pruning_point);
         let pov_sink_multiset = self.utxo_multisets_store.get(pov_sink).unwrap();
@@ -36,6 +36,9 @@ use crate::{
     model::{
         services::reachability::{MTReachabilityService, ReachabilityService},
         stores::{
+            daa_score_timestamps::{
+                daa_score_sample_bucket, DaaScoreTimestamp, DaaScoreTimestampStoreReader, DbDaaScoreTimestampStore,
+            },
             depth::DbDepthStore,
             ghostdag::{DbGhostdagStore, GhostdagData, GhostdagStore, GhostdagStoreReader},
             headers::{DbHeadersStore, HeaderStore, HeaderStoreReader},
@@ -88,6 +91,7 @@ pub struct PruningProofManager {
     headers_selected_tip_store: Arc<RwLock<DbHeadersSelectedTipStore>>,
     depth_store: Arc<DbDepthStore>,
     selected_chain_store: Arc<RwLock<DbSelectedChainStore>>,
+    daa_score_timestamp_store: Arc<DbDaaScoreTimestampStore>,
 
     ghostdag_managers: Arc<Vec<DbGhostdagManager>>,
     traversal_manager: DbDagTraversalManager,
@@ -135,6 +139,7 @@ impl PruningProofManager {
             headers_selected_tip_store: storage.headers_selected_tip_store.clone(),
             selected_chain_store: storage.selected_chain_store.clone(),
             depth_store: storage.depth_store.clone(),
+            daa_score_timestamp_store: storage.daa_score_timestamp_store.clone(),
 
             ghostdag_managers,
             traversal_manager,
@@ -175,6 +180,16 @@ impl PruningProofManager {
         let mut batch = WriteBatch::default();
         pruning_point_write.set_batch(&mut batch, new_pruning_point, new_pruning_point, (pruning_points.len() - 1) as u64).unwrap();
         pruning_point_write.set_history_root(&mut batch, new_pruning_point).unwrap();
+
+        // The data below the pruning point is never synced, so the pruning point headers are the only
+        // selected chain blocks available for sampling DAA score timestamps of the pruned history
+        for header in pruning_points.iter() {
+            let bucket = daa_score_sample_bucket(header.daa_score);
+            if !self.daa_score_timestamp_store.has(bucket).unwrap() {
+                let sample = DaaScoreTimestamp { daa_score: header.daa_score, timestamp: header.timestamp };
+                self.daa_score_timestamp_store.insert_batch(&mut batch, bucket, sample).unwrap();
+            }
+        }
         self.db.write(batch).unwrap();
         drop(pruning_point_write);
     }
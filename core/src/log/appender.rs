@@ -1,12 +1,17 @@
 use super::consts::{
     LOG_ARCHIVE_SUFFIX, LOG_FILE_BASE_ROLLS, LOG_FILE_MAX_ROLLS, LOG_FILE_MAX_SIZE, LOG_LINE_PATTERN, LOG_LINE_PATTERN_COLORED,
 };
-use log::LevelFilter;
+use super::logger::LogError;
+use log::{LevelFilter, Record};
 use log4rs::{
     append::{
         console::ConsoleAppender,
         rolling_file::{
-            policy::compound::{roll::fixed_window::FixedWindowRoller, trigger::size::SizeTrigger, CompoundPolicy},
+            policy::compound::{
+                roll::{fixed_window::FixedWindowRoller, Roll},
+                trigger::size::SizeTrigger,
+                CompoundPolicy,
+            },
             RollingFileAppender,
         },
         Append,
@@ -15,7 +20,79 @@ use log4rs::{
     encode::pattern::PatternEncoder,
     filter::{threshold::ThresholdFilter, Filter},
 };
-use std::path::PathBuf;
+use std::{
+    path::PathBuf,
+    sync::{Arc, RwLock},
+};
+
+fn fixed_window_roller(log_dir: &str, file_name: &str) -> FixedWindowRoller {
+    let roller_pattern = PathBuf::from(log_dir).join(format!("{}{}", file_name, LOG_ARCHIVE_SUFFIX));
+    FixedWindowRoller::builder().base(LOG_FILE_BASE_ROLLS).build(roller_pattern.to_str().unwrap(), LOG_FILE_MAX_ROLLS).unwrap()
+}
+
+fn rolling_file_appender(log_dir: &str, file_name: &str) -> RollingFileAppender {
+    let trigger = Box::new(SizeTrigger::new(LOG_FILE_MAX_SIZE));
+
+    let file_path = PathBuf::from(log_dir).join(file_name);
+    let roller = Box::new(fixed_window_roller(log_dir, file_name));
+
+    let compound_policy = Box::new(CompoundPolicy::new(trigger, roller));
+    RollingFileAppender::builder().encoder(Box::new(PatternEncoder::new(LOG_LINE_PATTERN))).build(file_path, compound_policy).unwrap()
+}
+
+/// Archives the log file `file_name` of `log_dir` the same way a size-triggered roll would.
+///
+/// The appender writing into the file must have been released beforehand.
+fn roll_log_file(log_dir: &str, file_name: &str) -> Result<(), LogError> {
+    let file_path = PathBuf::from(log_dir).join(file_name);
+    match file_path.metadata() {
+        Ok(metadata) if metadata.len() > 0 => {
+            fixed_window_roller(log_dir, file_name).roll(&file_path).map_err(|err| LogError::RotationError(err.to_string()))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// A size-triggered rolling file appender which can also be rolled on demand.
+///
+/// Clones share the same underlying file, so the appender survives logger config changes.
+#[derive(Clone, Debug)]
+pub(super) struct RollableFileAppender {
+    log_dir: String,
+    file_name: &'static str,
+    inner: Arc<RwLock<Option<RollingFileAppender>>>,
+}
+
+impl RollableFileAppender {
+    pub fn new(log_dir: &str, file_name: &'static str) -> Self {
+        Self { log_dir: log_dir.to_string(), file_name, inner: Arc::new(RwLock::new(Some(rolling_file_appender(log_dir, file_name)))) }
+    }
+
+    /// Archives the current log file and starts a new one. Records logged meanwhile wait for the new file.
+    pub fn roll(&self) -> Result<(), LogError> {
+        let mut inner = self.inner.write().unwrap();
+        // Dropping the appender flushes and closes the file
+        inner.take();
+        let result = roll_log_file(&self.log_dir, self.file_name);
+        inner.replace(rolling_file_appender(&self.log_dir, self.file_name));
+        result
+    }
+}
+
+impl Append for RollableFileAppender {
+    fn append(&self, record: &Record) -> anyhow::Result<()> {
+        match self.inner.read().unwrap().as_ref() {
+            Some(appender) => appender.append(record),
+            None => Ok(()),
+        }
+    }
+
+    fn flush(&self) {
+        if let Some(appender) = self.inner.read().unwrap().as_ref() {
+            appender.flush();
+        }
+    }
+}
 
 pub(super) struct AppenderSpec {
     pub name: &'static str,
@@ -32,28 +109,8 @@ impl AppenderSpec {
         )
     }
 
-    pub fn roller(name: &'static str, level: Option<LevelFilter>, log_dir: &str, file_name: &str) -> Self {
-        let appender = {
-            let trigger = Box::new(SizeTrigger::new(LOG_FILE_MAX_SIZE));
-
-            let file_path = PathBuf::from(log_dir).join(file_name);
-            let roller_pattern = PathBuf::from(log_dir).join(format!("{}{}", file_name, LOG_ARCHIVE_SUFFIX));
-            let roller = Box::new(
-                FixedWindowRoller::builder()
-                    .base(LOG_FILE_BASE_ROLLS)
-                    .build(roller_pattern.to_str().unwrap(), LOG_FILE_MAX_ROLLS)
-                    .unwrap(),
-            );
-
-            let compound_policy = Box::new(CompoundPolicy::new(trigger, roller));
-            let file_appender = RollingFileAppender::builder()
-                .encoder(Box::new(PatternEncoder::new(LOG_LINE_PATTERN)))
-                .build(file_path, compound_policy)
-                .unwrap();
-
-            Box::new(file_appender) as Box<dyn Append>
-        };
-        Self::new(name, level, appender)
+    pub fn roller(name: &'static str, level: Option<LevelFilter>, appender: &RollableFileAppender) -> Self {
+        Self::new(name, level, Box::new(appender.clone()))
     }
 
     pub fn new(name: &'static str, level: Option<LevelFilter>, append: Box<dyn Append>) -> Self {
@@ -13,3 +13,26 @@ pub const LOG_FILE_MAX_SIZE: u64 = 100_000_000;
 pub const LOG_LINE_PATTERN_COLORED: &str = "{d(%Y-%m-%d %H:%M:%S%.3f%:z)} [{h({({l}):5.5})}] {m}{n}";
 /// File log line pattern, with offset from the local time to UTC (UTC being +00:00)
 pub const LOG_LINE_PATTERN: &str = "{d(%Y-%m-%d %H:%M:%S%.3f%:z)} [{({l}):5.5}] {m}{n}";
+
+/// Logging subsystems of the node, each matching the log target prefix of a workspace crate
+pub const LOG_SUBSYSTEMS: &[&str] = &[
+    "kaspad",
+    "kaspa_addressmanager",
+    "kaspa_connectionmanager",
+    "kaspa_consensus",
+    "kaspa_consensus_notify",
+    "kaspa_consensusmanager",
+    "kaspa_core",
+    "kaspa_database",
+    "kaspa_grpc_server",
+    "kaspa_index_processor",
+    "kaspa_mining",
+    "kaspa_notify",
+    "kaspa_p2p_flows",
+    "kaspa_p2p_lib",
+    "kaspa_perf_monitor",
+    "kaspa_rpc_core",
+    "kaspa_rpc_service",
+    "kaspa_utxoindex",
+    "kaspa_wrpc_server",
+];
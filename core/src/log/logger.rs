@@ -7,6 +7,15 @@ use thiserror::Error;
 pub enum LogError {
     #[error("Logger spec parsing error: {0}")]
     ParseLoggerSpecError(String),
+
+    #[error("The logger is not initialized")]
+    NotInitialized,
+
+    #[error("The logger is not configured to write into log files")]
+    NoLogFiles,
+
+    #[error("Log file rotation error: {0}")]
+    RotationError(String),
 }
 
 #[derive(Clone)]
@@ -39,6 +48,10 @@ impl Loggers {
     pub fn items(&self) -> impl IntoIterator<Item = Logger> + '_ {
         self.loggers.iter().map(|x| x.logger())
     }
+
+    pub fn levels(&self) -> impl Iterator<Item = (&str, LevelFilter)> + '_ {
+        self.loggers.iter().map(|x| (x.name.as_str(), x.level))
+    }
 }
 
 pub(super) struct Builder {
@@ -76,44 +89,58 @@ impl Builder {
         self.parse_specs(expression)
     }
 
+    /// Parses a specs expression, failing on the first invalid spec instead of ignoring it.
+    pub fn try_parse_expression(&mut self, expression: &str) -> Result<&mut Self, LogError> {
+        for spec in Self::specs(expression) {
+            let (name, log_level) = Self::parse_spec(spec)?;
+            self.apply_spec(name, log_level);
+        }
+        Ok(self)
+    }
+
     fn parse_specs(&mut self, expression: &str) -> &mut Self {
-        for spec in expression.split(',').map(|x| x.trim()) {
-            if spec.is_empty() {
-                continue;
+        for spec in Self::specs(expression) {
+            match Self::parse_spec(spec) {
+                Ok((name, log_level)) => self.apply_spec(name, log_level),
+                Err(err) => println!("Ignoring invalid logging spec '{}'", err),
             }
-            let mut parts = spec.split('=');
-            let (log_level, name) = match (parts.next(), parts.next().map(|x| x.trim()), parts.next()) {
-                (Some(part0), None, None) => {
-                    // if the single argument is a log-level string or number,
-                    // it defines the root level
-                    match part0.parse() {
-                        Ok(lvl) => (lvl, None),
-                        Err(_) => (LevelFilter::max(), Some(part0)),
-                    }
-                }
-                (Some(part0), Some(""), None) => (LevelFilter::max(), Some(part0)),
-                (Some(part0), Some(part1), None) => match part1.parse() {
-                    Ok(lvl) => (lvl, Some(part0)),
-                    _ => {
-                        println!("Ignoring invalid logging spec '{}'", LogError::ParseLoggerSpecError(part1.to_string()));
-                        continue;
-                    }
-                },
-                _ => {
-                    println!("Ignoring invalid logging spec '{}'", LogError::ParseLoggerSpecError(spec.to_string()));
-                    continue;
-                }
-            };
-            match name {
-                Some(name) => {
-                    self.logger(name.to_string(), log_level);
-                }
-                None => {
-                    self.root_level(log_level);
+        }
+        self
+    }
+
+    fn specs(expression: &str) -> impl Iterator<Item = &str> {
+        expression.split(',').map(|x| x.trim()).filter(|x| !x.is_empty())
+    }
+
+    fn parse_spec(spec: &str) -> Result<(Option<&str>, LevelFilter), LogError> {
+        let mut parts = spec.split('=');
+        match (parts.next(), parts.next().map(|x| x.trim()), parts.next()) {
+            (Some(part0), None, None) => {
+                // if the single argument is a log-level string or number,
+                // it defines the root level
+                match part0.parse() {
+                    Ok(lvl) => Ok((None, lvl)),
+                    Err(_) => Ok((Some(part0), LevelFilter::max())),
                 }
             }
+            (Some(part0), Some(""), None) => Ok((Some(part0), LevelFilter::max())),
+            (Some(part0), Some(part1), None) => match part1.parse() {
+                Ok(lvl) => Ok((Some(part0), lvl)),
+                _ => Err(LogError::ParseLoggerSpecError(part1.to_string())),
+            },
+            _ => Err(LogError::ParseLoggerSpecError(spec.to_string())),
+        }
+    }
+
+    fn apply_spec(&mut self, name: Option<&str>, log_level: LevelFilter) {
+        match name {
+            Some(name) => {
+                self.logger(name.to_string(), log_level);
+            }
+            None => {
+                self.root_level(log_level);
+            }
         }
-        self
     }
 
     #[allow(dead_code)]
@@ -147,3 +174,33 @@ impl FromStr for Builder {
         Ok(Self::from_expression(s))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_spec() {
+        let ok = |spec| Builder::parse_spec(spec).unwrap();
+        assert_eq!(ok("info"), (None, LevelFilter::Info));
+        assert_eq!(ok("kaspa_core"), (Some("kaspa_core"), LevelFilter::max()));
+        assert_eq!(ok("kaspa_core="), (Some("kaspa_core"), LevelFilter::max()));
+        assert_eq!(ok("kaspa_core=debug"), (Some("kaspa_core"), LevelFilter::Debug));
+        assert_eq!(ok("kaspa_core= warn"), (Some("kaspa_core"), LevelFilter::Warn));
+        assert_eq!(ok("kaspa_core=off"), (Some("kaspa_core"), LevelFilter::Off));
+
+        assert!(matches!(Builder::parse_spec("kaspa_core=loud"), Err(LogError::ParseLoggerSpecError(x)) if x == "loud"));
+        assert!(matches!(Builder::parse_spec("kaspa_core=info=debug"), Err(LogError::ParseLoggerSpecError(_))));
+    }
+
+    #[test]
+    fn test_parse_expression() {
+        let loggers =
+            Builder::new().root_level(LevelFilter::Info).parse_expression("warn, kaspa_core=debug,,kaspa_mining=loud").build();
+        assert_eq!(loggers.root_level(), LevelFilter::Warn);
+        // The invalid spec is ignored
+        assert_eq!(loggers.levels().collect::<Vec<_>>(), vec![("kaspa_core", LevelFilter::Debug)]);
+
+        assert!(Builder::new().try_parse_expression("kaspa_core=debug,kaspa_mining=loud").is_err());
+    }
+}
@@ -38,7 +38,97 @@ cfg_if::cfg_if! {
 }
 
 #[cfg(not(target_arch = "wasm32"))]
-pub fn init_logger(log_dir: Option<&str>, filters: &str) {
+pub use logger::LogError;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use consts::LOG_SUBSYSTEMS;
+
+/// Log levels currently applied by the logger
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Clone, Debug)]
+pub struct LogLevels {
+    /// Level applying to any log target not covered by a subsystem level
+    pub root: LevelFilter,
+    /// Known subsystems and explicitly configured log targets, with their effective level
+    pub subsystems: Vec<(String, LevelFilter)>,
+}
+
+/// Log levels configured on the logger
+#[cfg(not(target_arch = "wasm32"))]
+struct LevelsConfig {
+    root_level: LevelFilter,
+    levels: std::collections::BTreeMap<String, LevelFilter>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl LevelsConfig {
+    fn new(loggers: &logger::Loggers) -> Self {
+        Self { root_level: loggers.root_level(), levels: loggers.levels().map(|(name, level)| (name.to_string(), level)).collect() }
+    }
+
+    /// Applies `filters` on top of the current levels
+    fn apply(&mut self, filters: &str) -> Result<(), LogError> {
+        let loggers = logger::Builder::new().root_level(self.root_level).try_parse_expression(filters)?.build();
+        self.root_level = loggers.root_level();
+        self.levels.extend(loggers.levels().map(|(name, level)| (name.to_string(), level)));
+        Ok(())
+    }
+
+    fn loggers(&self) -> logger::Loggers {
+        let mut builder = logger::Builder::new();
+        builder.root_level(self.root_level);
+        self.levels.iter().for_each(|(name, level)| {
+            builder.logger(name.clone(), *level);
+        });
+        builder.build()
+    }
+
+    /// Returns the level of the most specific configured logger covering `target`
+    fn effective_level(&self, target: &str) -> LevelFilter {
+        self.levels
+            .iter()
+            .filter(|(name, _)| target == name.as_str() || target.strip_prefix(name.as_str()).is_some_and(|x| x.starts_with("::")))
+            .max_by_key(|(name, _)| name.len())
+            .map(|(_, level)| *level)
+            .unwrap_or(self.root_level)
+    }
+
+    fn log_levels(&self) -> LogLevels {
+        let names =
+            LOG_SUBSYSTEMS.iter().copied().chain(self.levels.keys().map(|x| x.as_str())).collect::<std::collections::BTreeSet<_>>();
+        LogLevels { root: self.root_level, subsystems: names.into_iter().map(|x| (x.to_string(), self.effective_level(x))).collect() }
+    }
+}
+
+/// The log file appenders, shared by all the logger configs so the files are kept open across level changes
+#[cfg(not(target_arch = "wasm32"))]
+struct LogFiles {
+    log_file: appender::RollableFileAppender,
+    err_log_file: appender::RollableFileAppender,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl LogFiles {
+    fn new(log_dir: &str) -> Self {
+        Self {
+            log_file: appender::RollableFileAppender::new(log_dir, LOG_FILE_NAME),
+            err_log_file: appender::RollableFileAppender::new(log_dir, ERR_LOG_FILE_NAME),
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+struct LoggerState {
+    handle: log4rs::Handle,
+    log_files: Option<LogFiles>,
+    levels: LevelsConfig,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+static LOGGER_STATE: std::sync::Mutex<Option<LoggerState>> = std::sync::Mutex::new(None);
+
+#[cfg(not(target_arch = "wasm32"))]
+fn build_config(log_files: Option<&LogFiles>, loggers: &logger::Loggers) -> log4rs::Config {
     use crate::log::appender::AppenderSpec;
     use log4rs::{config::Root, Config};
     use std::iter::once;
@@ -47,16 +137,13 @@ pub fn init_logger(log_dir: Option<&str>, filters: &str) {
     const LOG_FILE_APPENDER: &str = "log_file";
     const ERR_LOG_FILE_APPENDER: &str = "err_log_file";
 
-    let level = LevelFilter::Info;
-    let loggers = logger::Builder::new().root_level(level).parse_env(DEFAULT_LOGGER_ENV).parse_expression(filters).build();
-
     let mut stdout_appender = AppenderSpec::console(CONSOLE_APPENDER, None);
-    let mut file_appender = log_dir.map(|x| AppenderSpec::roller(LOG_FILE_APPENDER, None, x, LOG_FILE_NAME));
+    let mut file_appender = log_files.map(|x| AppenderSpec::roller(LOG_FILE_APPENDER, None, &x.log_file));
     let mut err_file_appender =
-        log_dir.map(|x| AppenderSpec::roller(ERR_LOG_FILE_APPENDER, Some(LevelFilter::Warn), x, ERR_LOG_FILE_NAME));
+        log_files.map(|x| AppenderSpec::roller(ERR_LOG_FILE_APPENDER, Some(LevelFilter::Warn), &x.err_log_file));
     let appenders = once(&mut stdout_appender).chain(&mut file_appender).chain(&mut err_file_appender).map(|x| x.appender());
 
-    let config = Config::builder()
+    Config::builder()
         .appenders(appenders)
         .loggers(loggers.items())
         .build(
@@ -64,13 +151,47 @@ pub fn init_logger(log_dir: Option<&str>, filters: &str) {
                 .appenders(once(&stdout_appender).chain(&file_appender).chain(&err_file_appender).map(|x| x.name))
                 .build(loggers.root_level()),
         )
-        .unwrap();
+        .unwrap()
+}
 
-    let _handle = log4rs::init_config(config).unwrap();
+#[cfg(not(target_arch = "wasm32"))]
+pub fn init_logger(log_dir: Option<&str>, filters: &str) {
+    let level = LevelFilter::Info;
+    let loggers = logger::Builder::new().root_level(level).parse_env(DEFAULT_LOGGER_ENV).parse_expression(filters).build();
+    let log_files = log_dir.map(LogFiles::new);
+
+    let handle = log4rs::init_config(build_config(log_files.as_ref(), &loggers)).unwrap();
+    *LOGGER_STATE.lock().unwrap() = Some(LoggerState { handle, log_files, levels: LevelsConfig::new(&loggers) });
 
     set_log_level(level);
 }
 
+/// Returns the log levels currently applied by the logger set up with [`init_logger`].
+#[cfg(not(target_arch = "wasm32"))]
+pub fn get_log_levels() -> Result<LogLevels, LogError> {
+    LOGGER_STATE.lock().unwrap().as_ref().map(|x| x.levels.log_levels()).ok_or(LogError::NotInitialized)
+}
+
+/// Applies `filters`, using the same syntax as the `--loglevel` argument, on top of the current log levels
+/// and returns the resulting levels.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn set_log_levels(filters: &str) -> Result<LogLevels, LogError> {
+    let mut state = LOGGER_STATE.lock().unwrap();
+    let state = state.as_mut().ok_or(LogError::NotInitialized)?;
+    state.levels.apply(filters)?;
+    state.handle.set_config(build_config(state.log_files.as_ref(), &state.levels.loggers()));
+    Ok(state.levels.log_levels())
+}
+
+/// Archives the current log files and starts writing into new ones.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn rotate_log_files() -> Result<(), LogError> {
+    let state = LOGGER_STATE.lock().unwrap();
+    let log_files = state.as_ref().ok_or(LogError::NotInitialized)?.log_files.as_ref().ok_or(LogError::NoLogFiles)?;
+    log_files.log_file.roll()?;
+    log_files.err_log_file.roll()
+}
+
 /// Tries to init the global logger, but does not panic if it was already setup.
 /// Should be used for tests.
 #[cfg(not(target_arch = "wasm32"))]
@@ -179,3 +300,53 @@ macro_rules! error {
         log::error!($($t)*);
     )
 }
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+
+    fn levels_config(filters: &str) -> LevelsConfig {
+        LevelsConfig::new(&logger::Builder::new().root_level(LevelFilter::Info).parse_expression(filters).build())
+    }
+
+    #[test]
+    fn test_levels_config_apply() {
+        let mut config = levels_config("kaspa_core=debug,kaspa_mining=warn");
+
+        config.apply("error,kaspa_mining=trace,kaspa_p2p_flows=off").unwrap();
+        assert_eq!(config.root_level, LevelFilter::Error);
+        assert_eq!(
+            config.levels.iter().map(|(name, level)| (name.as_str(), *level)).collect::<Vec<_>>(),
+            vec![("kaspa_core", LevelFilter::Debug), ("kaspa_mining", LevelFilter::Trace), ("kaspa_p2p_flows", LevelFilter::Off)]
+        );
+
+        // Omitting the root level keeps the current one
+        config.apply("kaspa_core=info").unwrap();
+        assert_eq!(config.root_level, LevelFilter::Error);
+        assert_eq!(config.levels["kaspa_core"], LevelFilter::Info);
+
+        // An invalid expression leaves the levels untouched
+        assert!(config.apply("warn,kaspa_core=loud").is_err());
+        assert_eq!(config.root_level, LevelFilter::Error);
+        assert_eq!(config.levels["kaspa_core"], LevelFilter::Info);
+    }
+
+    #[test]
+    fn test_levels_config_effective_level() {
+        let config = levels_config("warn,kaspa_consensus=debug,kaspa_consensus::pipeline=trace");
+
+        assert_eq!(config.effective_level("kaspa_consensus"), LevelFilter::Debug);
+        assert_eq!(config.effective_level("kaspa_consensus::processes"), LevelFilter::Debug);
+        assert_eq!(config.effective_level("kaspa_consensus::pipeline"), LevelFilter::Trace);
+        assert_eq!(config.effective_level("kaspa_consensus::pipeline::virtual_processor"), LevelFilter::Trace);
+        // Only whole path segments are matched
+        assert_eq!(config.effective_level("kaspa_consensus_notify"), LevelFilter::Warn);
+        assert_eq!(config.effective_level("kaspa_mining"), LevelFilter::Warn);
+
+        let levels = config.log_levels();
+        assert_eq!(levels.root, LevelFilter::Warn);
+        assert!(levels.subsystems.contains(&("kaspa_consensus".to_string(), LevelFilter::Debug)));
+        assert!(levels.subsystems.contains(&("kaspa_consensus::pipeline".to_string(), LevelFilter::Trace)));
+        assert!(levels.subsystems.contains(&("kaspa_mining".to_string(), LevelFilter::Warn)));
+    }
+}
@@ -3,7 +3,7 @@ use futures_util::future::{select_all, try_join_all};
 use kaspa_core::core::Core;
 use kaspa_core::service::Service;
 use kaspa_core::task::service::AsyncService;
-use kaspa_core::trace;
+use kaspa_core::{error, trace};
 use std::{
     sync::{Arc, Mutex},
     thread::{self, JoinHandle as ThreadJoinHandle},
@@ -84,7 +84,11 @@ impl AsyncRuntime {
         // if at least one service yields an error, initiate global shutdown
         // this will cause signal_exit() to be executed externally (by Core invoking `stop()`)
         match result {
-            Ok(Err(_)) | Err(_) => {
+            Ok(Err(err)) => {
+                error!("shutting down core due to async-runtime error: {}", err);
+                core.shutdown()
+            }
+            Err(_) => {
                 trace!("shutting down core due to async-runtime error");
                 core.shutdown()
             }
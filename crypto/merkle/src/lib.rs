@@ -1,4 +1,9 @@
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
 use kaspa_hashes::{Hash, HasherBase, MerkleBranchHash, ZERO_HASH};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
 pub fn calc_merkle_root(hashes: impl ExactSizeIterator<Item = Hash>) -> Hash {
     if hashes.len() == 0 {
@@ -22,8 +27,79 @@ pub fn calc_merkle_root(hashes: impl ExactSizeIterator<Item = Hash>) -> Hash {
     merkles.last().unwrap().unwrap()
 }
 
+/// Proof of inclusion of a leaf in a merkle tree built by [`calc_merkle_root`]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MerkleProof {
+    /// Position of the leaf among the tree leaves
+    pub index: u32,
+    /// Sibling hashes along the path from the leaf to the root, bottom-up
+    pub siblings: Vec<Hash>,
+}
+
+impl MerkleProof {
+    /// Calculates the merkle root implied by this proof for `leaf`
+    pub fn calc_root(&self, leaf: Hash) -> Hash {
+        self.siblings.iter().enumerate().fold(leaf, |node, (level, &sibling)| {
+            if (self.index >> level) & 1 == 0 {
+                merkle_hash(node, sibling)
+            } else {
+                merkle_hash(sibling, node)
+            }
+        })
+    }
+
+    /// Verifies that `leaf` is included in the merkle tree whose root is `root`
+    pub fn verify(&self, leaf: Hash, root: Hash) -> bool {
+        // Reject indices with bits above the tree height, which would otherwise be ignored
+        let is_index_in_range = self.siblings.len() >= u32::BITS as usize || self.index >> self.siblings.len() == 0;
+        is_index_in_range && self.calc_root(leaf) == root
+    }
+}
+
+/// Builds the proof of inclusion of the leaf at `index`. Returns `None` if `index` is out of range.
+pub fn create_merkle_proof(hashes: impl ExactSizeIterator<Item = Hash>, index: usize) -> Option<MerkleProof> {
+    if index >= hashes.len() {
+        return None;
+    }
+    // Build the tree level by level, where missing nodes (past the leaves count) are `None`
+    let mut level = vec![None; hashes.len().next_power_of_two()];
+    for (i, hash) in hashes.enumerate() {
+        level[i] = Some(hash);
+    }
+    let mut siblings = Vec::new();
+    let mut position = index;
+    while level.len() > 1 {
+        siblings.push(level[position ^ 1].unwrap_or(ZERO_HASH));
+        level = level.chunks(2).map(|pair| pair[0].map(|left| merkle_hash(left, pair[1].unwrap_or(ZERO_HASH)))).collect();
+        position /= 2;
+    }
+    Some(MerkleProof { index: index as u32, siblings })
+}
+
 fn merkle_hash(left: Hash, right: Hash) -> Hash {
     let mut hasher = MerkleBranchHash::new();
     hasher.update(left).update(right);
     hasher.finalize()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merkle_proofs() {
+        for count in 1..=17u64 {
+            let leaves = (0..count).map(|i| Hash::from_u64_word(i + 1)).collect::<Vec<_>>();
+            let root = calc_merkle_root(leaves.iter().copied());
+            for (index, leaf) in leaves.iter().copied().enumerate() {
+                let proof = create_merkle_proof(leaves.iter().copied(), index).unwrap();
+                assert!(proof.verify(leaf, root), "count {count}, index {index}");
+                assert!(!proof.verify(Hash::from_u64_word(1000), root));
+                assert!(!MerkleProof { index: proof.index ^ 1, ..proof.clone() }.verify(leaf, root) || count == 1);
+                assert!(!MerkleProof { index: proof.index + (1 << proof.siblings.len()), ..proof }.verify(leaf, root));
+            }
+            assert!(create_merkle_proof(leaves.iter().copied(), leaves.len()).is_none());
+        }
+    }
+}
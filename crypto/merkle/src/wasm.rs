@@ -0,0 +1,13 @@
+use crate::MerkleProof;
+use kaspa_hashes::Hash;
+use wasm_bindgen::prelude::*;
+use workflow_wasm::error::Error;
+use workflow_wasm::result::Result;
+
+/// Verifies that `leaf` is included in the merkle tree whose root is `root`.
+/// The `proof` is an object as returned by the inclusion and acceptance proof RPC methods.
+#[wasm_bindgen(js_name = verifyMerkleProof)]
+pub fn verify_merkle_proof(leaf: JsValue, proof: JsValue, root: JsValue) -> Result<bool> {
+    let proof: MerkleProof = serde_wasm_bindgen::from_value(proof).map_err(|err| Error::Custom(err.to_string()))?;
+    Ok(proof.verify(Hash::try_from(leaf)?, Hash::try_from(root)?))
+}
@@ -52,8 +52,10 @@ where
         TData: DeserializeOwned, // We need `DeserializeOwned` since the slice coming from `db.get_pinned` has short lifetime
     {
         if let Some(data) = self.cache.get(&key) {
+            self.db.cache_stats().record(&self.prefix, true);
             Ok(data)
         } else {
+            self.db.cache_stats().record(&self.prefix, false);
             let db_key = DbKey::new(&self.prefix, key.clone());
             if let Some(slice) = self.db.get_pinned(&db_key)? {
                 let data: TData = bincode::deserialize(&slice)?;
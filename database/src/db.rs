@@ -1,12 +1,51 @@
-use rocksdb::{DBWithThreadMode, MultiThreaded};
-use std::path::PathBuf;
+use crate::stats::CacheStats;
+use rocksdb::{DBWithThreadMode, MultiThreaded, Options};
+use std::{
+    ops::Deref,
+    path::{Path, PathBuf},
+};
 
 pub use conn_builder::ConnBuilder;
 
 mod conn_builder;
 
-/// The DB type used for Kaspad stores
-pub type DB = DBWithThreadMode<MultiThreaded>;
+/// The DB type used for Kaspad stores. Derefs to the underlying RocksDB instance and owns
+/// the cache statistics of the stores built over it
+pub struct DB {
+    inner: DBWithThreadMode<MultiThreaded>,
+    cache_stats: CacheStats,
+}
+
+impl DB {
+    pub fn open<P: AsRef<Path>>(opts: &Options, path: P) -> Result<Self, rocksdb::Error> {
+        Ok(Self::new(DBWithThreadMode::open(opts, path)?))
+    }
+
+    pub fn open_default<P: AsRef<Path>>(path: P) -> Result<Self, rocksdb::Error> {
+        Ok(Self::new(DBWithThreadMode::open_default(path)?))
+    }
+
+    pub fn destroy<P: AsRef<Path>>(opts: &Options, path: P) -> Result<(), rocksdb::Error> {
+        DBWithThreadMode::<MultiThreaded>::destroy(opts, path)
+    }
+
+    fn new(inner: DBWithThreadMode<MultiThreaded>) -> Self {
+        Self { inner, cache_stats: CacheStats::default() }
+    }
+
+    /// Cache hit/miss counters of the stores of this DB
+    pub(crate) fn cache_stats(&self) -> &CacheStats {
+        &self.cache_stats
+    }
+}
+
+impl Deref for DB {
+    type Target = DBWithThreadMode<MultiThreaded>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
 
 /// Deletes an existing DB if it exists
 pub fn delete_db(db_dir: PathBuf) {
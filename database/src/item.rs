@@ -23,8 +23,10 @@ impl<T> CachedDbItem<T> {
         T: Clone + DeserializeOwned,
     {
         if let Some(item) = self.cached_item.read().clone() {
+            self.db.cache_stats().record(&self.key, true);
             return Ok(item);
         }
+        self.db.cache_stats().record(&self.key, false);
         if let Some(slice) = self.db.get_pinned(&self.key)? {
             let item: T = bincode::deserialize(&slice)?;
             *self.cached_item.write() = Some(item.clone());
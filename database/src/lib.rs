@@ -7,6 +7,7 @@ mod key;
 mod writer;
 
 pub mod registry;
+pub mod stats;
 pub mod utils;
 
 pub mod prelude {
@@ -0,0 +1,196 @@
+use crate::{db::DB, errors::StoreResult, registry::DatabaseStorePrefixes};
+use num_traits::FromPrimitive;
+use std::{
+    collections::BTreeMap,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+/// Cache hit/miss counters of the stores sharing a prefix. Aligned to (twice) the cache line size so that
+/// stores updating their counters concurrently do not contend on the same line
+#[derive(Default)]
+#[repr(align(128))]
+struct CacheCounters {
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+/// Cache hit/miss counters of the stores of a DB, indexed by store prefix
+pub(crate) struct CacheStats {
+    counters: Box<[CacheCounters]>,
+}
+
+impl Default for CacheStats {
+    fn default() -> Self {
+        Self { counters: (0..=u8::MAX).map(|_| CacheCounters::default()).collect() }
+    }
+}
+
+impl CacheStats {
+    /// Records a cache lookup of the store whose keys start with `prefix`
+    pub(crate) fn record(&self, prefix: &[u8], hit: bool) {
+        if let Some(&prefix) = prefix.first() {
+            let counters = &self.counters[prefix as usize];
+            let counter = if hit { &counters.hits } else { &counters.misses };
+            counter.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Returns the cache hits and misses of the stores with the given prefix
+    fn get(&self, prefix: u8) -> (u64, u64) {
+        let counters = &self.counters[prefix as usize];
+        (counters.hits.load(Ordering::Relaxed), counters.misses.load(Ordering::Relaxed))
+    }
+}
+
+/// Approximate storage and cache statistics of a single store, identified by its [`DatabaseStorePrefixes`] prefix
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StorePrefixStats {
+    pub prefix: u8,
+    pub name: String,
+    /// Approximate size in bytes of the data persisted to disk
+    pub size: u64,
+    /// Approximate number of live entries persisted to disk
+    pub entries: u64,
+    pub cache_hits: u64,
+    pub cache_misses: u64,
+}
+
+impl StorePrefixStats {
+    fn new(prefix: u8, (cache_hits, cache_misses): (u64, u64)) -> Self {
+        let name = match DatabaseStorePrefixes::from_u8(prefix) {
+            Some(prefix) => format!("{prefix:?}"),
+            None => format!("Unknown({prefix})"),
+        };
+        Self { prefix, name, cache_hits, cache_misses, ..Default::default() }
+    }
+}
+
+/// Position of a key within the DB key space, interpolated from its leading 8 bytes
+fn key_position(key: &[u8]) -> f64 {
+    let mut bytes = [0u8; 8];
+    let len = key.len().min(bytes.len());
+    bytes[..len].copy_from_slice(&key[..len]);
+    u64::from_be_bytes(bytes) as f64
+}
+
+/// Returns whether the DB holds any key within the `[prefix, prefix + 1)` range
+fn has_prefix(db: &DB, prefix: u8) -> StoreResult<bool> {
+    let mut iter = db.raw_iterator();
+    iter.seek([prefix]);
+    iter.status()?;
+    Ok(iter.key().is_some_and(|key| key.first() == Some(&prefix)))
+}
+
+/// Collects per-prefix statistics of the given DB, ordered by prefix.
+///
+/// Sizes and entry counts are estimated per live SST file over the `[prefix, prefix + 1)` range of each store.
+/// A file holding keys of several stores is split between the stores actually present in its key range,
+/// proportionally to the share of the range they cover. Data still held in memtables is not accounted for.
+pub fn store_prefix_stats(db: &DB) -> StoreResult<Vec<StorePrefixStats>> {
+    const PREFIX_RANGE: f64 = (1u64 << 56) as f64;
+
+    let mut estimates: BTreeMap<u8, (f64, f64)> = BTreeMap::new();
+    let mut present: BTreeMap<u8, bool> = BTreeMap::new();
+    let mut spanned = Vec::new();
+    for file in db.live_files()? {
+        let (Some(start_key), Some(end_key)) = (file.start_key.as_deref(), file.end_key.as_deref()) else {
+            continue;
+        };
+        let (Some(&first), Some(&last)) = (start_key.first(), end_key.first()) else {
+            continue;
+        };
+        let size = file.size as f64;
+        let entries = file.num_entries.saturating_sub(file.num_deletions) as f64;
+
+        // Collect the ranges of all stores overlapping the file key range
+        let (start, end) = (key_position(start_key), key_position(end_key));
+        spanned.clear();
+        for prefix in first..=last {
+            let lower = start.max(prefix as f64 * PREFIX_RANGE);
+            let upper = end.min((prefix as f64 + 1.0) * PREFIX_RANGE);
+            if first != last {
+                let is_present = match present.get(&prefix) {
+                    Some(&is_present) => is_present,
+                    None => *present.entry(prefix).or_insert(has_prefix(db, prefix)?),
+                };
+                if !is_present {
+                    continue;
+                }
+            }
+            spanned.push((prefix, (upper - lower).max(0.0)));
+        }
+        let total: f64 = spanned.iter().map(|&(_, range)| range).sum();
+        for &(prefix, range) in spanned.iter() {
+            let share = if total > 0.0 { range / total } else { 1.0 / spanned.len() as f64 };
+            let estimate = estimates.entry(prefix).or_default();
+            estimate.0 += size * share;
+            estimate.1 += entries * share;
+        }
+    }
+    Ok(estimates
+        .into_iter()
+        .map(|(prefix, (size, entries))| StorePrefixStats {
+            size: size.round() as u64,
+            entries: entries.round() as u64,
+            ..StorePrefixStats::new(prefix, db.cache_stats().get(prefix))
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{create_temp_db, prelude::ConnBuilder};
+
+    #[test]
+    fn test_store_prefix_stats() {
+        let (_lifetime, db) = create_temp_db!(ConnBuilder::default().with_files_limit(10));
+        let prefix = DatabaseStorePrefixes::Headers as u8;
+        for i in 0u32..100 {
+            let mut key = vec![prefix];
+            key.extend(i.to_le_bytes());
+            db.put(key, [0u8; 32]).unwrap();
+        }
+        // Nothing was flushed to SST files yet
+        assert!(store_prefix_stats(&db).unwrap().is_empty());
+
+        db.flush().unwrap();
+        let stats = store_prefix_stats(&db).unwrap();
+        assert_eq!(stats.len(), 1);
+        assert_eq!(stats[0].prefix, prefix);
+        assert_eq!(stats[0].name, "Headers");
+        assert_eq!(stats[0].entries, 100);
+        assert!(stats[0].size > 0);
+
+        // Cache lookups are accounted to the DB of the store only
+        db.cache_stats().record(&[prefix], true);
+        db.cache_stats().record(&[prefix], false);
+        let (_other_lifetime, other_db) = create_temp_db!(ConnBuilder::default().with_files_limit(10));
+        other_db.cache_stats().record(&[prefix], true);
+        let stats = store_prefix_stats(&db).unwrap();
+        assert_eq!((stats[0].cache_hits, stats[0].cache_misses), (1, 1));
+    }
+
+    #[test]
+    fn test_store_prefix_stats_spanning_files() {
+        let (_lifetime, db) = create_temp_db!(ConnBuilder::default().with_files_limit(10));
+        let (first, last) = (DatabaseStorePrefixes::Headers as u8, DatabaseStorePrefixes::Headers as u8 + 2);
+        // Keys of both stores are spread uniformly within their prefix range and flushed to a single file
+        for prefix in [first, last] {
+            for i in 0u32..100 {
+                let mut key = vec![prefix];
+                key.extend(i.wrapping_mul(0x9e37_79b9).to_be_bytes());
+                db.put(key, [0u8; 32]).unwrap();
+            }
+        }
+        db.flush().unwrap();
+        let file_size: u64 = db.live_files().unwrap().iter().map(|file| file.size as u64).sum();
+
+        // The prefix in between holds no keys and is not reported
+        let stats = store_prefix_stats(&db).unwrap();
+        assert_eq!(stats.iter().map(|stats| stats.prefix).collect::<Vec<_>>(), vec![first, last]);
+        assert_eq!(stats.iter().map(|stats| stats.entries).sum::<u64>(), 200);
+        assert!(stats.iter().all(|stats| (90..=110).contains(&stats.entries)), "{stats:?}");
+        assert!(stats.iter().map(|stats| stats.size).sum::<u64>().abs_diff(file_size) <= 1);
+    }
+}
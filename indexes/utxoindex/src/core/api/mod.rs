@@ -4,7 +4,7 @@ use kaspa_consensus_core::{
     BlockHashSet,
 };
 use kaspa_consensusmanager::spawn_blocking;
-use kaspa_database::prelude::StoreResult;
+use kaspa_database::{prelude::StoreResult, stats::StorePrefixStats};
 use kaspa_hashes::Hash;
 use parking_lot::RwLock;
 use std::{collections::HashSet, fmt::Debug, sync::Arc};
@@ -21,6 +21,11 @@ pub trait UtxoIndexApi: Send + Sync + Debug {
     /// Note: Use a read lock when accessing this method
     fn get_circulating_supply(&self) -> StoreResult<u64>;
 
+    /// Retrieve approximate storage and cache statistics of the utxoindex stores, ordered by store prefix.
+    ///
+    /// Note: Use a read lock when accessing this method
+    fn get_store_stats(&self) -> StoreResult<Vec<StorePrefixStats>>;
+
     /// Retrieve utxos by script public keys supply from the utxoindex db.
     ///
     /// Note: Use a read lock when accessing this method
@@ -67,6 +72,10 @@ impl UtxoIndexProxy {
         spawn_blocking(move || self.inner.read().get_circulating_supply()).await.unwrap()
     }
 
+    pub async fn get_store_stats(self) -> StoreResult<Vec<StorePrefixStats>> {
+        spawn_blocking(move || self.inner.read().get_store_stats()).await.unwrap()
+    }
+
     pub async fn get_utxos_by_script_public_keys(self, script_public_keys: ScriptPublicKeys) -> StoreResult<UtxoSetByScriptPublicKey> {
         spawn_blocking(move || self.inner.read().get_utxos_by_script_public_keys(script_public_keys)).await.unwrap()
     }
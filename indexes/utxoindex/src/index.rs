@@ -9,7 +9,10 @@ use crate::{
 use kaspa_consensus_core::{tx::ScriptPublicKeys, utxo::utxo_diff::UtxoDiff, BlockHashSet};
 use kaspa_consensusmanager::{ConsensusManager, ConsensusResetHandler};
 use kaspa_core::{info, trace};
-use kaspa_database::prelude::{StoreError, StoreResult, DB};
+use kaspa_database::{
+    prelude::{StoreError, StoreResult, DB},
+    stats::StorePrefixStats,
+};
 use kaspa_hashes::Hash;
 use kaspa_utils::arc::ArcExtensions;
 use parking_lot::RwLock;
@@ -56,6 +59,13 @@ impl UtxoIndexApi for UtxoIndex {
         self.store.get_utxos_by_script_public_key(script_public_keys)
     }
 
+    /// Retrieve approximate storage and cache statistics of the utxoindex stores.
+    fn get_store_stats(&self) -> StoreResult<Vec<StorePrefixStats>> {
+        trace!("[{0}] retrieving store stats", IDENT);
+
+        self.store.get_store_stats()
+    }
+
     /// Retrieve the stored tips of the utxoindex.
     fn get_utxo_index_tips(&self) -> StoreResult<Arc<BlockHashSet>> {
         trace!("[{0}] retrieving tips", IDENT);
@@ -5,7 +5,10 @@ use kaspa_consensus_core::{
     BlockHashSet,
 };
 use kaspa_core::trace;
-use kaspa_database::prelude::{StoreResult, DB};
+use kaspa_database::{
+    prelude::{StoreResult, DB},
+    stats::{store_prefix_stats, StorePrefixStats},
+};
 
 use crate::{
     model::UtxoSetByScriptPublicKey,
@@ -19,6 +22,7 @@ use crate::{
 
 #[derive(Clone)]
 pub struct Store {
+    db: Arc<DB>,
     utxoindex_tips_store: DbUtxoIndexTipsStore,
     circulating_supply_store: DbCirculatingSupplyStore,
     utxos_by_script_public_key_store: DbUtxoSetByScriptPublicKeyStore,
@@ -29,7 +33,8 @@ impl Store {
         Self {
             utxoindex_tips_store: DbUtxoIndexTipsStore::new(db.clone()),
             circulating_supply_store: DbCirculatingSupplyStore::new(db.clone()),
-            utxos_by_script_public_key_store: DbUtxoSetByScriptPublicKeyStore::new(db, 0),
+            utxos_by_script_public_key_store: DbUtxoSetByScriptPublicKeyStore::new(db.clone(), 0),
+            db,
         }
     }
 
@@ -86,6 +91,10 @@ impl Store {
         res
     }
 
+    pub fn get_store_stats(&self) -> StoreResult<Vec<StorePrefixStats>> {
+        store_prefix_stats(&self.db)
+    }
+
     pub fn get_tips(&self) -> StoreResult<Arc<BlockHashSet>> {
         self.utxoindex_tips_store.get()
     }
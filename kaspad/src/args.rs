@@ -27,24 +27,34 @@ pub struct Args {
     pub appdir: Option<String>,
     pub logdir: Option<String>,
     pub no_log_files: bool,
-    pub rpclisten: Option<ContextualNetAddress>,
-    pub rpclisten_borsh: Option<WrpcNetAddress>,
-    pub rpclisten_json: Option<WrpcNetAddress>,
+    pub rpclisten: Vec<ContextualNetAddress>,
+    pub rpclisten_borsh: Vec<WrpcNetAddress>,
+    pub rpclisten_json: Vec<WrpcNetAddress>,
     pub unsafe_rpc: bool,
     pub wrpc_verbose: bool,
     pub log_level: String,
     pub async_threads: usize,
     pub connect_peers: Vec<ContextualNetAddress>,
     pub add_peers: Vec<ContextualNetAddress>,
-    pub listen: Option<ContextualNetAddress>,
+    pub listen: Vec<ContextualNetAddress>,
+    pub disable_dns_seeding: bool,
+    pub dns_seeders: Vec<String>,
+    pub grpc_seed: Option<String>,
+    pub peers_file: Option<String>,
     pub user_agent_comments: Vec<String>,
     pub utxoindex: bool,
     pub reset_db: bool,
     pub outbound_target: usize,
     pub inbound_limit: usize,
     pub rpc_max_clients: usize,
+    pub rpc_max_connections_per_ip: usize,
+    pub rpc_max_requests_per_second: u32,
+    pub rpc_max_concurrent_requests: usize,
+    pub rpc_max_subscriptions: usize,
     pub enable_unsynced_mining: bool,
     pub enable_mainnet_mining: bool,
+    pub blocks_only: bool,
+    pub disable_rpc_submit: bool,
     pub testnet: bool,
     pub testnet_suffix: u32,
     pub devnet: bool,
@@ -55,6 +65,7 @@ pub struct Args {
     pub externalip: Option<IpAddress>,
     pub perf_metrics: bool,
     pub perf_metrics_interval_sec: u64,
+    pub profile: Option<u16>,
 
     #[cfg(feature = "devnet-prealloc")]
     pub num_prealloc_utxos: Option<u64>,
@@ -69,8 +80,8 @@ impl Default for Args {
         Self {
             appdir: Some("datadir".into()),
             no_log_files: false,
-            rpclisten_borsh: Some(WrpcNetAddress::Default),
-            rpclisten_json: Some(WrpcNetAddress::Default),
+            rpclisten_borsh: vec![WrpcNetAddress::Default],
+            rpclisten_json: vec![WrpcNetAddress::Default],
             unsafe_rpc: false,
             async_threads: num_cpus::get(),
             utxoindex: false,
@@ -78,8 +89,14 @@ impl Default for Args {
             outbound_target: 8,
             inbound_limit: 128,
             rpc_max_clients: 128,
+            rpc_max_connections_per_ip: 0,
+            rpc_max_requests_per_second: 0,
+            rpc_max_concurrent_requests: 0,
+            rpc_max_subscriptions: 0,
             enable_unsynced_mining: false,
             enable_mainnet_mining: false,
+            blocks_only: false,
+            disable_rpc_submit: false,
             testnet: false,
             testnet_suffix: 10,
             devnet: false,
@@ -87,16 +104,21 @@ impl Default for Args {
             archival: false,
             sanity: false,
             logdir: Some("".into()),
-            rpclisten: None,
+            rpclisten: vec![],
             wrpc_verbose: false,
             log_level: "INFO".into(),
             connect_peers: vec![],
             add_peers: vec![],
-            listen: None,
+            listen: vec![],
+            disable_dns_seeding: false,
+            dns_seeders: vec![],
+            grpc_seed: None,
+            peers_file: None,
             user_agent_comments: vec![],
             yes: false,
             perf_metrics: false,
             perf_metrics_interval_sec: 1,
+            profile: None,
             externalip: None,
 
             #[cfg(feature = "devnet-prealloc")]
@@ -114,10 +136,20 @@ impl Args {
         config.utxoindex = self.utxoindex;
         config.unsafe_rpc = self.unsafe_rpc;
         config.enable_unsynced_mining = self.enable_unsynced_mining;
+        config.blocks_only = self.blocks_only;
+        config.disable_rpc_submit = self.disable_rpc_submit;
+        config.rpc_max_connections_per_ip = self.rpc_max_connections_per_ip;
+        config.rpc_max_requests_per_second = self.rpc_max_requests_per_second;
+        config.rpc_max_concurrent_requests = self.rpc_max_concurrent_requests;
+        config.rpc_max_subscriptions = self.rpc_max_subscriptions;
         config.is_archival = self.archival;
         // TODO: change to `config.enable_sanity_checks = self.sanity` when we reach stable versions
         config.enable_sanity_checks = true;
         config.user_agent_comments = self.user_agent_comments.clone();
+        if !self.listen.is_empty() {
+            config.p2p_listen_addresses = self.listen.clone();
+        }
+        config.externalip = self.externalip;
 
         #[cfg(feature = "devnet-prealloc")]
         if let Some(num_prealloc_utxos) = self.num_prealloc_utxos {
@@ -183,19 +215,21 @@ pub fn cli() -> Command {
                 .long("rpclisten")
                 .value_name("IP[:PORT]")
                 .num_args(0..=1)
+                .action(ArgAction::Append)
                 .require_equals(true)
                 .value_parser(clap::value_parser!(ContextualNetAddress))
-                .help("Interface:port to listen for gRPC connections (default port: 16110, testnet: 16210)."),
+                .help("Add an interface:port to listen for gRPC connections (default port: 16110, testnet: 16210)."),
         )
         .arg(
             Arg::new("rpclisten-borsh")
                 .long("rpclisten-borsh")
                 .value_name("IP[:PORT]")
                 .num_args(0..=1)
+                .action(ArgAction::Append)
                 .require_equals(true)
                 .default_missing_value("default") // TODO: Find a way to use defaults.rpclisten_borsh
                 .value_parser(clap::value_parser!(WrpcNetAddress))
-                .help("Interface:port to listen for wRPC Borsh connections (default port: 17110, testnet: 17210)."),
+                .help("Add an interface:port to listen for wRPC Borsh connections (default port: 17110, testnet: 17210)."),
 
         )
         .arg(
@@ -203,10 +237,11 @@ pub fn cli() -> Command {
                 .long("rpclisten-json")
                 .value_name("IP[:PORT]")
                 .num_args(0..=1)
+                .action(ArgAction::Append)
                 .require_equals(true)
                 .default_missing_value("default") // TODO: Find a way to use defaults.rpclisten_json
                 .value_parser(clap::value_parser!(WrpcNetAddress))
-                .help("Interface:port to listen for wRPC JSON connections (default port: 18110, testnet: 18210)."),
+                .help("Add an interface:port to listen for wRPC JSON connections (default port: 18110, testnet: 18210)."),
         )
         .arg(arg!(--unsaferpc "Enable RPC commands which affect the state of the node"))
         .arg(
@@ -231,10 +266,34 @@ pub fn cli() -> Command {
             Arg::new("listen")
                 .long("listen")
                 .value_name("IP[:PORT]")
+                .action(ArgAction::Append)
                 .require_equals(true)
                 .value_parser(clap::value_parser!(ContextualNetAddress))
                 .help("Add an interface:port to listen for connections (default all interfaces port: 16111, testnet: 16211)."),
         )
+        .arg(arg!(--nodnsseed "Disable DNS seeding for peers"))
+        .arg(
+            Arg::new("dnsseed")
+                .long("dnsseed")
+                .value_name("HOSTNAME")
+                .action(ArgAction::Append)
+                .require_equals(true)
+                .help("Override the network DNS seeders with the specified hostname (may be specified multiple times)."),
+        )
+        .arg(
+            Arg::new("grpcseed")
+                .long("grpcseed")
+                .value_name("HOST[:PORT]")
+                .require_equals(true)
+                .help("Hostname of a gRPC server for seeding peers when DNS seeding yields no addresses."),
+        )
+        .arg(
+            Arg::new("peersfile")
+                .long("peersfile")
+                .value_name("PEERS_FILE")
+                .require_equals(true)
+                .help("File with peer addresses (one IP[:PORT] per line) to add to the address manager at startup."),
+        )
         .arg(
             Arg::new("outpeers")
                 .long("outpeers")
@@ -259,6 +318,38 @@ pub fn cli() -> Command {
                 .value_parser(clap::value_parser!(usize))
                 .help("Max number of RPC clients for standard connections (default: 128)."),
         )
+        .arg(
+            Arg::new("rpcmaxconnsperip")
+                .long("rpcmaxconnsperip")
+                .value_name("rpcmaxconnsperip")
+                .require_equals(true)
+                .value_parser(clap::value_parser!(usize))
+                .help("Max number of RPC connections from a single IP address (default: 0, no limit)."),
+        )
+        .arg(
+            Arg::new("rpcmaxreqspersec")
+                .long("rpcmaxreqspersec")
+                .value_name("rpcmaxreqspersec")
+                .require_equals(true)
+                .value_parser(clap::value_parser!(u32))
+                .help("Max number of requests per second issued by a single RPC connection (default: 0, no limit)."),
+        )
+        .arg(
+            Arg::new("rpcmaxconcurrentreqs")
+                .long("rpcmaxconcurrentreqs")
+                .value_name("rpcmaxconcurrentreqs")
+                .require_equals(true)
+                .value_parser(clap::value_parser!(usize))
+                .help("Max number of requests of a single RPC connection that may be processed concurrently (default: 0, no limit)."),
+        )
+        .arg(
+            Arg::new("rpcmaxsubscriptions")
+                .long("rpcmaxsubscriptions")
+                .value_name("rpcmaxsubscriptions")
+                .require_equals(true)
+                .value_parser(clap::value_parser!(usize))
+                .help("Max number of notification subscriptions of a single RPC connection, each UTXO address counting as one (default: 0, no limit)."),
+        )
         .arg(arg!(--"reset-db" "Reset database before starting node. It's needed when switching between subnetworks."))
         .arg(arg!(--"enable-unsynced-mining" "Allow the node to accept blocks from RPC while not synced (this flag is mainly used for testing)"))
         .arg(
@@ -268,6 +359,8 @@ pub fn cli() -> Command {
                 .hide(true)
                 .help("Allow mainnet mining (do not use unless you know what you are doing)"),
         )
+        .arg(arg!(--blocksonly "Do not request or accept transactions from remote peers"))
+        .arg(arg!(--"disable-rpc-submit" "Reject transactions submitted via RPC (mostly useful together with --blocksonly)"))
         .arg(arg!(--utxoindex "Enable the UTXO index"))
         .arg(arg!(--testnet "Use the test network"))
         .arg(
@@ -306,6 +399,14 @@ pub fn cli() -> Command {
             .require_equals(true)
             .value_parser(clap::value_parser!(u64))
             .help("Interval in seconds for performance metrics collection."),
+    )
+    .arg(
+        Arg::new("profile")
+            .long("profile")
+            .value_name("PORT")
+            .require_equals(true)
+            .value_parser(clap::value_parser!(u16).range(1024..))
+            .help("Enable HTTP profiling on the given local port (serves thread stacks, CPU profiles, allocator and pipeline statistics). Requires the `profiling` build feature."),
     );
 
     #[cfg(feature = "devnet-prealloc")]
@@ -325,22 +426,35 @@ pub fn parse_args() -> Args {
         appdir: m.get_one::<String>("appdir").cloned(),
         logdir: m.get_one::<String>("logdir").cloned(),
         no_log_files: m.get_one::<bool>("nologfiles").cloned().unwrap_or(defaults.no_log_files),
-        rpclisten: m.get_one::<ContextualNetAddress>("rpclisten").cloned(),
-        rpclisten_borsh: m.get_one::<WrpcNetAddress>("rpclisten-borsh").cloned(),
-        rpclisten_json: m.get_one::<WrpcNetAddress>("rpclisten-json").cloned(),
+        rpclisten: m.get_many::<ContextualNetAddress>("rpclisten").unwrap_or_default().copied().collect(),
+        rpclisten_borsh: m.get_many::<WrpcNetAddress>("rpclisten-borsh").unwrap_or_default().cloned().collect(),
+        rpclisten_json: m.get_many::<WrpcNetAddress>("rpclisten-json").unwrap_or_default().cloned().collect(),
         unsafe_rpc: m.get_one::<bool>("unsaferpc").cloned().unwrap_or(defaults.unsafe_rpc),
         wrpc_verbose: false,
         log_level: m.get_one::<String>("log_level").cloned().unwrap(),
         async_threads: m.get_one::<usize>("async_threads").cloned().unwrap_or(defaults.async_threads),
         connect_peers: m.get_many::<ContextualNetAddress>("connect-peers").unwrap_or_default().copied().collect(),
         add_peers: m.get_many::<ContextualNetAddress>("add-peers").unwrap_or_default().copied().collect(),
-        listen: m.get_one::<ContextualNetAddress>("listen").cloned(),
+        listen: m.get_many::<ContextualNetAddress>("listen").unwrap_or_default().copied().collect(),
+        disable_dns_seeding: m.get_one::<bool>("nodnsseed").cloned().unwrap_or(defaults.disable_dns_seeding),
+        dns_seeders: m.get_many::<String>("dnsseed").unwrap_or_default().cloned().collect(),
+        grpc_seed: m.get_one::<String>("grpcseed").cloned(),
+        peers_file: m.get_one::<String>("peersfile").cloned(),
         outbound_target: m.get_one::<usize>("outpeers").cloned().unwrap_or(defaults.outbound_target),
         inbound_limit: m.get_one::<usize>("maxinpeers").cloned().unwrap_or(defaults.inbound_limit),
         rpc_max_clients: m.get_one::<usize>("rpcmaxclients").cloned().unwrap_or(defaults.rpc_max_clients),
+        rpc_max_connections_per_ip: m.get_one::<usize>("rpcmaxconnsperip").cloned().unwrap_or(defaults.rpc_max_connections_per_ip),
+        rpc_max_requests_per_second: m.get_one::<u32>("rpcmaxreqspersec").cloned().unwrap_or(defaults.rpc_max_requests_per_second),
+        rpc_max_concurrent_requests: m
+            .get_one::<usize>("rpcmaxconcurrentreqs")
+            .cloned()
+            .unwrap_or(defaults.rpc_max_concurrent_requests),
+        rpc_max_subscriptions: m.get_one::<usize>("rpcmaxsubscriptions").cloned().unwrap_or(defaults.rpc_max_subscriptions),
         reset_db: m.get_one::<bool>("reset-db").cloned().unwrap_or(defaults.reset_db),
         enable_unsynced_mining: m.get_one::<bool>("enable-unsynced-mining").cloned().unwrap_or(defaults.enable_unsynced_mining),
         enable_mainnet_mining: m.get_one::<bool>("enable-mainnet-mining").cloned().unwrap_or(defaults.enable_mainnet_mining),
+        blocks_only: m.get_one::<bool>("blocksonly").cloned().unwrap_or(defaults.blocks_only),
+        disable_rpc_submit: m.get_one::<bool>("disable-rpc-submit").cloned().unwrap_or(defaults.disable_rpc_submit),
         utxoindex: m.get_one::<bool>("utxoindex").cloned().unwrap_or(defaults.utxoindex),
         testnet: m.get_one::<bool>("testnet").cloned().unwrap_or(defaults.testnet),
         testnet_suffix: m.get_one::<u32>("netsuffix").cloned().unwrap_or(defaults.testnet_suffix),
@@ -356,6 +470,7 @@ pub fn parse_args() -> Args {
             .get_one::<u64>("perf-metrics-interval-sec")
             .cloned()
             .unwrap_or(defaults.perf_metrics_interval_sec),
+        profile: m.get_one::<u16>("profile").cloned(),
 
         #[cfg(feature = "devnet-prealloc")]
         num_prealloc_utxos: m.get_one::<u64>("num-prealloc-utxos").cloned(),
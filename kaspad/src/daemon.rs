@@ -1,4 +1,10 @@
-use std::{fs, path::PathBuf, process::exit, sync::Arc, time::Duration};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::exit,
+    sync::Arc,
+    time::Duration,
+};
 
 use async_channel::unbounded;
 use kaspa_consensus_core::{
@@ -12,6 +18,8 @@ use kaspa_grpc_server::service::GrpcService;
 use kaspa_rpc_service::service::RpcCoreService;
 use kaspa_utils::networking::ContextualNetAddress;
 
+use kaspa_connectionmanager::{dns::DnsSeeder, DynAddressSeeder};
+
 use kaspa_addressmanager::AddressManager;
 use kaspa_consensus::pipeline::monitor::ConsensusMonitor;
 use kaspa_consensus::{consensus::factory::Factory as ConsensusFactory, pipeline::ProcessingCounters};
@@ -25,7 +33,12 @@ use kaspa_perf_monitor::builder::Builder as PerfMonitorBuilder;
 use kaspa_utxoindex::{api::UtxoIndexProxy, UtxoIndex};
 use kaspa_wrpc_server::service::{Options as WrpcServerOptions, ServerCounters as WrpcServerCounters, WrpcEncoding, WrpcService};
 
-use crate::args::Args;
+#[cfg(feature = "profiling")]
+use crate::profiling::ProfilingService;
+use crate::{
+    args::Args,
+    seed::{read_peers_file, GrpcSeeder},
+};
 
 const DEFAULT_DATA_DIR: &str = "datadir";
 const CONSENSUS_DB: &str = "consensus";
@@ -65,6 +78,9 @@ fn validate_args(args: &Args) -> ConfigResult<()> {
     if args.logdir.is_some() && args.no_log_files {
         return Err(ConfigError::MixedLogDirAndNoLogFiles);
     }
+    if !args.dns_seeders.is_empty() && args.disable_dns_seeding {
+        return Err(ConfigError::MixedDnsSeedAndNoDnsSeed);
+    }
     Ok(())
 }
 
@@ -218,12 +234,35 @@ do you confirm? (answer y/n or pass --yes to the Kaspad command line to confirm
 
     let connect_peers = args.connect_peers.iter().map(|x| x.normalize(config.default_p2p_port())).collect::<Vec<_>>();
     let add_peers = args.add_peers.iter().map(|x| x.normalize(config.default_p2p_port())).collect();
-    let p2p_server_addr = args.listen.unwrap_or(ContextualNetAddress::unspecified()).normalize(config.default_p2p_port());
+    let p2p_server_addrs = config.p2p_listen_addresses.iter().map(|x| x.normalize(config.default_p2p_port())).collect::<Vec<_>>();
     // connect_peers means no DNS seeding and no outbound peers
     let outbound_target = if connect_peers.is_empty() { args.outbound_target } else { 0 };
-    let dns_seeders = if connect_peers.is_empty() { config.dns_seeders } else { &[] };
+    let dns_seeder = if !connect_peers.is_empty() || args.disable_dns_seeding {
+        DnsSeeder::disabled()
+    } else if !args.dns_seeders.is_empty() {
+        DnsSeeder::new(args.dns_seeders.clone(), config.default_p2p_port())
+    } else {
+        DnsSeeder::new(config.dns_seeders.iter().map(|seeder| seeder.to_string()).collect(), config.default_p2p_port())
+    };
+    let address_seeder = match args.grpc_seed.as_ref() {
+        Some(host) if connect_peers.is_empty() => Some(Arc::new(GrpcSeeder::new(host, config.default_rpc_port())) as DynAddressSeeder),
+        _ => None,
+    };
+    let peers_file_addresses = match args.peers_file.as_ref() {
+        Some(peers_file) => match read_peers_file(Path::new(peers_file), config.default_p2p_port()) {
+            Ok(addresses) => addresses,
+            Err(err) => {
+                println!("Error reading peers file {}: {}", peers_file, err);
+                exit(1);
+            }
+        },
+        None => vec![],
+    };
 
-    let grpc_server_addr = args.rpclisten.unwrap_or(ContextualNetAddress::unspecified()).normalize(config.default_rpc_port());
+    let grpc_server_addrs = if args.rpclisten.is_empty() { vec![ContextualNetAddress::unspecified()] } else { args.rpclisten.clone() }
+        .into_iter()
+        .map(|x| x.normalize(config.default_rpc_port()))
+        .collect::<Vec<_>>();
 
     let core = Arc::new(Core::new());
 
@@ -275,6 +314,13 @@ do you confirm? (answer y/n or pass --yes to the Kaspad command line to confirm
     };
 
     let address_manager = AddressManager::new(config.clone(), meta_db);
+    if !peers_file_addresses.is_empty() {
+        info!("Adding {} addresses from the peers file", peers_file_addresses.len());
+        let mut address_manager = address_manager.lock();
+        for address in peers_file_addresses {
+            address_manager.add_address(address);
+        }
+    }
     let mining_manager =
         MiningManagerProxy::new(Arc::new(MiningManager::new(config.target_time_per_block, false, config.max_block_mass, None)));
 
@@ -290,11 +336,11 @@ do you confirm? (answer y/n or pass --yes to the Kaspad command line to confirm
         flow_context.clone(),
         connect_peers,
         add_peers,
-        p2p_server_addr,
+        p2p_server_addrs,
         outbound_target,
         args.inbound_limit,
-        dns_seeders,
-        config.default_p2p_port(),
+        dns_seeder,
+        address_seeder,
     ));
 
     let rpc_core_service = Arc::new(RpcCoreService::new(
@@ -306,12 +352,12 @@ do you confirm? (answer y/n or pass --yes to the Kaspad command line to confirm
         index_service.as_ref().map(|x| x.utxoindex().unwrap()),
         config,
         core.clone(),
-        processing_counters,
+        processing_counters.clone(),
         wrpc_borsh_counters.clone(),
         wrpc_json_counters.clone(),
         perf_monitor.clone(),
     ));
-    let grpc_service = Arc::new(GrpcService::new(grpc_server_addr, rpc_core_service.clone(), args.rpc_max_clients));
+    let grpc_service = Arc::new(GrpcService::new(grpc_server_addrs, rpc_core_service.clone(), args.rpc_max_clients));
 
     // Create an async runtime and register the top-level async services
     let async_runtime = Arc::new(AsyncRuntime::new(args.async_threads));
@@ -325,29 +371,34 @@ do you confirm? (answer y/n or pass --yes to the Kaspad command line to confirm
     async_runtime.register(p2p_service);
     async_runtime.register(consensus_monitor);
     async_runtime.register(perf_monitor);
+    #[cfg(feature = "profiling")]
+    if let Some(port) = args.profile {
+        async_runtime.register(Arc::new(ProfilingService::new(port, consensus_manager.clone(), processing_counters)));
+    }
+    #[cfg(not(feature = "profiling"))]
+    if args.profile.is_some() {
+        kaspa_core::warn!("Ignoring --profile since kaspad was built without the `profiling` feature");
+    }
     let wrpc_service_tasks: usize = 2; // num_cpus::get() / 2;
                                        // Register wRPC servers based on command line arguments
-    [
+    for (listen_addresses, encoding, wrpc_server_counters) in [
         (args.rpclisten_borsh.clone(), WrpcEncoding::Borsh, wrpc_borsh_counters),
         (args.rpclisten_json.clone(), WrpcEncoding::SerdeJson, wrpc_json_counters),
-    ]
-    .into_iter()
-    .filter_map(|(listen_address, encoding, wrpc_server_counters)| {
-        listen_address.map(|listen_address| {
-            Arc::new(WrpcService::new(
+    ] {
+        for listen_address in listen_addresses {
+            async_runtime.register(Arc::new(WrpcService::new(
                 wrpc_service_tasks,
                 Some(rpc_core_service.clone()),
                 &encoding,
-                wrpc_server_counters,
+                wrpc_server_counters.clone(),
                 WrpcServerOptions {
                     listen_address: listen_address.to_address(&network.network_type, &encoding).to_string(), // TODO: use a normalized ContextualNetAddress instead of a String
                     verbose: args.wrpc_verbose,
                     ..WrpcServerOptions::default()
                 },
-            ))
-        })
-    })
-    .for_each(|server| async_runtime.register(server));
+            )));
+        }
+    }
 
     // Consensus must start first in order to init genesis in stores
     core.bind(consensus_manager);
@@ -1,2 +1,5 @@
 pub mod args;
 pub mod daemon;
+#[cfg(feature = "profiling")]
+pub mod profiling;
+pub mod seed;
@@ -0,0 +1,325 @@
+//! An opt-in HTTP endpoint exposing runtime diagnostics of the node: threads, CPU profiles,
+//! allocator statistics and the state of the consensus pipeline. Available with the `profiling` feature.
+//!
+//! The server only binds the loopback interface and is meant for debugging a live node, for instance with
+//! `curl http://127.0.0.1:<port>/debug/pipeline`.
+//!
+//! Thread stacks are captured by attaching a debugger (`eu-stack` from elfutils, or else `gdb`) to the node
+//! process, since unwinding foreign threads in-process from a signal handler is not async-signal-safe. Only the
+//! spawned debugger is allowed to trace the node, and only while the dump runs.
+
+use hyper::{
+    service::{make_service_fn, service_fn},
+    Body, Method, Request, Response, Server, StatusCode,
+};
+use kaspa_consensus::pipeline::ProcessingCounters;
+use kaspa_consensusmanager::ConsensusManager;
+use kaspa_core::{
+    info,
+    task::service::{AsyncService, AsyncServiceError, AsyncServiceFuture},
+    trace,
+};
+use kaspa_utils::triggers::SingleTrigger;
+use std::{
+    convert::Infallible,
+    fmt::Write,
+    net::{Ipv4Addr, SocketAddr},
+    sync::Arc,
+};
+
+const PROFILING_SERVICE: &str = "profiling-service";
+
+/// Default duration of a CPU profile, in seconds
+const DEFAULT_PROFILE_SECONDS: u64 = 30;
+
+/// Maximum duration of a CPU profile, in seconds
+const MAX_PROFILE_SECONDS: u64 = 300;
+
+const INDEX: &str = "\
+/debug/stacks               stack traces of all the threads of the process (requires eu-stack or gdb)
+/debug/threads              threads of the process and their scheduling state
+/debug/profile?seconds=N    CPU profile of the next N seconds, as a flamegraph SVG (default: 30, max: 300)
+/debug/allocator            allocator statistics
+/debug/pipeline             consensus pipeline queue depths and processing counters
+";
+
+struct Context {
+    consensus_manager: Arc<ConsensusManager>,
+    counters: Arc<ProcessingCounters>,
+}
+
+pub struct ProfilingService {
+    address: SocketAddr,
+    context: Arc<Context>,
+    shutdown: SingleTrigger,
+}
+
+impl ProfilingService {
+    /// Builds a service serving on the given port of the loopback interface
+    pub fn new(port: u16, consensus_manager: Arc<ConsensusManager>, counters: Arc<ProcessingCounters>) -> Self {
+        let address = SocketAddr::from((Ipv4Addr::LOCALHOST, port));
+        Self { address, context: Arc::new(Context { consensus_manager, counters }), shutdown: SingleTrigger::default() }
+    }
+}
+
+async fn handle(context: Arc<Context>, request: Request<Body>) -> Result<Response<Body>, Infallible> {
+    if request.method() != Method::GET {
+        return Ok(text_response(StatusCode::METHOD_NOT_ALLOWED, "Only GET requests are supported\n".to_string()));
+    }
+    let response = match request.uri().path() {
+        "/" | "/debug" => text_response(StatusCode::OK, INDEX.to_string()),
+        "/debug/stacks" => match tokio::task::spawn_blocking(stacks::dump).await {
+            Ok(Ok(dump)) => text_response(StatusCode::OK, dump),
+            Ok(Err(err)) => text_response(StatusCode::SERVICE_UNAVAILABLE, format!("{err}\n")),
+            Err(err) => text_response(StatusCode::INTERNAL_SERVER_ERROR, format!("{err}\n")),
+        },
+        "/debug/threads" => match threads::list() {
+            Ok(list) => text_response(StatusCode::OK, list),
+            Err(err) => text_response(StatusCode::NOT_IMPLEMENTED, format!("{err}\n")),
+        },
+        "/debug/profile" => {
+            let seconds = match profile_seconds(request.uri().query()) {
+                Ok(seconds) => seconds,
+                Err(err) => return Ok(text_response(StatusCode::BAD_REQUEST, format!("{err}\n"))),
+            };
+            match tokio::task::spawn_blocking(move || cpu::profile(seconds)).await {
+                Ok(Ok(svg)) => Response::builder().header("Content-Type", "image/svg+xml").body(Body::from(svg)).unwrap(),
+                Ok(Err(err)) => text_response(StatusCode::SERVICE_UNAVAILABLE, format!("{err}\n")),
+                Err(err) => text_response(StatusCode::INTERNAL_SERVER_ERROR, format!("{err}\n")),
+            }
+        }
+        "/debug/allocator" => match allocator::stats() {
+            Ok(stats) => text_response(StatusCode::OK, stats),
+            Err(err) => text_response(StatusCode::NOT_IMPLEMENTED, format!("{err}\n")),
+        },
+        "/debug/pipeline" => text_response(StatusCode::OK, pipeline_report(&context)),
+        _ => text_response(StatusCode::NOT_FOUND, INDEX.to_string()),
+    };
+    Ok(response)
+}
+
+fn text_response(status: StatusCode, body: String) -> Response<Body> {
+    Response::builder().status(status).header("Content-Type", "text/plain; charset=utf-8").body(Body::from(body)).unwrap()
+}
+
+fn profile_seconds(query: Option<&str>) -> Result<u64, String> {
+    let value = query.into_iter().flat_map(|query| query.split('&')).find_map(|pair| pair.strip_prefix("seconds="));
+    match value {
+        None => Ok(DEFAULT_PROFILE_SECONDS),
+        Some(value) => match value.parse::<u64>() {
+            Ok(seconds) if (1..=MAX_PROFILE_SECONDS).contains(&seconds) => Ok(seconds),
+            _ => Err(format!("seconds must be an integer between 1 and {MAX_PROFILE_SECONDS}")),
+        },
+    }
+}
+
+fn pipeline_report(context: &Context) -> String {
+    let depths = context.consensus_manager.consensus().pipeline_queue_depths();
+    let counters = context.counters.snapshot();
+    let mut report = String::new();
+    writeln!(report, "Queue depths").unwrap();
+    writeln!(report, "  header processor:  {}", depths.header_processor).unwrap();
+    writeln!(report, "  body processor:    {}", depths.body_processor).unwrap();
+    writeln!(report, "  virtual processor: {}", depths.virtual_processor).unwrap();
+    writeln!(report, "  pruning processor: {}", depths.pruning_processor).unwrap();
+    writeln!(report, "Processing counters").unwrap();
+    writeln!(report, "  blocks submitted:       {}", counters.blocks_submitted).unwrap();
+    writeln!(report, "  headers processed:      {}", counters.header_counts).unwrap();
+    writeln!(report, "  bodies processed:       {}", counters.body_counts).unwrap();
+    writeln!(report, "  transactions processed: {}", counters.txs_counts).unwrap();
+    writeln!(report, "  parent references:      {}", counters.dep_counts).unwrap();
+    writeln!(report, "  UTXO-validated blocks:  {}", counters.chain_block_counts).unwrap();
+    report
+}
+
+#[cfg(target_os = "linux")]
+mod stacks {
+    use std::{
+        io::{self, Write},
+        process::{Command, Output, Stdio},
+        sync::Mutex,
+    };
+
+    /// Debuggers tried in order, each followed by the arguments preceding the pid to attach to
+    const DEBUGGERS: [(&str, &[&str]); 2] = [("eu-stack", &["-p"]), ("gdb", &["-batch", "-nx", "-ex", "thread apply all bt", "-p"])];
+
+    /// Exit status of the shell when the command to execute is not found
+    const COMMAND_NOT_FOUND: i32 = 127;
+
+    /// Serializes the dumps since a process designates a single ptracer at a time
+    static DUMP_LOCK: Mutex<()> = Mutex::new(());
+
+    /// Dumps the stacks of all the threads of the process by attaching a debugger to it
+    pub fn dump() -> Result<String, String> {
+        let _guard = DUMP_LOCK.lock().unwrap_or_else(|err| err.into_inner());
+        let pid = std::process::id().to_string();
+        for (debugger, args) in DEBUGGERS {
+            let output = run_debugger(debugger, args, &pid).map_err(|err| format!("Failed running {debugger}: {err}"))?;
+            if output.status.code() == Some(COMMAND_NOT_FOUND) {
+                continue;
+            }
+            if !output.status.success() {
+                return Err(format!("{debugger} failed ({}): {}", output.status, String::from_utf8_lossy(&output.stderr).trim()));
+            }
+            return Ok(String::from_utf8_lossy(&output.stdout).into_owned());
+        }
+        Err("Capturing thread stacks requires eu-stack (elfutils) or gdb to be installed".to_string())
+    }
+
+    /// Runs the debugger through a shell which waits for a line on its stdin before executing it. With Yama
+    /// `ptrace_scope = 1`, only ancestors may attach to a process, so the pid of the spawned shell, which the
+    /// debugger then inherits, is designated as the only process allowed to attach to the node until it exits.
+    fn run_debugger(debugger: &str, args: &[&str], pid: &str) -> io::Result<Output> {
+        let mut child = Command::new("sh")
+            .arg("-c")
+            .arg(r#"read _ && exec "$0" "$@""#)
+            .arg(debugger)
+            .args(args)
+            .arg(pid)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+        unsafe { libc::prctl(libc::PR_SET_PTRACER, child.id() as libc::c_ulong, 0, 0, 0) };
+        // Dropping stdin right after releasing the shell leaves the debugger with no input
+        let released = child.stdin.take().expect("stdin is piped").write_all(b"\n");
+        let output = child.wait_with_output();
+        unsafe { libc::prctl(libc::PR_SET_PTRACER, 0, 0, 0, 0) };
+        released.and(output)
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+mod stacks {
+    pub fn dump() -> Result<String, String> {
+        Err("Thread stacks are only available on Linux".to_string())
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod threads {
+    use std::{fmt::Write, fs};
+
+    fn thread_state(tid: u64) -> String {
+        // The state follows the parenthesized thread name in the stat file
+        fs::read_to_string(format!("/proc/self/task/{tid}/stat"))
+            .ok()
+            .and_then(|stat| stat.rsplit_once(')').and_then(|(_, rest)| rest.split_whitespace().next().map(|s| s.to_string())))
+            .unwrap_or_else(|| "?".to_string())
+    }
+
+    /// Lists the threads of the process along with their name and scheduling state
+    pub fn list() -> Result<String, String> {
+        let mut tids = fs::read_dir("/proc/self/task")
+            .map_err(|err| err.to_string())?
+            .filter_map(|entry| entry.ok()?.file_name().to_str()?.parse::<u64>().ok())
+            .collect::<Vec<_>>();
+        tids.sort_unstable();
+
+        let mut list = String::new();
+        writeln!(list, "Process {} has {} threads", std::process::id(), tids.len()).unwrap();
+        for tid in tids {
+            let name = fs::read_to_string(format!("/proc/self/task/{tid}/comm")).unwrap_or_default();
+            writeln!(list, "  {} \"{}\" ({})", tid, name.trim(), thread_state(tid)).unwrap();
+        }
+        Ok(list)
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+mod threads {
+    pub fn list() -> Result<String, String> {
+        Err("Thread listing is only available on Linux".to_string())
+    }
+}
+
+#[cfg(unix)]
+mod cpu {
+    use std::time::Duration;
+
+    /// Samples the CPU usage of the process for `seconds` and renders it as a flamegraph SVG
+    pub fn profile(seconds: u64) -> Result<Vec<u8>, String> {
+        let guard = pprof::ProfilerGuardBuilder::default()
+            .frequency(99)
+            .blocklist(&["libc", "libgcc", "pthread", "vdso"])
+            .build()
+            .map_err(|err| format!("Failed starting the CPU profiler: {err}"))?;
+        std::thread::sleep(Duration::from_secs(seconds));
+        let report = guard.report().build().map_err(|err| format!("Failed building the CPU profile: {err}"))?;
+        if report.data.is_empty() {
+            return Err("No CPU samples were collected, the node was idle".to_string());
+        }
+        let mut svg = Vec::new();
+        report.flamegraph(&mut svg).map_err(|err| format!("Failed rendering the CPU profile: {err}"))?;
+        Ok(svg)
+    }
+}
+
+#[cfg(not(unix))]
+mod cpu {
+    pub fn profile(_seconds: u64) -> Result<Vec<u8>, String> {
+        Err("CPU profiling is not available on this platform".to_string())
+    }
+}
+
+#[cfg(all(target_os = "linux", target_env = "gnu"))]
+mod allocator {
+    use std::fmt::Write;
+
+    pub fn stats() -> Result<String, String> {
+        let info = unsafe { libc::mallinfo2() };
+        let mut stats = String::new();
+        writeln!(stats, "Allocator: glibc malloc").unwrap();
+        writeln!(stats, "  allocated from system (heap): {} bytes", info.arena).unwrap();
+        writeln!(stats, "  allocated from system (mmap): {} bytes in {} regions", info.hblkhd, info.hblks).unwrap();
+        writeln!(stats, "  in use:                       {} bytes", info.uordblks).unwrap();
+        writeln!(stats, "  free:                         {} bytes in {} chunks", info.fordblks, info.ordblks).unwrap();
+        writeln!(stats, "  releasable (top chunk):       {} bytes", info.keepcost).unwrap();
+        Ok(stats)
+    }
+}
+
+#[cfg(not(all(target_os = "linux", target_env = "gnu")))]
+mod allocator {
+    pub fn stats() -> Result<String, String> {
+        Err("Allocator statistics are only available with the glibc allocator".to_string())
+    }
+}
+
+impl AsyncService for ProfilingService {
+    fn ident(self: Arc<Self>) -> &'static str {
+        PROFILING_SERVICE
+    }
+
+    fn start(self: Arc<Self>) -> AsyncServiceFuture {
+        trace!("{} starting", PROFILING_SERVICE);
+        let shutdown_signal = self.shutdown.listener.clone();
+        let context = self.context.clone();
+        let make_service = make_service_fn(move |_| {
+            let context = context.clone();
+            async move { Ok::<_, Infallible>(service_fn(move |request| handle(context.clone(), request))) }
+        });
+
+        Box::pin(async move {
+            let server = Server::try_bind(&self.address).map_err(|err| AsyncServiceError::Service(err.to_string()))?;
+            info!("Profiling server listening on http://{}", self.address);
+            server
+                .serve(make_service)
+                .with_graceful_shutdown(shutdown_signal)
+                .await
+                .map_err(|err| AsyncServiceError::Service(err.to_string()))
+        })
+    }
+
+    fn signal_exit(self: Arc<Self>) {
+        trace!("sending an exit signal to {}", PROFILING_SERVICE);
+        self.shutdown.trigger.trigger();
+    }
+
+    fn stop(self: Arc<Self>) -> AsyncServiceFuture {
+        Box::pin(async move {
+            trace!("{} stopped", PROFILING_SERVICE);
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,109 @@
+use std::{fs, io, path::Path, str::FromStr};
+
+use async_trait::async_trait;
+use kaspa_addressmanager::NetAddress;
+use kaspa_connectionmanager::AddressSeeder;
+use kaspa_core::warn;
+use kaspa_grpc_client::GrpcClient;
+use kaspa_rpc_core::{api::rpc::RpcApi, notify::mode::NotificationMode};
+use kaspa_utils::networking::ContextualNetAddress;
+
+/// Queries the `get_peer_addresses` RPC method of a remote node over gRPC
+pub struct GrpcSeeder {
+    url: String,
+}
+
+impl GrpcSeeder {
+    /// Builds a seeder for `host`, given as `HOST[:PORT]`. The default RPC port is used if no port is specified.
+    pub fn new(host: &str, default_port: u16) -> Self {
+        Self { url: format!("grpc://{}", with_default_port(host, default_port)) }
+    }
+}
+
+#[async_trait]
+impl AddressSeeder for GrpcSeeder {
+    fn name(&self) -> String {
+        format!("gRPC seeder {}", self.url)
+    }
+
+    async fn seed(&self) -> Result<Vec<NetAddress>, String> {
+        let client = GrpcClient::connect(NotificationMode::Direct, self.url.clone(), false, None, false, None)
+            .await
+            .map_err(|err| err.to_string())?;
+        let response = client.get_peer_addresses().await.map_err(|err| err.to_string());
+        client.disconnect().await.map_err(|err| err.to_string())?;
+        Ok(response?.known_addresses)
+    }
+}
+
+fn with_default_port(host: &str, default_port: u16) -> String {
+    if let Ok(address) = ContextualNetAddress::from_str(host) {
+        return address.normalize(default_port).to_string();
+    }
+    match host.rsplit_once(':') {
+        Some((_, port)) if port.parse::<u16>().is_ok() => host.to_string(),
+        _ => format!("{host}:{default_port}"),
+    }
+}
+
+/// Reads a peers file made of one `IP[:PORT]` entry per line. Empty lines and lines starting
+/// with `#` are ignored, entries which cannot be parsed are skipped with a warning.
+pub fn read_peers_file(path: &Path, default_port: u16) -> io::Result<Vec<NetAddress>> {
+    let content = fs::read_to_string(path)?;
+    Ok(parse_peers(&content, default_port))
+}
+
+fn parse_peers(content: &str, default_port: u16) -> Vec<NetAddress> {
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| match ContextualNetAddress::from_str(line) {
+            Ok(address) => Some(address.normalize(default_port)),
+            Err(err) => {
+                warn!("Ignoring invalid peers file entry {}: {}", line, err);
+                None
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kaspa_utils::networking::IpAddress;
+
+    #[test]
+    fn test_with_default_port() {
+        assert_eq!(with_default_port("127.0.0.1", 16110), "127.0.0.1:16110");
+        assert_eq!(with_default_port("127.0.0.1:1234", 16110), "127.0.0.1:1234");
+        assert_eq!(with_default_port("[::1]", 16110), "[::1]:16110");
+        assert_eq!(with_default_port("[::1]:1234", 16110), "[::1]:1234");
+        assert_eq!(with_default_port("seed.example.org", 16110), "seed.example.org:16110");
+        assert_eq!(with_default_port("seed.example.org:1234", 16110), "seed.example.org:1234");
+        // A non-numeric suffix is not a port
+        assert_eq!(with_default_port("seed.example.org:rpc", 16110), "seed.example.org:rpc:16110");
+    }
+
+    #[test]
+    fn test_parse_peers() {
+        let content = "
+            # a comment
+            127.0.0.1
+            10.0.0.1:1234
+
+            [::1]:4321
+            not an address
+            ";
+        let peers = parse_peers(content, 16111);
+        assert_eq!(
+            peers,
+            vec![
+                NetAddress::new(IpAddress::from_str("127.0.0.1").unwrap(), 16111),
+                NetAddress::new(IpAddress::from_str("10.0.0.1").unwrap(), 1234),
+                NetAddress::new(IpAddress::from_str("::1").unwrap(), 4321),
+            ]
+        );
+        assert!(parse_peers("# only comments\n\n", 16111).is_empty());
+    }
+}
@@ -13,7 +13,9 @@ use crate::{
     },
     model::{
         candidate_tx::CandidateTransaction,
+        mempool_stats::MempoolStats,
         owner_txs::{GroupedOwnerTransactions, ScriptPublicKeySet},
+        tx_validation::TransactionValidation,
     },
 };
 use kaspa_consensus_core::{
@@ -131,6 +133,17 @@ impl MiningManager {
         Ok(self.mempool.write().validate_and_insert_transaction(consensus, transaction, priority, orphan)?)
     }
 
+    /// validate_transaction runs the same validations as validate_and_insert_transaction
+    /// but neither inserts the transaction into the mempool nor modifies it otherwise.
+    pub fn validate_transaction(
+        &self,
+        consensus: &dyn ConsensusApi,
+        transaction: Transaction,
+        orphan: Orphan,
+    ) -> TransactionValidation {
+        self.mempool.read().validate_transaction(consensus, transaction, orphan)
+    }
+
     /// Exposed only for tests. Ordinary users should let the mempool create the mutable tx internally
     #[cfg(test)]
     pub fn validate_and_insert_mutable_transaction(
@@ -185,6 +198,11 @@ impl MiningManager {
         self.mempool.read().transaction_count(include_transaction_pool, include_orphan_pool)
     }
 
+    /// Returns a snapshot of the content of the mempool.
+    pub fn mempool_stats(&self) -> MempoolStats {
+        self.mempool.read().stats()
+    }
+
     pub fn handle_new_block_transactions(
         &self,
         consensus: &dyn ConsensusApi,
@@ -245,6 +263,17 @@ impl MiningManagerProxy {
         consensus.clone().spawn_blocking(move |c| self.inner.validate_and_insert_transaction(c, transaction, priority, orphan)).await
     }
 
+    /// validate_transaction runs the same validations as validate_and_insert_transaction
+    /// but neither inserts the transaction into the mempool nor modifies it otherwise.
+    pub async fn validate_transaction(
+        self,
+        consensus: &ConsensusProxy,
+        transaction: Transaction,
+        orphan: Orphan,
+    ) -> TransactionValidation {
+        consensus.clone().spawn_blocking(move |c| self.inner.validate_transaction(c, transaction, orphan)).await
+    }
+
     pub async fn handle_new_block_transactions(
         self,
         consensus: &ConsensusProxy,
@@ -287,6 +316,10 @@ impl MiningManagerProxy {
         spawn_blocking(move || self.inner.transaction_count(include_transaction_pool, include_orphan_pool)).await.unwrap()
     }
 
+    pub async fn mempool_stats(self) -> MempoolStats {
+        spawn_blocking(move || self.inner.mempool_stats()).await.unwrap()
+    }
+
     pub async fn get_all_transactions(
         self,
         include_transaction_pool: bool,
@@ -107,6 +107,42 @@ mod tests {
         );
     }
 
+    // test_validate_transaction verifies that validating a transaction reports its mass, fee and orphan status
+    // as well as the mempool rejection reasons, without modifying the mempool.
+    #[test]
+    fn test_validate_transaction() {
+        let consensus = Arc::new(ConsensusMock::new());
+        let mining_manager = MiningManager::new(TARGET_TIME_PER_BLOCK, false, MAX_BLOCK_MASS, None);
+
+        let transaction = create_child_and_parent_txs_and_add_parent_to_consensus(&consensus);
+        let validation = mining_manager.validate_transaction(consensus.as_ref(), transaction.clone(), Orphan::Forbidden);
+        assert!(validation.is_valid());
+        assert!(!validation.is_orphan);
+        assert_eq!(validation.fee, Some(1000));
+        assert_eq!(validation.mass, consensus.calculate_transaction_mass(&transaction));
+        assert!(validation.fee_rate().unwrap() > 0.0);
+        assert_eq!(mining_manager.transaction_count(true, true), 0, "validating a transaction must not insert it into the mempool");
+
+        // Once inserted, the transaction is rejected as a duplicate, still reporting its mass
+        mining_manager
+            .validate_and_insert_transaction(consensus.as_ref(), transaction.clone(), Priority::Low, Orphan::Forbidden)
+            .unwrap();
+        let validation = mining_manager.validate_transaction(consensus.as_ref(), transaction.clone(), Orphan::Forbidden);
+        assert!(matches!(validation.rejection, Some(RuleError::RejectDuplicate(id)) if id == transaction.id()));
+        assert_eq!(validation.mass, consensus.calculate_transaction_mass(&transaction));
+
+        // A transaction spending unknown outputs is an orphan
+        let (_, orphan) = create_parent_and_children_transactions(&consensus, vec![400 * SOMPI_PER_KASPA]);
+        let validation = mining_manager.validate_transaction(consensus.as_ref(), orphan.clone(), Orphan::Forbidden);
+        assert!(matches!(validation.rejection, Some(RuleError::RejectDisallowedOrphan(id)) if id == orphan.id()));
+        assert!(validation.is_orphan);
+        let validation = mining_manager.validate_transaction(consensus.as_ref(), orphan, Orphan::Allowed);
+        assert!(validation.is_valid());
+        assert!(validation.is_orphan);
+        assert_eq!(validation.fee, None);
+        assert_eq!(mining_manager.transaction_count(true, true), 1);
+    }
+
     /// test_simulated_error_in_consensus verifies that a predefined result is actually
     /// returned by the consensus mock as expected when the mempool tries to validate and
     /// insert a transaction.
@@ -1,5 +1,6 @@
 use crate::model::{
     candidate_tx::CandidateTransaction,
+    mempool_stats::MempoolStats,
     owner_txs::{GroupedOwnerTransactions, ScriptPublicKeySet},
 };
 
@@ -119,6 +120,14 @@ impl Mempool {
         count
     }
 
+    pub(crate) fn stats(&self) -> MempoolStats {
+        let mut stats = MempoolStats::new(self.orphan_pool.len() as u64);
+        for transaction in self.transaction_pool.all().values() {
+            stats.add_transaction(transaction.mtx.calculated_mass.unwrap(), transaction.mtx.calculated_fee.unwrap());
+        }
+        stats
+    }
+
     pub(crate) fn block_candidate_transactions(&self) -> Vec<CandidateTransaction> {
         self.transaction_pool.all_ready_transactions()
     }
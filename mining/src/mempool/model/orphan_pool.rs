@@ -72,9 +72,7 @@ impl OrphanPool {
             // TODO: determine how/why this may happen
             return Ok(());
         }
-        self.check_orphan_duplicate(&transaction)?;
-        self.check_orphan_mass(&transaction)?;
-        self.check_orphan_double_spend(&transaction)?;
+        self.check_orphan(&transaction)?;
         // Make sure there is room in the pool for the new transaction
         self.limit_orphan_pool_size(1)?;
         self.add_orphan(consensus, transaction, priority)?;
@@ -100,6 +98,13 @@ impl OrphanPool {
         Ok(())
     }
 
+    /// Checks whether the transaction is eligible for the orphan pool, regardless of the pool capacity
+    pub(crate) fn check_orphan(&self, transaction: &MutableTransaction) -> RuleResult<()> {
+        self.check_orphan_duplicate(transaction)?;
+        self.check_orphan_mass(transaction)?;
+        self.check_orphan_double_spend(transaction)
+    }
+
     fn check_orphan_mass(&self, transaction: &MutableTransaction) -> RuleResult<()> {
         if transaction.calculated_mass.unwrap() > self.config.maximum_orphan_transaction_mass {
             return Err(RuleError::RejectBadOrphanMass(
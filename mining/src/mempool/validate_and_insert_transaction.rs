@@ -1,9 +1,12 @@
 use std::sync::Arc;
 
-use crate::mempool::{
-    errors::{RuleError, RuleResult},
-    model::{pool::Pool, tx::MempoolTransaction},
-    Mempool,
+use crate::{
+    mempool::{
+        errors::{RuleError, RuleResult},
+        model::{pool::Pool, tx::MempoolTransaction},
+        Mempool,
+    },
+    model::tx_validation::TransactionValidation,
 };
 use kaspa_consensus_core::{
     api::ConsensusApi,
@@ -67,6 +70,58 @@ impl Mempool {
         Ok(accepted_transactions)
     }
 
+    /// Runs the validations of [`Self::validate_and_insert_transaction`] without mutating the mempool.
+    ///
+    /// Note that the mempool capacity is checked as if low priority transactions could be evicted.
+    pub(crate) fn validate_transaction(
+        &self,
+        consensus: &dyn ConsensusApi,
+        transaction: Transaction,
+        orphan: Orphan,
+    ) -> TransactionValidation {
+        let mut transaction = MutableTransaction::from_tx(transaction);
+        let mass = consensus.calculate_transaction_mass(&transaction.tx);
+        transaction.calculated_mass = Some(mass);
+
+        match self.validate_transaction_without_insertion(consensus, &mut transaction, orphan) {
+            Ok(is_orphan) => TransactionValidation::new(mass, transaction.calculated_fee, is_orphan, None),
+            Err(err) => {
+                let is_orphan = matches!(err, RuleError::RejectDisallowedOrphan(_));
+                TransactionValidation::new(mass, None, is_orphan, Some(err))
+            }
+        }
+    }
+
+    /// Returns whether the transaction is an orphan
+    fn validate_transaction_without_insertion(
+        &self,
+        consensus: &dyn ConsensusApi,
+        transaction: &mut MutableTransaction,
+        orphan: Orphan,
+    ) -> RuleResult<bool> {
+        self.validate_transaction_pre_utxo_entry(transaction)?;
+
+        match self.populate_entries_and_try_validate(consensus, transaction) {
+            Ok(_) => {}
+            Err(RuleError::RejectMissingOutpoint) => {
+                if orphan == Orphan::Forbidden {
+                    return Err(RuleError::RejectDisallowedOrphan(transaction.id()));
+                }
+                if self.config.maximum_orphan_transaction_count > 0 {
+                    self.orphan_pool.check_orphan(transaction)?;
+                }
+                return Ok(true);
+            }
+            Err(err) => {
+                return Err(err);
+            }
+        }
+
+        self.validate_transaction_in_context(transaction)?;
+        self.transaction_pool.limit_transaction_count(1)?;
+        Ok(false)
+    }
+
     fn validate_transaction_pre_utxo_entry(&self, transaction: &MutableTransaction) -> RuleResult<()> {
         self.validate_transaction_in_isolation(transaction)?;
         self.transaction_pool.check_double_spends(transaction)
@@ -0,0 +1,74 @@
+/// Lower bounds, in sompi per gram, of the buckets of the mempool fee rate histogram
+pub const FEE_RATE_HISTOGRAM_BOUNDS: [f64; 11] = [0.0, 1.0, 2.0, 5.0, 10.0, 20.0, 50.0, 100.0, 200.0, 500.0, 1000.0];
+
+/// Transactions of the mempool having a fee rate in `[min_fee_rate, next bucket min_fee_rate)`
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct FeeRateBucket {
+    /// Fee rate lower bound in sompi per gram
+    pub min_fee_rate: f64,
+    pub transaction_count: u64,
+    pub total_mass: u64,
+}
+
+/// Snapshot of the content of the mempool
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MempoolStats {
+    /// Number of transactions in the transaction pool
+    pub transaction_count: u64,
+    /// Number of transactions in the orphan pool
+    pub orphan_count: u64,
+    /// Total mass of the transactions in the transaction pool
+    pub total_mass: u64,
+    /// Total fees of the transactions in the transaction pool
+    pub total_fees: u64,
+    /// Transactions of the transaction pool grouped by fee rate, see [`FEE_RATE_HISTOGRAM_BOUNDS`]
+    pub fee_rate_histogram: Vec<FeeRateBucket>,
+}
+
+impl MempoolStats {
+    pub fn new(orphan_count: u64) -> Self {
+        Self {
+            orphan_count,
+            fee_rate_histogram: FEE_RATE_HISTOGRAM_BOUNDS
+                .iter()
+                .map(|&min_fee_rate| FeeRateBucket { min_fee_rate, ..Default::default() })
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Accounts for a transaction of the transaction pool
+    pub fn add_transaction(&mut self, mass: u64, fee: u64) {
+        self.transaction_count += 1;
+        self.total_mass += mass;
+        self.total_fees += fee;
+        let fee_rate = fee as f64 / mass as f64;
+        let index = FEE_RATE_HISTOGRAM_BOUNDS.partition_point(|&bound| bound <= fee_rate).saturating_sub(1);
+        let bucket = &mut self.fee_rate_histogram[index];
+        bucket.transaction_count += 1;
+        bucket.total_mass += mass;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fee_rate_histogram() {
+        let mut stats = MempoolStats::new(3);
+        // Fee rates of 0.5, 1, 1.5 and 1200 sompi per gram
+        stats.add_transaction(2000, 1000);
+        stats.add_transaction(2000, 2000);
+        stats.add_transaction(2000, 3000);
+        stats.add_transaction(1000, 1_200_000);
+
+        assert_eq!(stats.transaction_count, 4);
+        assert_eq!(stats.orphan_count, 3);
+        assert_eq!(stats.total_mass, 7000);
+        assert_eq!(stats.total_fees, 1_206_000);
+        let counts = stats.fee_rate_histogram.iter().map(|x| x.transaction_count).collect::<Vec<_>>();
+        assert_eq!(counts, vec![1, 2, 0, 0, 0, 0, 0, 0, 0, 0, 1]);
+        assert_eq!(stats.fee_rate_histogram[1].total_mass, 4000);
+    }
+}
@@ -0,0 +1,29 @@
+use crate::mempool::errors::RuleError;
+
+/// Outcome of a mempool validation of a transaction which is not inserted into the mempool
+#[derive(Debug, Clone)]
+pub struct TransactionValidation {
+    /// Calculated transaction mass
+    pub mass: u64,
+    /// Calculated transaction fee, unknown if the transaction is an orphan
+    pub fee: Option<u64>,
+    /// Whether the transaction spends outputs which are neither in the UTXO set nor in the mempool
+    pub is_orphan: bool,
+    /// Reason the mempool would reject the transaction, if any
+    pub rejection: Option<RuleError>,
+}
+
+impl TransactionValidation {
+    pub fn new(mass: u64, fee: Option<u64>, is_orphan: bool, rejection: Option<RuleError>) -> Self {
+        Self { mass, fee, is_orphan, rejection }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.rejection.is_none()
+    }
+
+    /// Fee rate in sompi per gram, unknown if the transaction is an orphan
+    pub fn fee_rate(&self) -> Option<f64> {
+        self.fee.map(|fee| fee as f64 / self.mass as f64)
+    }
+}
@@ -450,14 +450,14 @@ impl ConnectionInitializer for FlowContext {
 
         let network_name = self.config.network_name();
 
-        let local_address = self.address_manager.lock().best_local_address();
+        let local_address = self.address_manager.lock().best_local_address(router.net_address().ip());
 
         // Build the local version message
         // Subnets are not currently supported
         let mut self_version_message = Version::new(local_address, self.node_id, network_name.clone(), None, PROTOCOL_VERSION);
         self_version_message.add_user_agent(name(), version(), &self.config.user_agent_comments);
+        self_version_message.disable_relay_tx = self.config.blocks_only;
         // TODO: get number of live services
-        // TODO: disable_relay_tx from config/cmd
 
         // Perform the handshake
         let peer_version_message = handshake.handshake(self_version_message.into()).await?;
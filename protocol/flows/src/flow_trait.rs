@@ -21,6 +21,9 @@ where
             let res = self.start().await;
             if let Err(err) = res {
                 if let Some(router) = self.router() {
+                    if err.is_invalid_message_error() {
+                        router.increment_invalid_messages();
+                    }
                     router.try_sending_reject_message(&err).await;
                     if router.close().await || !err.is_connection_closed_error() {
                         warn!("{} flow error: {}, disconnecting from peer {}.", self.name(), err, router);
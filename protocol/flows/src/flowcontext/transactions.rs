@@ -68,7 +68,8 @@ impl TransactionsSpread {
         Ok(())
     }
 
+    /// Broadcasts the message to all peers except those which asked not to be relayed transactions during the handshake
     async fn broadcast(&self, msg: KaspadMessage) {
-        self.hub.broadcast(msg).await
+        self.hub.broadcast_filtered(msg, |router| !router.properties().disable_relay_tx).await
     }
 }
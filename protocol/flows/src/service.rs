@@ -1,9 +1,9 @@
 use std::sync::Arc;
 
 use kaspa_addressmanager::NetAddress;
-use kaspa_connectionmanager::ConnectionManager;
+use kaspa_connectionmanager::{dns::DnsSeeder, ConnectionManager, DynAddressSeeder};
 use kaspa_core::{
-    task::service::{AsyncService, AsyncServiceFuture},
+    task::service::{AsyncService, AsyncServiceError, AsyncServiceFuture},
     trace,
 };
 use kaspa_p2p_lib::Adaptor;
@@ -17,11 +17,11 @@ pub struct P2pService {
     flow_context: Arc<FlowContext>,
     connect_peers: Vec<NetAddress>,
     add_peers: Vec<NetAddress>,
-    listen: NetAddress,
+    listen: Vec<NetAddress>,
     outbound_target: usize,
     inbound_limit: usize,
-    dns_seeders: &'static [&'static str],
-    default_port: u16,
+    dns_seeder: Arc<DnsSeeder>,
+    address_seeder: Option<DynAddressSeeder>,
     shutdown: SingleTrigger,
 }
 
@@ -30,11 +30,11 @@ impl P2pService {
         flow_context: Arc<FlowContext>,
         connect_peers: Vec<NetAddress>,
         add_peers: Vec<NetAddress>,
-        listen: NetAddress,
+        listen: Vec<NetAddress>,
         outbound_target: usize,
         inbound_limit: usize,
-        dns_seeders: &'static [&'static str],
-        default_port: u16,
+        dns_seeder: DnsSeeder,
+        address_seeder: Option<DynAddressSeeder>,
     ) -> Self {
         Self {
             flow_context,
@@ -44,8 +44,8 @@ impl P2pService {
             listen,
             outbound_target,
             inbound_limit,
-            dns_seeders,
-            default_port,
+            dns_seeder: Arc::new(dns_seeder),
+            address_seeder,
         }
     }
 }
@@ -61,13 +61,19 @@ impl AsyncService for P2pService {
         // Prepare a shutdown signal receiver
         let shutdown_signal = self.shutdown.listener.clone();
 
-        let p2p_adaptor = Adaptor::bidirectional(self.listen, self.flow_context.hub().clone(), self.flow_context.clone()).unwrap();
+        let p2p_adaptor = match Adaptor::bidirectional(self.listen.clone(), self.flow_context.hub().clone(), self.flow_context.clone())
+        {
+            Ok(adaptor) => adaptor,
+            Err(err) => {
+                return Box::pin(async move { Err(AsyncServiceError::Service(format!("{P2P_CORE_SERVICE} failed to listen: {err}"))) })
+            }
+        };
         let connection_manager = ConnectionManager::new(
             p2p_adaptor.clone(),
             self.outbound_target,
             self.inbound_limit,
-            self.dns_seeders,
-            self.default_port,
+            self.dns_seeder.clone(),
+            self.address_seeder.clone(),
             self.flow_context.address_manager.clone(),
         );
 
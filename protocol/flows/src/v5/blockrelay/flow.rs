@@ -154,6 +154,7 @@ impl HandleRelayInvsFlow {
             }
 
             self.ctx.log_block_acceptance(inv.hash, BlockSource::Relay);
+            self.router.increment_relayed_blocks();
             self.ctx.on_new_block_template().await?;
             self.ctx.on_new_block(&session, block).await?;
 
@@ -73,6 +73,7 @@ impl IbdFlow {
         while let Some(relay_block) = self.relay_receiver.recv().await {
             if let Some(_guard) = self.ctx.try_set_ibd_running(self.router.key()) {
                 info!("IBD started with peer {}", self.router);
+                self.router.increment_ibd_sessions();
 
                 match self.ibd(relay_block).await {
                     Ok(_) => info!("IBD with peer {} completed successfully", self.router),
@@ -85,6 +85,12 @@ impl RelayTransactionsFlow {
                 return Err(ProtocolError::Other("Number of invs in tx inv message is over the limit"));
             }
 
+            // Transactions are neither requested nor accepted when running in blocks-only mode. Note that we still
+            // consume the invs since peers are not obliged to respect the `disable_relay_tx` flag of our version message
+            if self.ctx.config.blocks_only {
+                continue;
+            }
+
             let consensus = self.ctx.consensus();
             let session = consensus.session().await;
 
@@ -186,6 +192,9 @@ impl RelayTransactionsFlow {
                 .await
             {
                 Ok(accepted_transactions) => {
+                    if accepted_transactions.iter().any(|x| x.id() == transaction_id) {
+                        self.router.increment_relayed_transactions();
+                    }
                     // trace!("Broadcast {} accepted transaction ids", accepted_transactions.len());
                     self.ctx.broadcast_transactions(accepted_transactions.iter().map(|x| x.id())).await?;
                 }
@@ -85,6 +85,19 @@ impl ProtocolError {
         matches!(self, Self::ConnectionClosed)
     }
 
+    /// Indicates whether the error was caused by an invalid or unexpected message sent by the peer
+    pub fn is_invalid_message_error(&self) -> bool {
+        matches!(
+            self,
+            Self::UnexpectedMessage(..)
+                | Self::ConversionError(_)
+                | Self::RuleError(_)
+                | Self::PruningImportError(_)
+                | Self::MisbehavingPeer(_)
+                | Self::NoRouteForMessageType(_)
+        )
+    }
+
     pub fn can_send_outgoing_message(&self) -> bool {
         !matches!(self, Self::ConnectionClosed | Self::OutgoingRouteCapacityReached(_))
     }
@@ -3,6 +3,7 @@ use crate::core::hub::Hub;
 use crate::ConnectionError;
 use crate::{core::connection_handler::ConnectionHandler, Router};
 use kaspa_utils::networking::NetAddress;
+use std::net::TcpListener;
 use std::ops::Deref;
 use std::sync::Arc;
 use std::time::Duration;
@@ -24,13 +25,13 @@ pub struct Adaptor {
     // Internal design & resource management: management of active peers was extracted to the `Hub` object
     // in order to decouple the memory usage from the `ConnectionHandler` and avoid indirect reference cycles.
     // This way, when the adaptor drops, the following chain of events is triggered (assuming all peer routers were dropped already):
-    // - `self._server_termination` is dropped, making the server listener exit (`ConnectionHandler::serve`) thus releasing the handler -> `hub_sender`
+    // - `self._server_terminations` are dropped, making the server listeners exit (`ConnectionHandler::serve`) thus releasing the handler -> `hub_sender`
     // - `self.connection_handler` is dropped from the adaptor as well, cleaning the last `hub_sender`
     // - Hub event loop (`Hub::start_event_loop`) exits because all senders were dropped
     // - `self.hub` is dropped
     //
-    /// If a server was started, it will get cleaned up when this sender is dropped or invoked
-    _server_termination: Option<OneshotSender<()>>,
+    /// If servers were started, each will get cleaned up when its sender is dropped or invoked
+    _server_terminations: Vec<OneshotSender<()>>,
 
     /// An object for creating new outbound connections as well as handling new connections coming from a server
     connection_handler: ConnectionHandler,
@@ -40,29 +41,42 @@ pub struct Adaptor {
 }
 
 impl Adaptor {
-    pub(crate) fn new(server_termination: Option<OneshotSender<()>>, connection_handler: ConnectionHandler, hub: Hub) -> Self {
-        Self { _server_termination: server_termination, connection_handler, hub }
+    pub(crate) fn new(server_terminations: Vec<OneshotSender<()>>, connection_handler: ConnectionHandler, hub: Hub) -> Self {
+        Self { _server_terminations: server_terminations, connection_handler, hub }
     }
 
     /// Creates a P2P adaptor with only client-side support. Typical Kaspa nodes should use `Adaptor::bidirectional`
     pub fn client_only(hub: Hub, initializer: Arc<dyn ConnectionInitializer>) -> Arc<Self> {
         let (hub_sender, hub_receiver) = mpsc_channel(Self::hub_channel_size());
         let connection_handler = ConnectionHandler::new(hub_sender, initializer.clone());
-        let adaptor = Arc::new(Adaptor::new(None, connection_handler, hub));
+        let adaptor = Arc::new(Adaptor::new(vec![], connection_handler, hub));
         adaptor.hub.clone().start_event_loop(hub_receiver, initializer);
         adaptor
     }
 
-    /// Creates a bidirectional P2P adaptor with a server serving at `serve_address` and with client support
+    /// Creates a bidirectional P2P adaptor with a server serving at each of `serve_addresses` and with client support
     pub fn bidirectional(
-        serve_address: NetAddress,
+        serve_addresses: Vec<NetAddress>,
+        hub: Hub,
+        initializer: Arc<dyn ConnectionInitializer>,
+    ) -> Result<Arc<Self>, ConnectionError> {
+        let listeners =
+            serve_addresses.iter().map(|serve_address| serve_address.bind_tcp_listener()).collect::<Result<Vec<_>, _>>()?;
+        Self::bidirectional_with_listeners(listeners, hub, initializer)
+    }
+
+    /// Creates a bidirectional P2P adaptor with a server accepting connections from each of the already bound `listeners`
+    /// and with client support
+    pub fn bidirectional_with_listeners(
+        listeners: Vec<TcpListener>,
         hub: Hub,
         initializer: Arc<dyn ConnectionInitializer>,
     ) -> Result<Arc<Self>, ConnectionError> {
         let (hub_sender, hub_receiver) = mpsc_channel(Self::hub_channel_size());
         let connection_handler = ConnectionHandler::new(hub_sender, initializer.clone());
-        let server_termination = connection_handler.serve(serve_address)?;
-        let adaptor = Arc::new(Adaptor::new(Some(server_termination), connection_handler, hub));
+        let server_terminations =
+            listeners.into_iter().map(|listener| connection_handler.serve(listener)).collect::<Result<Vec<_>, _>>()?;
+        let adaptor = Arc::new(Adaptor::new(server_terminations, connection_handler, hub));
         adaptor.hub.clone().start_event_loop(hub_receiver, initializer);
         Ok(adaptor)
     }
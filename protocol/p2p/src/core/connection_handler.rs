@@ -6,15 +6,15 @@ use crate::pb::{
 use crate::{ConnectionInitializer, Router};
 use futures::FutureExt;
 use kaspa_core::{debug, info};
-use kaspa_utils::networking::NetAddress;
 use std::net::ToSocketAddrs;
 use std::pin::Pin;
 use std::sync::Arc;
 use std::time::Duration;
 use thiserror::Error;
+use tokio::net::TcpListener;
 use tokio::sync::mpsc::{channel as mpsc_channel, Sender as MpscSender};
 use tokio::sync::oneshot::{channel as oneshot_channel, Sender as OneshotSender};
-use tokio_stream::wrappers::ReceiverStream;
+use tokio_stream::wrappers::{ReceiverStream, TcpListenerStream};
 use tokio_stream::StreamExt;
 use tonic::transport::{Error as TonicError, Server as TonicServer};
 use tonic::{Request, Response, Status as TonicStatus, Streaming};
@@ -53,8 +53,10 @@ impl ConnectionHandler {
         Self { hub_sender, initializer }
     }
 
-    /// Launches a P2P server listener loop
-    pub(crate) fn serve(&self, serve_address: NetAddress) -> Result<OneshotSender<()>, ConnectionError> {
+    /// Launches a P2P server loop accepting connections from a bound listener
+    pub(crate) fn serve(&self, listener: std::net::TcpListener) -> Result<OneshotSender<()>, ConnectionError> {
+        let serve_address = listener.local_addr()?;
+        let listener = TcpListener::from_std(listener)?;
         let (termination_sender, termination_receiver) = oneshot_channel::<()>();
         let connection_handler = self.clone();
         info!("P2P Server starting on: {}", serve_address);
@@ -67,7 +69,7 @@ impl ConnectionHandler {
             // TODO: check whether we should set tcp_keepalive
             let serve_result = TonicServer::builder()
                 .add_service(proto_server)
-                .serve_with_shutdown(serve_address.into(), termination_receiver.map(drop))
+                .serve_with_incoming_shutdown(TcpListenerStream::new(listener), termination_receiver.map(drop))
                 .await;
 
             match serve_result {
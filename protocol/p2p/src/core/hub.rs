@@ -1,13 +1,13 @@
 use crate::{common::ProtocolError, pb::KaspadMessage, ConnectionInitializer, Peer, Router};
 use kaspa_core::{debug, info, warn};
-use parking_lot::RwLock;
+use parking_lot::{Mutex, RwLock};
 use std::{
     collections::{hash_map::Entry::Occupied, HashMap},
     sync::Arc,
 };
-use tokio::sync::mpsc::Receiver as MpscReceiver;
+use tokio::sync::{mpsc::Receiver as MpscReceiver, Notify};
 
-use super::peer::PeerKey;
+use super::peer::{PeerKey, PeerStats};
 
 #[derive(Debug)]
 pub(crate) enum HubEvent {
@@ -22,11 +22,21 @@ pub struct Hub {
     ///
     /// Note: the map key holds the node id and IP to prevent node impersonating.
     pub(crate) peers: Arc<RwLock<HashMap<PeerKey, Arc<Router>>>>,
+
+    /// Statistics accumulated over the connections removed from the hub
+    closed_peers_stats: Arc<Mutex<PeerStats>>,
+
+    /// Notified whenever peers are added to or removed from the map of active peers
+    peers_changed: Arc<Notify>,
 }
 
 impl Hub {
     pub fn new() -> Self {
-        Self { peers: Arc::new(RwLock::new(HashMap::new())) }
+        Self {
+            peers: Arc::new(RwLock::new(HashMap::new())),
+            closed_peers_stats: Arc::new(Mutex::new(PeerStats::default())),
+            peers_changed: Arc::new(Notify::new()),
+        }
     }
 
     /// Starts a loop for receiving central hub events from all peer routers. This mechanism is used for
@@ -65,6 +75,8 @@ impl Hub {
                             // This is extremely important in cases of duplicate connection rejection etc.
                             if Arc::ptr_eq(entry.get(), &router) {
                                 entry.remove_entry();
+                                self.accumulate_closed_peer_stats(&router);
+                                self.peers_changed.notify_waiters();
                                 debug!("P2P, Hub event loop, removing peer, router-id: {}", router.identity());
                             }
                         }
@@ -77,8 +89,10 @@ impl Hub {
 
     async fn insert_new_router(&self, new_router: Arc<Router>) {
         let prev = self.peers.write().insert(new_router.key(), new_router);
+        self.peers_changed.notify_waiters();
         if let Some(previous_router) = prev {
             // This is not supposed to ever happen but can on rare race-conditions
+            self.accumulate_closed_peer_stats(&previous_router);
             previous_router.close().await;
             warn!("P2P, Hub event loop, removing peer with duplicate key: {}", previous_router.key());
         }
@@ -104,6 +118,14 @@ impl Hub {
         }
     }
 
+    /// Broadcast a message to all peers satisfying `filter`
+    pub async fn broadcast_filtered<F: Fn(&Router) -> bool>(&self, msg: KaspadMessage, filter: F) {
+        let peers = self.peers.read().values().filter(|r| filter(r)).cloned().collect::<Vec<_>>();
+        for router in peers {
+            let _ = router.enqueue(msg.clone()).await;
+        }
+    }
+
     /// Terminate a specific peer
     pub async fn terminate(&self, peer_key: PeerKey) {
         let op = self.peers.read().get(&peer_key).cloned();
@@ -116,16 +138,32 @@ impl Hub {
     /// Terminate all peers
     pub async fn terminate_all_peers(&self) {
         let peers = self.peers.write().drain().map(|(_, r)| r).collect::<Vec<_>>();
+        self.peers_changed.notify_waiters();
         for router in peers {
+            self.accumulate_closed_peer_stats(&router);
             router.close().await;
         }
     }
 
+    fn accumulate_closed_peer_stats(&self, router: &Router) {
+        self.closed_peers_stats.lock().merge(&router.stats());
+    }
+
     /// Returns a list of all currently active peers
     pub fn active_peers(&self) -> Vec<Peer> {
         self.peers.read().values().map(|r| r.as_ref().into()).collect()
     }
 
+    /// Returns a list of all currently active peers along with a snapshot of their connection statistics
+    pub fn active_peers_with_stats(&self) -> Vec<Peer> {
+        self.peers.read().values().map(|r| r.peer_with_stats()).collect()
+    }
+
+    /// Returns the statistics accumulated over all the connections which were closed since the hub was created
+    pub fn closed_peers_stats(&self) -> PeerStats {
+        self.closed_peers_stats.lock().clone()
+    }
+
     /// Returns whether there are currently active peers
     pub fn has_peers(&self) -> bool {
         !self.peers.read().is_empty()
@@ -135,6 +173,19 @@ impl Hub {
     pub fn has_peer(&self, peer_key: PeerKey) -> bool {
         self.peers.read().contains_key(&peer_key)
     }
+
+    /// Waits until exactly `count` peers are active
+    #[cfg(test)]
+    pub(crate) async fn wait_for_peers(&self, count: usize) {
+        loop {
+            // Registered before checking the peers so that no change in between is missed
+            let peers_changed = self.peers_changed.notified();
+            if self.peers.read().len() == count {
+                return;
+            }
+            peers_changed.await;
+        }
+    }
 }
 
 impl Default for Hub {
@@ -1,7 +1,10 @@
 use crate::pb::kaspad_message::Payload as KaspadMessagePayload;
+use strum::{EnumCount, EnumIter};
 
+/// Message payload types. Discriminants are contiguous from 0, so that they can index per-type arrays
+/// of [`EnumCount::COUNT`] entries, iterated in order by [`strum::IntoEnumIterator::iter`]
 #[repr(u8)]
-#[derive(Debug, Copy, Clone, Eq, Hash, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, Hash, PartialEq, EnumCount, EnumIter)]
 pub enum KaspadMessagePayloadType {
     Addresses = 0,
     Block,
@@ -103,3 +106,15 @@ impl From<&KaspadMessagePayload> for KaspadMessagePayloadType {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn test_payload_types_ordered_by_discriminant() {
+        KaspadMessagePayloadType::iter().enumerate().for_each(|(i, msg_type)| assert_eq!(msg_type as usize, i));
+        assert_eq!(KaspadMessagePayloadType::iter().count(), KaspadMessagePayloadType::COUNT);
+    }
+}
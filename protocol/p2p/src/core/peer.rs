@@ -1,6 +1,17 @@
+use crate::KaspadMessagePayloadType;
 use kaspa_consensus_core::subnets::SubnetworkId;
 use kaspa_utils::networking::{IpAddress, PeerId};
-use std::{fmt::Display, net::SocketAddr, sync::Arc, time::Instant};
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    net::SocketAddr,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::Instant,
+};
+use strum::{EnumCount, IntoEnumIterator};
 
 #[derive(Debug, Clone, Default)]
 pub struct PeerProperties {
@@ -13,6 +24,164 @@ pub struct PeerProperties {
     pub time_offset: i64,
 }
 
+/// Message and byte counters of the traffic with a peer
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TrafficCounters {
+    pub messages: u64,
+    pub bytes: u64,
+}
+
+impl TrafficCounters {
+    pub fn add(&mut self, bytes: u64) {
+        self.messages += 1;
+        self.bytes += bytes;
+    }
+
+    pub fn merge(&mut self, other: &TrafficCounters) {
+        self.messages += other.messages;
+        self.bytes += other.bytes;
+    }
+}
+
+/// Statistics gathered over the lifetime of a peer connection
+#[derive(Debug, Clone, Default)]
+pub struct PeerStats {
+    /// Outgoing traffic per message type
+    pub sent: HashMap<KaspadMessagePayloadType, TrafficCounters>,
+
+    /// Incoming traffic per message type
+    pub received: HashMap<KaspadMessagePayloadType, TrafficCounters>,
+
+    /// Number of messages which were found to be invalid or unexpected
+    pub invalid_messages: u64,
+
+    /// Number of relayed blocks received from the peer and added to the DAG
+    pub relayed_blocks: u64,
+
+    /// Number of relayed transactions received from the peer and accepted to the mempool
+    pub relayed_transactions: u64,
+
+    /// Number of IBD sessions run with the peer as syncer
+    pub ibd_sessions: u64,
+}
+
+impl PeerStats {
+    pub fn total_sent(&self) -> TrafficCounters {
+        Self::total(&self.sent)
+    }
+
+    pub fn total_received(&self) -> TrafficCounters {
+        Self::total(&self.received)
+    }
+
+    fn total(counters: &HashMap<KaspadMessagePayloadType, TrafficCounters>) -> TrafficCounters {
+        counters.values().fold(TrafficCounters::default(), |mut total, x| {
+            total.merge(x);
+            total
+        })
+    }
+
+    /// Accumulates the statistics of `other` into `self`
+    pub fn merge(&mut self, other: &PeerStats) {
+        other.sent.iter().for_each(|(msg_type, counters)| self.sent.entry(*msg_type).or_default().merge(counters));
+        other.received.iter().for_each(|(msg_type, counters)| self.received.entry(*msg_type).or_default().merge(counters));
+        self.invalid_messages += other.invalid_messages;
+        self.relayed_blocks += other.relayed_blocks;
+        self.relayed_transactions += other.relayed_transactions;
+        self.ibd_sessions += other.ibd_sessions;
+    }
+}
+
+/// Lock-free message and byte counters of the traffic with a peer
+#[derive(Debug, Default)]
+struct AtomicTrafficCounters {
+    messages: AtomicU64,
+    bytes: AtomicU64,
+}
+
+impl AtomicTrafficCounters {
+    fn add(&self, bytes: u64) {
+        self.messages.fetch_add(1, Ordering::Relaxed);
+        self.bytes.fetch_add(bytes, Ordering::Relaxed);
+    }
+
+    fn load(&self) -> TrafficCounters {
+        TrafficCounters { messages: self.messages.load(Ordering::Relaxed), bytes: self.bytes.load(Ordering::Relaxed) }
+    }
+}
+
+/// Statistics of a peer connection, updated without locking for every message, see [`PeerStats`]
+#[derive(Debug)]
+pub(crate) struct PeerStatsCounters {
+    /// Outgoing traffic indexed by message type
+    sent: [AtomicTrafficCounters; KaspadMessagePayloadType::COUNT],
+    /// Incoming traffic indexed by message type
+    received: [AtomicTrafficCounters; KaspadMessagePayloadType::COUNT],
+    invalid_messages: AtomicU64,
+    relayed_blocks: AtomicU64,
+    relayed_transactions: AtomicU64,
+    ibd_sessions: AtomicU64,
+}
+
+impl Default for PeerStatsCounters {
+    fn default() -> Self {
+        Self {
+            sent: std::array::from_fn(|_| Default::default()),
+            received: std::array::from_fn(|_| Default::default()),
+            invalid_messages: Default::default(),
+            relayed_blocks: Default::default(),
+            relayed_transactions: Default::default(),
+            ibd_sessions: Default::default(),
+        }
+    }
+}
+
+impl PeerStatsCounters {
+    pub(crate) fn record_sent(&self, msg_type: KaspadMessagePayloadType, bytes: usize) {
+        self.sent[msg_type as usize].add(bytes as u64);
+    }
+
+    pub(crate) fn record_received(&self, msg_type: KaspadMessagePayloadType, bytes: usize) {
+        self.received[msg_type as usize].add(bytes as u64);
+    }
+
+    pub(crate) fn increment_invalid_messages(&self) {
+        self.invalid_messages.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn increment_relayed_blocks(&self) {
+        self.relayed_blocks.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn increment_relayed_transactions(&self) {
+        self.relayed_transactions.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn increment_ibd_sessions(&self) {
+        self.ibd_sessions.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Takes a snapshot of the counters, keeping the message types with some traffic only
+    pub(crate) fn snapshot(&self) -> PeerStats {
+        fn traffic(counters: &[AtomicTrafficCounters]) -> HashMap<KaspadMessagePayloadType, TrafficCounters> {
+            KaspadMessagePayloadType::iter()
+                .zip(counters)
+                .map(|(msg_type, counters)| (msg_type, counters.load()))
+                .filter(|(_, counters)| counters.messages > 0)
+                .collect()
+        }
+
+        PeerStats {
+            sent: traffic(&self.sent),
+            received: traffic(&self.received),
+            invalid_messages: self.invalid_messages.load(Ordering::Relaxed),
+            relayed_blocks: self.relayed_blocks.load(Ordering::Relaxed),
+            relayed_transactions: self.relayed_transactions.load(Ordering::Relaxed),
+            ibd_sessions: self.ibd_sessions.load(Ordering::Relaxed),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct Peer {
     identity: PeerId,
@@ -21,6 +190,7 @@ pub struct Peer {
     connection_started: Instant,
     properties: Arc<PeerProperties>,
     last_ping_duration: u64,
+    stats: Option<PeerStats>,
 }
 
 impl Peer {
@@ -31,8 +201,9 @@ impl Peer {
         connection_started: Instant,
         properties: Arc<PeerProperties>,
         last_ping_duration: u64,
+        stats: Option<PeerStats>,
     ) -> Self {
-        Self { identity, net_address, is_outbound, connection_started, properties, last_ping_duration }
+        Self { identity, net_address, is_outbound, connection_started, properties, last_ping_duration, stats }
     }
 
     /// Internal identity of this peer
@@ -65,6 +236,11 @@ impl Peer {
     pub fn last_ping_duration(&self) -> u64 {
         self.last_ping_duration
     }
+
+    /// Statistics of the connection at the time this peer snapshot was taken, if it was requested
+    pub fn stats(&self) -> Option<&PeerStats> {
+        self.stats.as_ref()
+    }
 }
 
 #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
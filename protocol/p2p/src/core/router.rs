@@ -6,6 +6,7 @@ use crate::{make_message, Peer};
 use kaspa_core::{debug, error, info, trace, warn};
 use kaspa_utils::networking::PeerId;
 use parking_lot::{Mutex, RwLock};
+use prost::Message;
 use seqlock::SeqLock;
 use std::fmt::{Debug, Display};
 use std::net::SocketAddr;
@@ -17,7 +18,7 @@ use tokio::sync::mpsc::{channel as mpsc_channel, Receiver as MpscReceiver, Sende
 use tokio::sync::oneshot::{channel as oneshot_channel, Sender as OneshotSender};
 use tonic::Streaming;
 
-use super::peer::{PeerKey, PeerProperties};
+use super::peer::{PeerKey, PeerProperties, PeerStats, PeerStatsCounters};
 
 pub type IncomingRoute = MpscReceiver<KaspadMessage>;
 
@@ -88,6 +89,9 @@ pub struct Router {
 
     /// Used for managing router mutable state
     mutable_state: Mutex<RouterMutableState>,
+
+    /// Statistics of the connection. Kept apart from the mutable state since it is updated for every message
+    stats: PeerStatsCounters,
 }
 
 impl Display for Router {
@@ -103,6 +107,7 @@ impl From<&Router> for PeerKey {
 }
 
 impl From<&Router> for Peer {
+    /// Takes a snapshot of the router without its connection statistics, see [`Router::peer_with_stats`]
     fn from(router: &Router) -> Self {
         Self::new(
             router.identity(),
@@ -111,6 +116,7 @@ impl From<&Router> for Peer {
             router.connection_started,
             router.properties(),
             router.last_ping_duration(),
+            None,
         )
     }
 }
@@ -141,6 +147,7 @@ impl Router {
             outgoing_route,
             hub_sender,
             mutable_state: Mutex::new(RouterMutableState::new(Some(start_sender), Some(shutdown_sender))),
+            stats: Default::default(),
         });
 
         let router_clone = router.clone();
@@ -160,6 +167,7 @@ impl Router {
                     res = incoming_stream.message() => match res {
                         Ok(Some(msg)) => {
                             trace!("P2P msg: {:?}, router-id: {}, peer: {}", message_summary(&msg), router.identity(), router);
+                            router.record_received_message(&msg);
                             match router.route_to_flow(msg) {
                                 Ok(()) => {},
                                 Err(e) => {
@@ -242,6 +250,50 @@ impl Router {
         self.mutable_state.lock().last_ping_duration
     }
 
+    /// Returns a snapshot of the statistics of this connection
+    pub fn stats(&self) -> PeerStats {
+        self.stats.snapshot()
+    }
+
+    /// Takes a snapshot of the router including its connection statistics
+    pub fn peer_with_stats(&self) -> Peer {
+        Peer::new(
+            self.identity(),
+            self.net_address,
+            self.is_outbound,
+            self.connection_started,
+            self.properties(),
+            self.last_ping_duration(),
+            Some(self.stats()),
+        )
+    }
+
+    fn record_received_message(&self, msg: &KaspadMessage) {
+        if let Some(payload) = msg.payload.as_ref() {
+            self.stats.record_received(payload.into(), msg.encoded_len());
+        }
+    }
+
+    /// Counts a message of this peer which was found to be invalid or unexpected
+    pub fn increment_invalid_messages(&self) {
+        self.stats.increment_invalid_messages();
+    }
+
+    /// Counts a relayed block of this peer which was added to the DAG
+    pub fn increment_relayed_blocks(&self) {
+        self.stats.increment_relayed_blocks();
+    }
+
+    /// Counts a relayed transaction of this peer which was accepted to the mempool
+    pub fn increment_relayed_transactions(&self) {
+        self.stats.increment_relayed_transactions();
+    }
+
+    /// Counts an IBD session started with this peer as syncer
+    pub fn increment_ibd_sessions(&self) {
+        self.stats.increment_ibd_sessions();
+    }
+
     pub fn incoming_flow_baseline_channel_size() -> usize {
         256
     }
@@ -289,6 +341,7 @@ impl Router {
     pub fn route_to_flow(&self, msg: KaspadMessage) -> Result<(), ProtocolError> {
         if msg.payload.is_none() {
             debug!("P2P, Route to flow got empty payload, peer: {}", self);
+            self.increment_invalid_messages();
             return Err(ProtocolError::Other("received kaspad p2p message with empty payload"));
         }
         let msg_type: KaspadMessagePayloadType = msg.payload.as_ref().expect("payload was just verified").into();
@@ -313,6 +366,7 @@ impl Router {
                 }
             }
         } else {
+            self.increment_invalid_messages();
             Err(ProtocolError::NoRouteForMessageType(msg_type))
         }
     }
@@ -320,8 +374,13 @@ impl Router {
     /// Enqueues a locally-originated message to be sent to the network peer
     pub async fn enqueue(&self, msg: KaspadMessage) -> Result<(), ProtocolError> {
         assert!(msg.payload.is_some(), "Kaspad P2P message should always have a value");
+        let msg_type: KaspadMessagePayloadType = msg.payload.as_ref().expect("payload was just verified").into();
+        let size = msg.encoded_len();
         match self.outgoing_route.try_send(msg) {
-            Ok(_) => Ok(()),
+            Ok(_) => {
+                self.stats.record_sent(msg_type, size);
+                Ok(())
+            }
             Err(TrySendError::Closed(_)) => Err(ProtocolError::ConnectionClosed),
             Err(TrySendError::Full(_)) => Err(ProtocolError::OutgoingRouteCapacityReached(self.to_string())),
         }
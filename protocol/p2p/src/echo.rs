@@ -145,10 +145,14 @@ impl ConnectionInitializer for EchoFlowInitializer {
 
 #[cfg(test)]
 mod tests {
-    use std::{str::FromStr, time::Duration};
+    use std::{
+        net::{Ipv4Addr, Ipv6Addr},
+        str::FromStr,
+        time::Duration,
+    };
 
     use super::*;
-    use crate::{Adaptor, Hub};
+    use crate::{make_message, Adaptor, Hub, PeerProperties};
     use kaspa_core::debug;
     use kaspa_utils::networking::NetAddress;
 
@@ -157,10 +161,11 @@ mod tests {
         kaspa_core::log::try_init_logger("debug");
 
         let address1 = NetAddress::from_str("[::1]:50053").unwrap();
-        let adaptor1 = Adaptor::bidirectional(address1, Hub::new(), Arc::new(EchoFlowInitializer::new())).unwrap();
+        let hub1 = Hub::new();
+        let adaptor1 = Adaptor::bidirectional(vec![address1], hub1.clone(), Arc::new(EchoFlowInitializer::new())).unwrap();
 
         let address2 = NetAddress::from_str("[::1]:50054").unwrap();
-        let adaptor2 = Adaptor::bidirectional(address2, Hub::new(), Arc::new(EchoFlowInitializer::new())).unwrap();
+        let adaptor2 = Adaptor::bidirectional(vec![address2], Hub::new(), Arc::new(EchoFlowInitializer::new())).unwrap();
 
         // Initiate the connection from `adaptor1` (outbound) to `adaptor2` (inbound)
         let peer2_id = adaptor1
@@ -181,6 +186,11 @@ mod tests {
         assert!(adaptor1_initial_peers[0].is_outbound());
         assert!(!adaptor2_initial_peers[0].is_outbound());
 
+        // Connection statistics are only collected on request
+        assert!(adaptor1_initial_peers[0].stats().is_none());
+        let peer1_stats = hub1.active_peers_with_stats()[0].stats().cloned().unwrap();
+        assert!(peer1_stats.total_sent().messages > 0 && peer1_stats.total_received().messages > 0);
+
         adaptor1.terminate(peer2_id).await;
         tokio::time::sleep(std::time::Duration::from_secs(2)).await;
 
@@ -188,6 +198,11 @@ mod tests {
         assert_eq!(adaptor1.active_peers().len(), 0, "peer termination failed -- outbound peer was not removed");
         assert_eq!(adaptor2.active_peers().len(), 0, "peer termination failed -- inbound peer was not removed");
 
+        // The statistics of the closed connection are retained by the hub
+        let closed_peers_stats = hub1.closed_peers_stats();
+        assert!(closed_peers_stats.total_sent().messages >= peer1_stats.total_sent().messages);
+        assert!(closed_peers_stats.total_received().messages >= peer1_stats.total_received().messages);
+
         adaptor1.close().await;
         adaptor2.close().await;
         tokio::time::sleep(std::time::Duration::from_secs(2)).await;
@@ -201,4 +216,78 @@ mod tests {
         drop(adaptor2);
         tokio::time::sleep(std::time::Duration::from_secs(2)).await;
     }
+    /// Binds listeners of both IP families on the same free port
+    fn bind_dual_stack_listeners() -> (Vec<std::net::TcpListener>, u16) {
+        let listener_v6 = NetAddress::from_str("[::]:0").unwrap().bind_tcp_listener().unwrap();
+        let port = listener_v6.local_addr().unwrap().port();
+        let listener_v4 = NetAddress::new(Ipv4Addr::UNSPECIFIED.into(), port).bind_tcp_listener().unwrap();
+        (vec![listener_v6, listener_v4], port)
+    }
+
+    async fn wait_for_peers(hub: &Hub, count: usize) {
+        tokio::time::timeout(Duration::from_secs(10), hub.wait_for_peers(count)).await.expect("timed out waiting for the peers");
+    }
+
+    #[tokio::test]
+    async fn test_dual_stack_listen() {
+        kaspa_core::log::try_init_logger("debug");
+
+        // Listening on both IP families on the same port
+        let (listeners, port) = bind_dual_stack_listeners();
+        let hub = Hub::new();
+        let server = Adaptor::bidirectional_with_listeners(listeners, hub.clone(), Arc::new(EchoFlowInitializer::new())).unwrap();
+
+        // A port already in use is reported to the caller
+        let listen = vec![NetAddress::new(Ipv6Addr::UNSPECIFIED.into(), port), NetAddress::new(Ipv4Addr::UNSPECIFIED.into(), port)];
+        assert!(Adaptor::bidirectional(listen, Hub::new(), Arc::new(EchoFlowInitializer::new())).is_err());
+
+        // The server is reachable over both families
+        let client_v4 = Adaptor::client_only(Hub::new(), Arc::new(EchoFlowInitializer::new()));
+        let client_v6 = Adaptor::client_only(Hub::new(), Arc::new(EchoFlowInitializer::new()));
+        client_v4.connect_peer(format!("127.0.0.1:{port}")).await.unwrap();
+        client_v6.connect_peer(format!("[::1]:{port}")).await.unwrap();
+        wait_for_peers(&hub, 2).await;
+
+        let mut peers_ipv4 = server.active_peers().iter().map(|peer| peer.net_address().is_ipv4()).collect::<Vec<_>>();
+        peers_ipv4.sort();
+        assert_eq!(peers_ipv4, vec![false, true]);
+
+        for adaptor in [client_v4, client_v6, server] {
+            adaptor.close().await;
+        }
+    }
+
+    #[tokio::test]
+    async fn test_broadcast_filtered() {
+        kaspa_core::log::try_init_logger("debug");
+
+        // The echo initializer does not set peer identities, so the clients connect over distinct IP families to get distinct keys
+        let (listeners, port) = bind_dual_stack_listeners();
+        let hub = Hub::new();
+        let server = Adaptor::bidirectional_with_listeners(listeners, hub.clone(), Arc::new(EchoFlowInitializer::new())).unwrap();
+        let clients = [
+            Adaptor::client_only(Hub::new(), Arc::new(EchoFlowInitializer::new())),
+            Adaptor::client_only(Hub::new(), Arc::new(EchoFlowInitializer::new())),
+        ];
+        for (client, address) in clients.iter().zip([format!("127.0.0.1:{port}"), format!("[::1]:{port}")]) {
+            client.connect_peer(address).await.unwrap();
+        }
+        wait_for_peers(&hub, 2).await;
+
+        // Mark one of the peers as having asked not to be relayed transactions
+        let routers = hub.peers.read().values().cloned().collect::<Vec<_>>();
+        routers[0].set_properties(Arc::new(PeerProperties { disable_relay_tx: true, ..Default::default() }));
+
+        let msg = make_message!(pb::kaspad_message::Payload::InvTransactions, pb::InvTransactionsMessage { ids: vec![] });
+        hub.broadcast_filtered(msg, |router| !router.properties().disable_relay_tx).await;
+
+        let invs_sent =
+            |router: &Router| router.stats().sent.get(&KaspadMessagePayloadType::InvTransactions).map_or(0, |x| x.messages);
+        assert_eq!(invs_sent(&routers[0]), 0, "the message was sent to a filtered out peer");
+        assert!(invs_sent(&routers[1]) > 0, "the message was not sent to a matching peer");
+
+        for adaptor in clients.into_iter().chain([server]) {
+            adaptor.close().await;
+        }
+    }
 }
@@ -0,0 +1,35 @@
+//!
+//! Paginated retrieval of large header ranges.
+//!
+
+use crate::{api::rpc::RpcApi, GetHeadersRequest, RpcHash, RpcHeader, RpcResult};
+use futures::{stream, Stream};
+use std::sync::Arc;
+
+/// Maximum number of headers returned by a single `get_headers` call.
+/// Larger request limits are clamped to it and the response reports where to continue from.
+pub const MAX_GET_HEADERS_LIMIT: u64 = 1_000;
+
+/// Returns a stream of header pages starting at (and including) `start_hash`, following the same order
+/// semantics as [`RpcApi::get_headers`]. Each page holds up to `page_size` headers and is requested from the
+/// node only once the previous page was consumed, so large header ranges can be synced without being held
+/// in memory at once. The stream ends once the node has no further headers in the requested direction.
+pub fn get_headers_stream<T>(
+    rpc: Arc<T>,
+    start_hash: RpcHash,
+    is_ascending: bool,
+    page_size: u64,
+) -> impl Stream<Item = RpcResult<Vec<RpcHeader>>> + Send
+where
+    T: RpcApi + ?Sized,
+{
+    let page_size = page_size.clamp(1, MAX_GET_HEADERS_LIMIT);
+    stream::unfold((rpc, Some(start_hash)), move |(rpc, start_hash)| async move {
+        let start_hash = start_hash?;
+        match rpc.get_headers_call(GetHeadersRequest::new(start_hash, page_size, is_ascending)).await {
+            Ok(response) if response.headers.is_empty() => None,
+            Ok(response) => Some((Ok(response.headers), (rpc, response.next_start_hash))),
+            Err(err) => Some((Err(err), (rpc, None))),
+        }
+    })
+}
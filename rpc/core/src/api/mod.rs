@@ -1,3 +1,4 @@
+pub mod headers;
 pub mod notifications;
 pub mod ops;
 pub mod rpc;
@@ -13,7 +13,7 @@ use workflow_core::enums::Describe;
 /// or using Serde attributes. This applies only to RPC infrastructure that uses internal
 /// data structures and does not affect gRPC. gRPC should issue and handle its
 /// own versioning.
-pub const RPC_API_VERSION: [u16; 4] = [0, 1, 0, 0];
+pub const RPC_API_VERSION: [u16; 4] = [0, 2, 0, 0];
 
 #[derive(Describe, Clone, Debug, PartialEq, Eq, Hash, BorshSerialize, BorshDeserialize, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -115,6 +115,34 @@ pub enum RpcApiOps {
     VirtualDaaScoreChangedNotification,
     PruningPointUtxoSetOverrideNotification,
     NewBlockTemplateNotification,
+
+    // Ops added to the API are appended below in order to preserve the values of the existing ones
+    /// Get a merkle proof of the inclusion of a transaction in a block
+    GetTransactionInclusionProof,
+    /// Get a merkle proof of the acceptance of a transaction by a chain block
+    GetTransactionAcceptanceProof,
+    /// Validate a transaction against the mempool rules without inserting it
+    ValidateTransaction,
+    /// Get the GHOSTDAG data of a block
+    GetBlockGhostdagData,
+    /// Get the children of a block
+    GetBlockChildren,
+    /// Get the anticone of a block, relative to the virtual block
+    GetBlockAnticone,
+    /// Get the acceptance data of a block
+    GetBlockAcceptanceData,
+    /// Check whether a block is on the virtual selected chain
+    IsChainBlock,
+    /// Get the virtual UTXO entries of a list of outpoints
+    GetUtxosByOutpoints,
+    /// Get timestamp estimates for a list of DAA scores
+    GetDaaScoreTimestampEstimate,
+    /// Get the log levels of the node subsystems
+    GetLogLevels,
+    /// Set the log levels of the node subsystems
+    SetLogLevels,
+    /// Archive the current log files and start new ones
+    RotateLogFiles,
 }
 
 impl From<RpcApiOps> for u32 {
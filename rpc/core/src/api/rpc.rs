@@ -11,6 +11,9 @@ use kaspa_notify::{listener::ListenerId, scope::Scope, subscription::Command};
 use std::sync::Arc;
 
 pub const MAX_SAFE_WINDOW_SIZE: u32 = 10_000;
+pub const MAX_SAFE_ANTICONE_TRAVERSAL: u64 = 100_000;
+pub const MAX_UTXOS_BY_OUTPOINTS: usize = 10_000;
+pub const MAX_DAA_SCORE_TIMESTAMP_ESTIMATES: usize = 10_000;
 
 /// Client RPC Api
 ///
@@ -29,8 +32,20 @@ pub trait RpcApi: Sync + Send + AnySync {
     // ---
 
     async fn get_metrics(&self, process_metrics: bool, consensus_metrics: bool) -> RpcResult<GetMetricsResponse> {
-        self.get_metrics_call(GetMetricsRequest { process_metrics, consensus_metrics }).await
-    }
+        self.get_metrics_call(GetMetricsRequest {
+            process_metrics,
+            consensus_metrics,
+            p2p_metrics: false,
+            mempool_metrics: false,
+            dag_metrics: false,
+            storage_metrics: false,
+            utxoindex_metrics: false,
+        })
+        .await
+    }
+    /// Returns the metrics sections selected by the request.
+    ///
+    /// The metrics sections are currently only served over wRPC, a gRPC response carries none of them.
     async fn get_metrics_call(&self, request: GetMetricsRequest) -> RpcResult<GetMetricsResponse>;
 
     // get_info alternative that carries only version, network_id (full), is_synced, virtual_daa_score
@@ -107,7 +122,7 @@ pub trait RpcApi: Sync + Send + AnySync {
 
     /// requests information about all the p2p peers currently connected to this node.
     async fn get_connected_peer_info(&self) -> RpcResult<GetConnectedPeerInfoResponse> {
-        self.get_connected_peer_info_call(GetConnectedPeerInfoRequest {}).await
+        self.get_connected_peer_info_call(GetConnectedPeerInfoRequest::new(false)).await
     }
     async fn get_connected_peer_info_call(&self, request: GetConnectedPeerInfoRequest) -> RpcResult<GetConnectedPeerInfoResponse>;
 
@@ -126,6 +141,12 @@ pub trait RpcApi: Sync + Send + AnySync {
     }
     async fn submit_transaction_call(&self, request: SubmitTransactionRequest) -> RpcResult<SubmitTransactionResponse>;
 
+    /// Validates a transaction against the mempool rules, without inserting it into the mempool nor relaying it.
+    async fn validate_transaction(&self, transaction: RpcTransaction, allow_orphan: bool) -> RpcResult<ValidateTransactionResponse> {
+        self.validate_transaction_call(ValidateTransactionRequest::new(transaction, allow_orphan)).await
+    }
+    async fn validate_transaction_call(&self, request: ValidateTransactionRequest) -> RpcResult<ValidateTransactionResponse>;
+
     /// Requests information about a specific block.
     async fn get_block(&self, hash: RpcHash, include_transactions: bool) -> RpcResult<RpcBlock> {
         Ok(self.get_block_call(GetBlockRequest::new(hash, include_transactions)).await?.block)
@@ -194,7 +215,13 @@ pub trait RpcApi: Sync + Send + AnySync {
     }
     async fn shutdown_call(&self, request: ShutdownRequest) -> RpcResult<ShutdownResponse>;
 
-    /// Requests headers between the given `start_hash` and the current virtual, up to the given limit.
+    /// Requests up to `limit` headers starting at (and including) `start_hash`.
+    ///
+    /// Descending order follows the selected parent chain of `start_hash`. Ascending order follows the virtual
+    /// selected chain, from the first chain block in the future of `start_hash` up to the sink.
+    /// The node clamps `limit` to [`MAX_GET_HEADERS_LIMIT`](crate::api::headers::MAX_GET_HEADERS_LIMIT) headers per call.
+    /// A truncated response carries [`GetHeadersResponse::next_start_hash`] to continue from, see
+    /// [`get_headers_stream`](crate::api::headers::get_headers_stream) for syncing larger ranges.
     async fn get_headers(&self, start_hash: RpcHash, limit: u64, is_ascending: bool) -> RpcResult<Vec<RpcHeader>> {
         Ok(self.get_headers_call(GetHeadersRequest::new(start_hash, limit, is_ascending)).await?.headers)
     }
@@ -290,6 +317,106 @@ pub trait RpcApi: Sync + Send + AnySync {
     }
     async fn get_coin_supply_call(&self, request: GetCoinSupplyRequest) -> RpcResult<GetCoinSupplyResponse>;
 
+    /// Returns a merkle proof of the inclusion of a transaction in the `hash_merkle_root` of a block.
+    async fn get_transaction_inclusion_proof(
+        &self,
+        block_hash: RpcHash,
+        transaction_id: RpcTransactionId,
+    ) -> RpcResult<GetTransactionInclusionProofResponse> {
+        self.get_transaction_inclusion_proof_call(GetTransactionInclusionProofRequest::new(block_hash, transaction_id)).await
+    }
+    async fn get_transaction_inclusion_proof_call(
+        &self,
+        request: GetTransactionInclusionProofRequest,
+    ) -> RpcResult<GetTransactionInclusionProofResponse>;
+
+    /// Returns a merkle proof of the acceptance of a transaction in the `accepted_id_merkle_root` of a block
+    /// of the virtual selected chain.
+    async fn get_transaction_acceptance_proof(
+        &self,
+        accepting_block_hash: RpcHash,
+        transaction_id: RpcTransactionId,
+    ) -> RpcResult<RpcMerkleProof> {
+        Ok(self
+            .get_transaction_acceptance_proof_call(GetTransactionAcceptanceProofRequest::new(accepting_block_hash, transaction_id))
+            .await?
+            .proof)
+    }
+    async fn get_transaction_acceptance_proof_call(
+        &self,
+        request: GetTransactionAcceptanceProofRequest,
+    ) -> RpcResult<GetTransactionAcceptanceProofResponse>;
+
+    /// Requests the GHOSTDAG data of a block.
+    async fn get_block_ghostdag_data(&self, hash: RpcHash) -> RpcResult<RpcGhostdagData> {
+        Ok(self.get_block_ghostdag_data_call(GetBlockGhostdagDataRequest::new(hash)).await?.ghostdag_data)
+    }
+    async fn get_block_ghostdag_data_call(&self, request: GetBlockGhostdagDataRequest) -> RpcResult<GetBlockGhostdagDataResponse>;
+
+    /// Requests the hashes of the children of a block.
+    async fn get_block_children(&self, hash: RpcHash) -> RpcResult<Vec<RpcHash>> {
+        Ok(self.get_block_children_call(GetBlockChildrenRequest::new(hash)).await?.children_hashes)
+    }
+    async fn get_block_children_call(&self, request: GetBlockChildrenRequest) -> RpcResult<GetBlockChildrenResponse>;
+
+    /// Requests the hashes of the anticone of a block, relative to the virtual block.
+    async fn get_block_anticone(&self, hash: RpcHash) -> RpcResult<Vec<RpcHash>> {
+        Ok(self.get_block_anticone_call(GetBlockAnticoneRequest::new(hash)).await?.anticone_hashes)
+    }
+    async fn get_block_anticone_call(&self, request: GetBlockAnticoneRequest) -> RpcResult<GetBlockAnticoneResponse>;
+
+    /// Requests the acceptance data of a block.
+    async fn get_block_acceptance_data(&self, hash: RpcHash) -> RpcResult<Vec<RpcMergesetBlockAcceptanceData>> {
+        Ok(self.get_block_acceptance_data_call(GetBlockAcceptanceDataRequest::new(hash)).await?.acceptance_data)
+    }
+    async fn get_block_acceptance_data_call(
+        &self,
+        request: GetBlockAcceptanceDataRequest,
+    ) -> RpcResult<GetBlockAcceptanceDataResponse>;
+
+    /// Checks whether a block is on the virtual selected chain.
+    async fn is_chain_block(&self, hash: RpcHash) -> RpcResult<bool> {
+        Ok(self.is_chain_block_call(IsChainBlockRequest::new(hash)).await?.is_chain_block)
+    }
+    async fn is_chain_block_call(&self, request: IsChainBlockRequest) -> RpcResult<IsChainBlockResponse>;
+
+    /// Requests the virtual UTXO entries of a list of outpoints. Unlike [`RpcApi::get_utxos_by_addresses`],
+    /// this call reads the consensus UTXO set directly and does not require the UTXO index.
+    /// At most [`MAX_UTXOS_BY_OUTPOINTS`] outpoints can be requested at once.
+    async fn get_utxos_by_outpoints(&self, outpoints: Vec<RpcTransactionOutpoint>) -> RpcResult<Vec<RpcUtxosByOutpointsEntry>> {
+        Ok(self.get_utxos_by_outpoints_call(GetUtxosByOutpointsRequest::new(outpoints)).await?.entries)
+    }
+    async fn get_utxos_by_outpoints_call(&self, request: GetUtxosByOutpointsRequest) -> RpcResult<GetUtxosByOutpointsResponse>;
+
+    /// Requests timestamp estimates for a list of DAA scores, based on the selected chain blocks.
+    /// At most [`MAX_DAA_SCORE_TIMESTAMP_ESTIMATES`] DAA scores can be requested at once.
+    async fn get_daa_score_timestamp_estimate(&self, daa_scores: Vec<u64>) -> RpcResult<Vec<u64>> {
+        Ok(self.get_daa_score_timestamp_estimate_call(GetDaaScoreTimestampEstimateRequest::new(daa_scores)).await?.timestamps)
+    }
+    async fn get_daa_score_timestamp_estimate_call(
+        &self,
+        request: GetDaaScoreTimestampEstimateRequest,
+    ) -> RpcResult<GetDaaScoreTimestampEstimateResponse>;
+
+    /// Requests the log levels of the node subsystems.
+    async fn get_log_levels(&self) -> RpcResult<GetLogLevelsResponse> {
+        self.get_log_levels_call(GetLogLevelsRequest {}).await
+    }
+    async fn get_log_levels_call(&self, request: GetLogLevelsRequest) -> RpcResult<GetLogLevelsResponse>;
+
+    /// Applies log level `filters`, using the syntax of the `--loglevel` argument, on top of the current log levels.
+    async fn set_log_levels(&self, filters: String) -> RpcResult<SetLogLevelsResponse> {
+        self.set_log_levels_call(SetLogLevelsRequest::new(filters)).await
+    }
+    async fn set_log_levels_call(&self, request: SetLogLevelsRequest) -> RpcResult<SetLogLevelsResponse>;
+
+    /// Archives the current log files of the node and starts new ones.
+    async fn rotate_log_files(&self) -> RpcResult<()> {
+        self.rotate_log_files_call(RotateLogFilesRequest {}).await?;
+        Ok(())
+    }
+    async fn rotate_log_files_call(&self, request: RotateLogFilesRequest) -> RpcResult<RotateLogFilesResponse>;
+
     // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
     // Notification API
 
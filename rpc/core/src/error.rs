@@ -46,12 +46,21 @@ pub enum RpcError {
     #[error("Block {0} is invalid. No verbose data can be built.")]
     InvalidBlock(RpcHash),
 
+    #[error("Block {0} not found")]
+    BlockNotFound(RpcHash),
+
     #[error("If includeTransactions is set, then includeBlockVerboseData must be set as well.")]
     InvalidGetBlocksRequest,
 
     #[error("Transaction {0} not found")]
     TransactionNotFound(TransactionId),
 
+    #[error("Transaction {0} is not included in block {1}")]
+    TransactionNotInBlock(TransactionId, RpcHash),
+
+    #[error("Transaction {0} is not accepted by block {1}")]
+    TransactionNotAcceptedByBlock(TransactionId, RpcHash),
+
     #[error("Method unavailable. Run the node with the --utxoindex argument.")]
     NoUtxoIndex,
 
@@ -64,9 +73,27 @@ pub enum RpcError {
     #[error("Requested window size {0} is larger than pruning point depth {1}.")]
     WindowSizeExceedingPruningDepth(u32, u64),
 
+    #[error("Request of {0} items is larger than max {1} allowed.")]
+    RequestSizeExceedingMaximum(usize, usize),
+
     #[error("Method unavailable in safe mode. Run the node with --unsafe argument.")]
     UnavailableInSafeMode,
 
+    #[error("Transaction submission is disabled on this node.")]
+    SubmitTransactionDisabled,
+
+    #[error("Connection limit of {0} per IP address reached.")]
+    ConnectionLimitPerIpReached(usize),
+
+    #[error("Request rate limit of {0} requests per second exceeded.")]
+    RequestRateLimitExceeded(u32),
+
+    #[error("Concurrent request limit of {0} reached, try again later.")]
+    ConcurrentRequestLimitReached(usize),
+
+    #[error("Notification subscription limit of {0} reached.")]
+    SubscriptionLimitReached(usize),
+
     #[error("Cannot ban IP {0} because it has some permanent connection.")]
     IpHasPermanentConnection(IpAddress),
 
@@ -0,0 +1,42 @@
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use kaspa_consensus_core::trusted::ExternalGhostdagData;
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::{RpcBlueWorkType, RpcHash};
+
+/// Represents the GHOSTDAG data of a block
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcGhostdagData {
+    pub blue_score: u64,
+    pub blue_work: RpcBlueWorkType,
+    pub selected_parent: RpcHash,
+    pub mergeset_blues: Vec<RpcHash>,
+    pub mergeset_reds: Vec<RpcHash>,
+    pub blues_anticone_sizes: Vec<RpcBluesAnticoneSize>,
+}
+
+/// Represents the size of the anticone of a mergeset blue block, as seen from the merging block
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcBluesAnticoneSize {
+    pub blue_hash: RpcHash,
+    pub anticone_size: u16,
+}
+
+impl From<ExternalGhostdagData> for RpcGhostdagData {
+    fn from(data: ExternalGhostdagData) -> Self {
+        Self {
+            blue_score: data.blue_score,
+            blue_work: data.blue_work,
+            selected_parent: data.selected_parent,
+            mergeset_blues: data.mergeset_blues,
+            mergeset_reds: data.mergeset_reds,
+            blues_anticone_sizes: data
+                .blues_anticone_sizes
+                .into_iter()
+                .map(|(blue_hash, anticone_size)| RpcBluesAnticoneSize { blue_hash, anticone_size })
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,16 @@
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use serde::{Deserialize, Serialize};
+
+/// Represents the effective log level of a logging subsystem of the node
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcSubsystemLogLevel {
+    pub subsystem: String,
+    pub level: String,
+}
+
+impl RpcSubsystemLogLevel {
+    pub fn new(subsystem: String, level: String) -> Self {
+        Self { subsystem, level }
+    }
+}
@@ -0,0 +1 @@
+pub type RpcMerkleProof = kaspa_merkle::MerkleProof;
@@ -238,7 +238,17 @@ impl GetMempoolEntriesResponse {
 
 #[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
 #[serde(rename_all = "camelCase")]
-pub struct GetConnectedPeerInfoRequest {}
+pub struct GetConnectedPeerInfoRequest {
+    /// Include the traffic and usefulness statistics of each peer
+    #[serde(default)]
+    pub include_stats: bool,
+}
+
+impl GetConnectedPeerInfoRequest {
+    pub fn new(include_stats: bool) -> Self {
+        Self { include_stats }
+    }
+}
 
 #[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
 #[serde(rename_all = "camelCase")]
@@ -468,11 +478,16 @@ impl GetHeadersRequest {
 #[serde(rename_all = "camelCase")]
 pub struct GetHeadersResponse {
     pub headers: Vec<RpcHeader>,
+    /// Set when the response was truncated, either by the requested `limit` or by
+    /// [`MAX_GET_HEADERS_LIMIT`](crate::api::headers::MAX_GET_HEADERS_LIMIT). Passing it as `start_hash`
+    /// of the next request continues right after the last returned header.
+    #[serde(default)]
+    pub next_start_hash: Option<RpcHash>,
 }
 
 impl GetHeadersResponse {
-    pub fn new(headers: Vec<RpcHeader>) -> Self {
-        Self { headers }
+    pub fn new(headers: Vec<RpcHeader>, next_start_hash: Option<RpcHash>) -> Self {
+        Self { headers, next_start_hash }
     }
 }
 
@@ -665,6 +680,335 @@ impl GetCoinSupplyResponse {
     }
 }
 
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GetTransactionInclusionProofRequest {
+    pub block_hash: RpcHash,
+    pub transaction_id: RpcTransactionId,
+}
+
+impl GetTransactionInclusionProofRequest {
+    pub fn new(block_hash: RpcHash, transaction_id: RpcTransactionId) -> Self {
+        Self { block_hash, transaction_id }
+    }
+}
+
+/// Proves that a transaction is included in a block.
+///
+/// The proof leaf is `transaction_hash` and its root is the `hash_merkle_root` of the block header.
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GetTransactionInclusionProofResponse {
+    pub transaction_hash: RpcHash,
+    pub proof: RpcMerkleProof,
+}
+
+impl GetTransactionInclusionProofResponse {
+    pub fn new(transaction_hash: RpcHash, proof: RpcMerkleProof) -> Self {
+        Self { transaction_hash, proof }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GetTransactionAcceptanceProofRequest {
+    pub accepting_block_hash: RpcHash,
+    pub transaction_id: RpcTransactionId,
+}
+
+impl GetTransactionAcceptanceProofRequest {
+    pub fn new(accepting_block_hash: RpcHash, transaction_id: RpcTransactionId) -> Self {
+        Self { accepting_block_hash, transaction_id }
+    }
+}
+
+/// Proves that a transaction is accepted by a block of the virtual selected chain.
+///
+/// The proof leaf is the transaction id and its root is the `accepted_id_merkle_root` of the accepting block header.
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GetTransactionAcceptanceProofResponse {
+    pub proof: RpcMerkleProof,
+}
+
+impl GetTransactionAcceptanceProofResponse {
+    pub fn new(proof: RpcMerkleProof) -> Self {
+        Self { proof }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidateTransactionRequest {
+    pub transaction: RpcTransaction,
+    pub allow_orphan: bool,
+}
+
+impl ValidateTransactionRequest {
+    pub fn new(transaction: RpcTransaction, allow_orphan: bool) -> Self {
+        Self { transaction, allow_orphan }
+    }
+}
+
+/// Outcome of validating a transaction against the mempool rules.
+///
+/// `fee` and `feerate` (in sompi per gram of mass) are unknown, and thus `None`, when the transaction
+/// is an orphan or when it was rejected before its inputs could be populated.
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidateTransactionResponse {
+    pub transaction_id: RpcTransactionId,
+    pub is_accepted: bool,
+    pub is_orphan: bool,
+    pub mass: u64,
+    pub fee: Option<u64>,
+    pub feerate: Option<f64>,
+    pub rejection_reason: Option<String>,
+}
+
+impl ValidateTransactionResponse {
+    pub fn new(
+        transaction_id: RpcTransactionId,
+        is_accepted: bool,
+        is_orphan: bool,
+        mass: u64,
+        fee: Option<u64>,
+        feerate: Option<f64>,
+        rejection_reason: Option<String>,
+    ) -> Self {
+        Self { transaction_id, is_accepted, is_orphan, mass, fee, feerate, rejection_reason }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GetBlockGhostdagDataRequest {
+    pub hash: RpcHash,
+}
+
+impl GetBlockGhostdagDataRequest {
+    pub fn new(hash: RpcHash) -> Self {
+        Self { hash }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GetBlockGhostdagDataResponse {
+    pub ghostdag_data: RpcGhostdagData,
+}
+
+impl GetBlockGhostdagDataResponse {
+    pub fn new(ghostdag_data: RpcGhostdagData) -> Self {
+        Self { ghostdag_data }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GetBlockChildrenRequest {
+    pub hash: RpcHash,
+}
+
+impl GetBlockChildrenRequest {
+    pub fn new(hash: RpcHash) -> Self {
+        Self { hash }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GetBlockChildrenResponse {
+    pub children_hashes: Vec<RpcHash>,
+}
+
+impl GetBlockChildrenResponse {
+    pub fn new(children_hashes: Vec<RpcHash>) -> Self {
+        Self { children_hashes }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GetBlockAnticoneRequest {
+    pub hash: RpcHash,
+}
+
+impl GetBlockAnticoneRequest {
+    pub fn new(hash: RpcHash) -> Self {
+        Self { hash }
+    }
+}
+
+/// The anticone of the block is computed relative to the current virtual block, hence it may change as the DAG grows.
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GetBlockAnticoneResponse {
+    pub anticone_hashes: Vec<RpcHash>,
+}
+
+impl GetBlockAnticoneResponse {
+    pub fn new(anticone_hashes: Vec<RpcHash>) -> Self {
+        Self { anticone_hashes }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GetBlockAcceptanceDataRequest {
+    pub hash: RpcHash,
+}
+
+impl GetBlockAcceptanceDataRequest {
+    pub fn new(hash: RpcHash) -> Self {
+        Self { hash }
+    }
+}
+
+/// The acceptance data is made of the transactions accepted by the block from each block of its mergeset.
+/// It is only up to date for blocks of the virtual selected chain.
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GetBlockAcceptanceDataResponse {
+    pub acceptance_data: Vec<RpcMergesetBlockAcceptanceData>,
+}
+
+impl GetBlockAcceptanceDataResponse {
+    pub fn new(acceptance_data: Vec<RpcMergesetBlockAcceptanceData>) -> Self {
+        Self { acceptance_data }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct IsChainBlockRequest {
+    pub hash: RpcHash,
+}
+
+impl IsChainBlockRequest {
+    pub fn new(hash: RpcHash) -> Self {
+        Self { hash }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct IsChainBlockResponse {
+    pub is_chain_block: bool,
+}
+
+impl IsChainBlockResponse {
+    pub fn new(is_chain_block: bool) -> Self {
+        Self { is_chain_block }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GetUtxosByOutpointsRequest {
+    pub outpoints: Vec<RpcTransactionOutpoint>,
+}
+
+impl GetUtxosByOutpointsRequest {
+    pub fn new(outpoints: Vec<RpcTransactionOutpoint>) -> Self {
+        Self { outpoints }
+    }
+}
+
+/// The entries are in the order of the requested outpoints.
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GetUtxosByOutpointsResponse {
+    pub entries: Vec<RpcUtxosByOutpointsEntry>,
+}
+
+impl GetUtxosByOutpointsResponse {
+    pub fn new(entries: Vec<RpcUtxosByOutpointsEntry>) -> Self {
+        Self { entries }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GetDaaScoreTimestampEstimateRequest {
+    pub daa_scores: Vec<u64>,
+}
+
+impl GetDaaScoreTimestampEstimateRequest {
+    pub fn new(daa_scores: Vec<u64>) -> Self {
+        Self { daa_scores }
+    }
+}
+
+/// The timestamps (in milliseconds) are in the order of the requested DAA scores.
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GetDaaScoreTimestampEstimateResponse {
+    pub timestamps: Vec<u64>,
+}
+
+impl GetDaaScoreTimestampEstimateResponse {
+    pub fn new(timestamps: Vec<u64>) -> Self {
+        Self { timestamps }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GetLogLevelsRequest {}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GetLogLevelsResponse {
+    /// Level applying to any log target not covered by a subsystem level
+    pub root_level: String,
+    pub subsystems: Vec<RpcSubsystemLogLevel>,
+}
+
+impl GetLogLevelsResponse {
+    pub fn new(root_level: String, subsystems: Vec<RpcSubsystemLogLevel>) -> Self {
+        Self { root_level, subsystems }
+    }
+}
+
+/// SetLogLevelsRequest applies log level filters on top of the current ones.
+///
+/// The filters use the same syntax as the `--loglevel` argument of kaspad,
+/// ie. `info,kaspa_p2p_flows=debug` sets the root level to info and the
+/// `kaspa_p2p_flows` subsystem level to debug.
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SetLogLevelsRequest {
+    pub filters: String,
+}
+
+impl SetLogLevelsRequest {
+    pub fn new(filters: String) -> Self {
+        Self { filters }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SetLogLevelsResponse {
+    pub root_level: String,
+    pub subsystems: Vec<RpcSubsystemLogLevel>,
+}
+
+impl SetLogLevelsResponse {
+    pub fn new(root_level: String, subsystems: Vec<RpcSubsystemLogLevel>) -> Self {
+        Self { root_level, subsystems }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RotateLogFilesRequest {}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RotateLogFilesResponse {}
+
 #[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct PingRequest {}
@@ -680,6 +1024,16 @@ pub struct PingResponse {}
 pub struct GetMetricsRequest {
     pub process_metrics: bool,
     pub consensus_metrics: bool,
+    #[serde(default)]
+    pub p2p_metrics: bool,
+    #[serde(default)]
+    pub mempool_metrics: bool,
+    #[serde(default)]
+    pub dag_metrics: bool,
+    #[serde(default)]
+    pub storage_metrics: bool,
+    #[serde(default)]
+    pub utxoindex_metrics: bool,
 }
 
 #[derive(Default, Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
@@ -701,6 +1055,11 @@ pub struct ProcessMetrics {
     pub json_live_connections: u64,
     pub json_connection_attempts: u64,
     pub json_handshake_failures: u64,
+
+    pub rpc_rejected_connections: u64,
+    pub rpc_rate_limited_requests: u64,
+    pub rpc_concurrency_limited_requests: u64,
+    pub rpc_rejected_subscriptions: u64,
 }
 
 #[derive(Default, Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
@@ -715,17 +1074,135 @@ pub struct ConsensusMetrics {
     pub mass_counts: u64,
 }
 
+#[derive(Default, Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct P2pMetrics {
+    pub inbound_peers: u64,
+    pub outbound_peers: u64,
+    /// Statistics accumulated over all the peer connections since the node started
+    pub peer_stats: RpcPeerStats,
+}
+
+/// Mempool transactions having a fee rate in `[min_fee_rate, next bucket min_fee_rate)`
+#[derive(Default, Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcFeeRateBucket {
+    /// Fee rate lower bound in sompi per gram
+    pub min_fee_rate: f64,
+    pub transaction_count: u64,
+    pub total_mass: u64,
+}
+
+#[derive(Default, Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MempoolMetrics {
+    pub transaction_count: u64,
+    pub orphan_count: u64,
+    pub total_mass: u64,
+    pub total_fees: u64,
+    pub fee_rate_histogram: Vec<RpcFeeRateBucket>,
+}
+
+#[derive(Default, Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DagMetrics {
+    pub tip_count: u64,
+    pub virtual_daa_score: u64,
+    pub pruning_point_daa_score: u64,
+    /// DAA score distance between virtual and the pruning point
+    pub pruning_point_daa_age: u64,
+    /// Milliseconds elapsed since the pruning point timestamp
+    pub pruning_point_time_age: u64,
+
+    pub header_queue_depth: u64,
+    pub body_queue_depth: u64,
+    pub virtual_queue_depth: u64,
+    pub pruning_queue_depth: u64,
+
+    /// Average processing latencies, in microseconds, since the node started
+    pub header_processing_avg_micros: u64,
+    pub body_processing_avg_micros: u64,
+    pub virtual_processing_avg_micros: u64,
+}
+
+/// Approximate storage and cache statistics of a single database store
+#[derive(Default, Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcStoreMetrics {
+    pub prefix: u8,
+    pub name: String,
+    pub size: u64,
+    pub entries: u64,
+    pub cache_hits: u64,
+    pub cache_misses: u64,
+}
+
+impl RpcStoreMetrics {
+    pub fn cache_hit_rate(&self) -> f64 {
+        let lookups = self.cache_hits + self.cache_misses;
+        if lookups == 0 {
+            0.0
+        } else {
+            self.cache_hits as f64 / lookups as f64
+        }
+    }
+}
+
+#[derive(Default, Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageMetrics {
+    /// Statistics of the consensus and utxoindex stores, ordered by store prefix
+    pub stores: Vec<RpcStoreMetrics>,
+}
+
+#[derive(Default, Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UtxoIndexMetrics {
+    /// Approximate number of indexed UTXO entries
+    pub entries: u64,
+    pub circulating_supply: u64,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct GetMetricsResponse {
     pub server_time: u128,
     pub process_metrics: Option<ProcessMetrics>,
     pub consensus_metrics: Option<ConsensusMetrics>,
+    #[serde(default)]
+    pub p2p_metrics: Option<P2pMetrics>,
+    #[serde(default)]
+    pub mempool_metrics: Option<MempoolMetrics>,
+    #[serde(default)]
+    pub dag_metrics: Option<DagMetrics>,
+    #[serde(default)]
+    pub storage_metrics: Option<StorageMetrics>,
+    #[serde(default)]
+    pub utxoindex_metrics: Option<UtxoIndexMetrics>,
 }
 
 impl GetMetricsResponse {
-    pub fn new(server_time: u128, process_metrics: Option<ProcessMetrics>, consensus_metrics: Option<ConsensusMetrics>) -> Self {
-        Self { process_metrics, consensus_metrics, server_time }
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        server_time: u128,
+        process_metrics: Option<ProcessMetrics>,
+        consensus_metrics: Option<ConsensusMetrics>,
+        p2p_metrics: Option<P2pMetrics>,
+        mempool_metrics: Option<MempoolMetrics>,
+        dag_metrics: Option<DagMetrics>,
+        storage_metrics: Option<StorageMetrics>,
+        utxoindex_metrics: Option<UtxoIndexMetrics>,
+    ) -> Self {
+        Self {
+            process_metrics,
+            consensus_metrics,
+            p2p_metrics,
+            mempool_metrics,
+            dag_metrics,
+            storage_metrics,
+            utxoindex_metrics,
+            server_time,
+        }
     }
 }
 
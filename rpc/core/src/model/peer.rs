@@ -20,4 +20,40 @@ pub struct RpcPeerInfo {
     pub advertised_protocol_version: u32,
     pub time_connected: u64, // NOTE: i64 in gRPC protowire
     pub is_ibd_peer: bool,
+
+    /// Traffic and usefulness statistics, only provided on request
+    pub stats: Option<RpcPeerStats>,
+}
+
+/// Traffic counters of a single P2P message type
+#[derive(Clone, Debug, Default, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcMessageTypeStats {
+    pub message_type: String,
+    pub messages_sent: u64,
+    pub bytes_sent: u64,
+    pub messages_received: u64,
+    pub bytes_received: u64,
+}
+
+/// Statistics gathered over the lifetime of one or more peer connections
+#[derive(Clone, Debug, Default, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcPeerStats {
+    pub messages_sent: u64,
+    pub bytes_sent: u64,
+    pub messages_received: u64,
+    pub bytes_received: u64,
+
+    /// Number of messages which were found to be invalid or unexpected
+    pub invalid_messages: u64,
+    /// Number of relayed blocks received and added to the DAG
+    pub relayed_blocks: u64,
+    /// Number of relayed transactions received and accepted to the mempool
+    pub relayed_transactions: u64,
+    /// Number of IBD sessions run with the peer as syncer
+    pub ibd_sessions: u64,
+
+    /// Traffic per message type, sorted by message type
+    pub message_types: Vec<RpcMessageTypeStats>,
 }
@@ -1,5 +1,6 @@
 use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
 use kaspa_addresses::Address;
+use kaspa_consensus_core::acceptance_data::MergesetBlockAcceptanceData;
 use kaspa_consensus_core::tx::{
     ScriptPublicKey, ScriptVec, TransactionId, TransactionInput, TransactionOutpoint, TransactionOutput, UtxoEntry,
 };
@@ -112,3 +113,43 @@ pub struct RpcAcceptedTransactionIds {
     pub accepting_block_hash: RpcHash,
     pub accepted_transaction_ids: Vec<RpcTransactionId>,
 }
+
+/// Represents the virtual UTXO entry of an outpoint, `None` if the outpoint is spent or unknown
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcUtxosByOutpointsEntry {
+    pub outpoint: RpcTransactionOutpoint,
+    pub utxo_entry: Option<RpcUtxoEntry>,
+}
+
+/// Represents the transactions of a mergeset block which were accepted by the merging chain block
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcMergesetBlockAcceptanceData {
+    pub block_hash: RpcHash,
+    pub accepted_transactions: Vec<RpcAcceptedTransactionEntry>,
+}
+
+/// Represents an accepted transaction along with its index within its containing block
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcAcceptedTransactionEntry {
+    pub transaction_id: RpcTransactionId,
+    pub index_within_block: u32,
+}
+
+impl From<&MergesetBlockAcceptanceData> for RpcMergesetBlockAcceptanceData {
+    fn from(item: &MergesetBlockAcceptanceData) -> Self {
+        Self {
+            block_hash: item.block_hash,
+            accepted_transactions: item
+                .accepted_transactions
+                .iter()
+                .map(|entry| RpcAcceptedTransactionEntry {
+                    transaction_id: entry.transaction_id,
+                    index_within_block: entry.index_within_block,
+                })
+                .collect(),
+        }
+    }
+}
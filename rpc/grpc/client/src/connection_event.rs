@@ -2,4 +2,10 @@
 pub enum ConnectionEvent {
     Connected,
     Disconnected,
+    /// Sent after [`Connected`](ConnectionEvent::Connected) once an automatic reconnection
+    /// succeeded and all active subscriptions were renewed. Notifications sent by the server
+    /// while disconnected are lost, so consumers tracking state derived from notifications
+    /// (i.e. `UtxosChanged`) should resync it, as the wallet `UtxoProcessor` does when its
+    /// RPC client reconnects.
+    Reconnected,
 }
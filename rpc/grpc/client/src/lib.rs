@@ -4,7 +4,6 @@ use self::{
 };
 use async_channel::{Receiver, Sender};
 use async_trait::async_trait;
-use connection_event::ConnectionEvent;
 use futures::{future::FutureExt, pin_mut, select};
 use kaspa_core::{debug, trace};
 use kaspa_grpc_core::{
@@ -44,12 +43,16 @@ use tokio::sync::Mutex;
 use tonic::Streaming;
 use tonic::{codec::CompressionEncoding, transport::Endpoint};
 
-mod connection_event;
+pub mod connection_event;
 pub mod error;
+pub mod reconnect;
 mod resolver;
 #[macro_use]
 mod route;
 
+pub use connection_event::ConnectionEvent;
+pub use reconnect::ReconnectPolicy;
+
 pub type GrpcClientCollector = CollectorFrom<RpcCoreConverter>;
 pub type GrpcClientNotify = DynNotify<Notification>;
 pub type GrpcClientNotifier = Notifier<Notification, ChannelConnection>;
@@ -70,6 +73,7 @@ pub struct GrpcClient {
 const GRPC_CLIENT: &str = "grpc-client";
 
 impl GrpcClient {
+    /// Connects to the server, reconnecting automatically every [`RECONNECT_INTERVAL`] milliseconds if `reconnect` is set.
     pub async fn connect(
         notification_mode: NotificationMode,
         url: String,
@@ -77,6 +81,30 @@ impl GrpcClient {
         connection_event_sender: Option<Sender<ConnectionEvent>>,
         override_handle_stop_notify: bool,
         timeout_duration: Option<u64>,
+    ) -> Result<GrpcClient> {
+        let reconnect_policy = reconnect.then(|| ReconnectPolicy::fixed(RECONNECT_INTERVAL));
+        Self::connect_with_policy(
+            notification_mode,
+            url,
+            reconnect_policy,
+            connection_event_sender,
+            override_handle_stop_notify,
+            timeout_duration,
+        )
+        .await
+    }
+
+    /// Connects to the server, reconnecting automatically according to `reconnect_policy` if provided.
+    ///
+    /// On reconnection, the active subscriptions are renewed and a [`ConnectionEvent::Reconnected`]
+    /// event is sent to `connection_event_sender`.
+    pub async fn connect_with_policy(
+        notification_mode: NotificationMode,
+        url: String,
+        reconnect_policy: Option<ReconnectPolicy>,
+        connection_event_sender: Option<Sender<ConnectionEvent>>,
+        override_handle_stop_notify: bool,
+        timeout_duration: Option<u64>,
     ) -> Result<GrpcClient> {
         let schema = Regex::new(r"^grpc://").unwrap();
         if !schema.is_match(&url) {
@@ -105,9 +133,9 @@ impl GrpcClient {
             }
         };
 
-        if reconnect {
+        if let Some(reconnect_policy) = reconnect_policy {
             // Start the connection monitor
-            inner.clone().spawn_connection_monitor(notifier.clone(), subscriptions.clone());
+            inner.clone().spawn_connection_monitor(reconnect_policy, notifier.clone(), subscriptions.clone());
         }
 
         Ok(Self { inner, notifier, collector, subscriptions, notification_mode })
@@ -172,6 +200,22 @@ impl GrpcClient {
     pub fn notification_mode(&self) -> NotificationMode {
         self.notification_mode
     }
+
+    /// Returns the scopes of the active subscriptions in Direct mode, allowing
+    /// them to be re-established on another client.
+    pub async fn subscriptions(&self) -> Vec<Scope> {
+        match self.subscriptions.as_ref() {
+            Some(subscriptions) => {
+                let subscriptions = subscriptions.lock().await;
+                EVENT_TYPE_ARRAY
+                    .into_iter()
+                    .filter(|event| subscriptions[*event].active())
+                    .map(|event| subscriptions[event].scope())
+                    .collect()
+            }
+            None => vec![],
+        }
+    }
 }
 
 #[async_trait]
@@ -231,6 +275,19 @@ impl RpcApi for GrpcClient {
     route!(estimate_network_hashes_per_second_call, EstimateNetworkHashesPerSecond);
     route!(get_mempool_entries_by_addresses_call, GetMempoolEntriesByAddresses);
     route!(get_coin_supply_call, GetCoinSupply);
+    route!(get_transaction_inclusion_proof_call, GetTransactionInclusionProof);
+    route!(get_transaction_acceptance_proof_call, GetTransactionAcceptanceProof);
+    route!(validate_transaction_call, ValidateTransaction);
+    route!(get_block_ghostdag_data_call, GetBlockGhostdagData);
+    route!(get_block_children_call, GetBlockChildren);
+    route!(get_block_anticone_call, GetBlockAnticone);
+    route!(get_block_acceptance_data_call, GetBlockAcceptanceData);
+    route!(is_chain_block_call, IsChainBlock);
+    route!(get_utxos_by_outpoints_call, GetUtxosByOutpoints);
+    route!(get_daa_score_timestamp_estimate_call, GetDaaScoreTimestampEstimate);
+    route!(get_log_levels_call, GetLogLevels);
+    route!(set_log_levels_call, SetLogLevels);
+    route!(rotate_log_files_call, RotateLogFiles);
 
     // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
     // Notification API
@@ -368,7 +425,6 @@ struct Inner {
     // Connection monitor allowing to reconnect automatically to the server
     connector_is_running: AtomicBool,
     connector_shutdown: DuplexTrigger,
-    connector_timer_interval: u64,
 
     // Connection event channel
     connection_event_sender: Option<Sender<ConnectionEvent>>,
@@ -407,7 +463,6 @@ impl Inner {
             timeout_timer_interval: TIMEOUT_MONITORING_INTERVAL,
             connector_is_running: AtomicBool::new(false),
             connector_shutdown: DuplexTrigger::new(),
-            connector_timer_interval: RECONNECT_INTERVAL,
             connection_event_sender,
             override_handle_stop_notify,
         }
@@ -537,6 +592,7 @@ impl Inner {
             }
         }
 
+        self.send_connection_event(ConnectionEvent::Reconnected);
         trace!("GRPC client: reconnected");
         Ok(())
     }
@@ -701,9 +757,10 @@ impl Inner {
     }
 
     /// Launch a task that periodically checks if the connection to the server is alive
-    /// and if not that tries to reconnect to the server.
+    /// and if not that tries to reconnect to the server, backing off according to `policy`.
     fn spawn_connection_monitor(
         self: Arc<Self>,
+        policy: ReconnectPolicy,
         notifier: Option<Arc<GrpcClientNotifier>>,
         subscriptions: Option<Arc<DirectSubscriptions>>,
     ) {
@@ -719,9 +776,9 @@ impl Inner {
             trace!("GRPC client: connection monitor task - started");
             let shutdown = self.connector_shutdown.request.listener.clone().fuse();
             pin_mut!(shutdown);
+            let mut connector_timer_interval = policy.initial_delay;
             loop {
-                let connector_timer_interval = Duration::from_millis(self.connector_timer_interval);
-                let delay = tokio::time::sleep(connector_timer_interval).fuse();
+                let delay = tokio::time::sleep(Duration::from_millis(connector_timer_interval)).fuse();
                 pin_mut!(delay);
                 select! {
                     _ = shutdown => { break; },
@@ -731,9 +788,11 @@ impl Inner {
                             match self.clone().reconnect(notifier.clone(), subscriptions.clone()).await {
                                 Ok(_) => {
                                     trace!("GRPC client: reconnection to server succeeded");
+                                    connector_timer_interval = policy.initial_delay;
                                 },
                                 Err(err) => {
-                                    trace!("GRPC client: reconnection to server failed with error {err:?}");
+                                    connector_timer_interval = policy.next_delay(connector_timer_interval);
+                                    trace!("GRPC client: reconnection to server failed with error {err:?}, retrying in {connector_timer_interval} ms");
                                 }
                             }
                         }
@@ -0,0 +1,60 @@
+use crate::RECONNECT_INTERVAL;
+
+/// Policy applied by the connection monitor of a [`GrpcClient`](crate::GrpcClient)
+/// when the connection to the server is lost.
+///
+/// After each failed attempt, the delay before the next one is multiplied by
+/// `multiplier`, up to `max_delay`. The delay is reset once reconnected.
+#[derive(Clone, Copy, Debug)]
+pub struct ReconnectPolicy {
+    /// Delay (in milliseconds) before the first reconnection attempt
+    pub initial_delay: u64,
+    /// Maximum delay (in milliseconds) between two reconnection attempts
+    pub max_delay: u64,
+    pub multiplier: u64,
+}
+
+impl ReconnectPolicy {
+    pub fn new(initial_delay: u64, max_delay: u64, multiplier: u64) -> Self {
+        Self { initial_delay, max_delay: max_delay.max(initial_delay), multiplier: multiplier.max(1) }
+    }
+
+    /// A policy retrying at a fixed interval (in milliseconds)
+    pub fn fixed(interval: u64) -> Self {
+        Self::new(interval, interval, 1)
+    }
+
+    /// Delay to wait after a failed attempt that was preceded by `delay`
+    pub fn next_delay(&self, delay: u64) -> u64 {
+        delay.saturating_mul(self.multiplier).clamp(self.initial_delay, self.max_delay)
+    }
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self::new(RECONNECT_INTERVAL, 60_000, 2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_delay() {
+        let policy = ReconnectPolicy::new(1_000, 10_000, 2);
+        let delays =
+            std::iter::successors(Some(policy.initial_delay), |&delay| Some(policy.next_delay(delay))).take(6).collect::<Vec<_>>();
+        assert_eq!(delays, vec![1_000, 2_000, 4_000, 8_000, 10_000, 10_000]);
+        assert_eq!(policy.next_delay(0), 1_000);
+        assert_eq!(policy.next_delay(u64::MAX), 10_000);
+
+        let policy = ReconnectPolicy::fixed(RECONNECT_INTERVAL);
+        assert_eq!(policy.next_delay(RECONNECT_INTERVAL), RECONNECT_INTERVAL);
+
+        // Invalid parameters are normalized
+        let policy = ReconnectPolicy::new(5_000, 1_000, 0);
+        assert_eq!((policy.max_delay, policy.multiplier), (5_000, 1));
+        assert_eq!(policy.next_delay(5_000), 5_000);
+    }
+}
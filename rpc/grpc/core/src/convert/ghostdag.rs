@@ -0,0 +1,42 @@
+use crate::protowire;
+use crate::{from, try_from};
+use kaspa_rpc_core::{FromRpcHex, RpcError, RpcHash, ToRpcHex};
+use std::str::FromStr;
+
+// ----------------------------------------------------------------------------
+// rpc_core to protowire
+// ----------------------------------------------------------------------------
+
+from!(item: &kaspa_rpc_core::RpcGhostdagData, protowire::RpcGhostdagData, {
+    Self {
+        blue_score: item.blue_score,
+        blue_work: item.blue_work.to_rpc_hex(),
+        selected_parent: item.selected_parent.to_string(),
+        mergeset_blues: item.mergeset_blues.iter().map(|x| x.to_string()).collect(),
+        mergeset_reds: item.mergeset_reds.iter().map(|x| x.to_string()).collect(),
+        blues_anticone_sizes: item.blues_anticone_sizes.iter().map(|x| x.into()).collect(),
+    }
+});
+
+from!(item: &kaspa_rpc_core::RpcBluesAnticoneSize, protowire::RpcBluesAnticoneSize, {
+    Self { blue_hash: item.blue_hash.to_string(), anticone_size: item.anticone_size.into() }
+});
+
+// ----------------------------------------------------------------------------
+// protowire to rpc_core
+// ----------------------------------------------------------------------------
+
+try_from!(item: &protowire::RpcGhostdagData, kaspa_rpc_core::RpcGhostdagData, {
+    Self {
+        blue_score: item.blue_score,
+        blue_work: kaspa_rpc_core::RpcBlueWorkType::from_rpc_hex(&item.blue_work)?,
+        selected_parent: RpcHash::from_str(&item.selected_parent)?,
+        mergeset_blues: item.mergeset_blues.iter().map(|x| RpcHash::from_str(x)).collect::<Result<Vec<_>, _>>()?,
+        mergeset_reds: item.mergeset_reds.iter().map(|x| RpcHash::from_str(x)).collect::<Result<Vec<_>, _>>()?,
+        blues_anticone_sizes: item.blues_anticone_sizes.iter().map(|x| x.try_into()).collect::<Result<Vec<_>, _>>()?,
+    }
+});
+
+try_from!(item: &protowire::RpcBluesAnticoneSize, kaspa_rpc_core::RpcBluesAnticoneSize, {
+    Self { blue_hash: RpcHash::from_str(&item.blue_hash)?, anticone_size: item.anticone_size.try_into()? }
+});
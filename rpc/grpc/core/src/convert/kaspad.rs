@@ -37,6 +37,19 @@ impl From<&kaspad_request::Payload> for RpcApiOps {
             Payload::GetCoinSupplyRequest(_) => RpcApiOps::GetCoinSupply,
             Payload::PingRequest(_) => RpcApiOps::Ping,
             Payload::GetMetricsRequest(_) => RpcApiOps::GetMetrics,
+            Payload::GetTransactionInclusionProofRequest(_) => RpcApiOps::GetTransactionInclusionProof,
+            Payload::GetTransactionAcceptanceProofRequest(_) => RpcApiOps::GetTransactionAcceptanceProof,
+            Payload::ValidateTransactionRequest(_) => RpcApiOps::ValidateTransaction,
+            Payload::GetBlockGhostdagDataRequest(_) => RpcApiOps::GetBlockGhostdagData,
+            Payload::GetBlockChildrenRequest(_) => RpcApiOps::GetBlockChildren,
+            Payload::GetBlockAnticoneRequest(_) => RpcApiOps::GetBlockAnticone,
+            Payload::GetBlockAcceptanceDataRequest(_) => RpcApiOps::GetBlockAcceptanceData,
+            Payload::IsChainBlockRequest(_) => RpcApiOps::IsChainBlock,
+            Payload::GetUtxosByOutpointsRequest(_) => RpcApiOps::GetUtxosByOutpoints,
+            Payload::GetDaaScoreTimestampEstimateRequest(_) => RpcApiOps::GetDaaScoreTimestampEstimate,
+            Payload::GetLogLevelsRequest(_) => RpcApiOps::GetLogLevels,
+            Payload::SetLogLevelsRequest(_) => RpcApiOps::SetLogLevels,
+            Payload::RotateLogFilesRequest(_) => RpcApiOps::RotateLogFiles,
 
             // Subscription commands for starting/stopping notifications
             Payload::NotifyBlockAddedRequest(_) => RpcApiOps::NotifyBlockAdded,
@@ -90,6 +103,19 @@ impl From<&kaspad_response::Payload> for RpcApiOps {
             Payload::GetCoinSupplyResponse(_) => RpcApiOps::GetCoinSupply,
             Payload::PingResponse(_) => RpcApiOps::Ping,
             Payload::GetMetricsResponse(_) => RpcApiOps::GetMetrics,
+            Payload::GetTransactionInclusionProofResponse(_) => RpcApiOps::GetTransactionInclusionProof,
+            Payload::GetTransactionAcceptanceProofResponse(_) => RpcApiOps::GetTransactionAcceptanceProof,
+            Payload::ValidateTransactionResponse(_) => RpcApiOps::ValidateTransaction,
+            Payload::GetBlockGhostdagDataResponse(_) => RpcApiOps::GetBlockGhostdagData,
+            Payload::GetBlockChildrenResponse(_) => RpcApiOps::GetBlockChildren,
+            Payload::GetBlockAnticoneResponse(_) => RpcApiOps::GetBlockAnticone,
+            Payload::GetBlockAcceptanceDataResponse(_) => RpcApiOps::GetBlockAcceptanceData,
+            Payload::IsChainBlockResponse(_) => RpcApiOps::IsChainBlock,
+            Payload::GetUtxosByOutpointsResponse(_) => RpcApiOps::GetUtxosByOutpoints,
+            Payload::GetDaaScoreTimestampEstimateResponse(_) => RpcApiOps::GetDaaScoreTimestampEstimate,
+            Payload::GetLogLevelsResponse(_) => RpcApiOps::GetLogLevels,
+            Payload::SetLogLevelsResponse(_) => RpcApiOps::SetLogLevels,
+            Payload::RotateLogFilesResponse(_) => RpcApiOps::RotateLogFiles,
 
             // Subscription commands for starting/stopping notifications
             Payload::NotifyBlockAddedResponse(_) => RpcApiOps::NotifyBlockAdded,
@@ -172,6 +198,19 @@ pub mod kaspad_request_convert {
     impl_into_kaspad_request!(GetCoinSupply);
     impl_into_kaspad_request!(Ping);
     impl_into_kaspad_request!(GetMetrics);
+    impl_into_kaspad_request!(GetTransactionInclusionProof);
+    impl_into_kaspad_request!(GetTransactionAcceptanceProof);
+    impl_into_kaspad_request!(ValidateTransaction);
+    impl_into_kaspad_request!(GetBlockGhostdagData);
+    impl_into_kaspad_request!(GetBlockChildren);
+    impl_into_kaspad_request!(GetBlockAnticone);
+    impl_into_kaspad_request!(GetBlockAcceptanceData);
+    impl_into_kaspad_request!(IsChainBlock);
+    impl_into_kaspad_request!(GetUtxosByOutpoints);
+    impl_into_kaspad_request!(GetDaaScoreTimestampEstimate);
+    impl_into_kaspad_request!(GetLogLevels);
+    impl_into_kaspad_request!(SetLogLevels);
+    impl_into_kaspad_request!(RotateLogFiles);
 
     impl_into_kaspad_request!(NotifyBlockAdded);
     impl_into_kaspad_request!(NotifyNewBlockTemplate);
@@ -300,6 +339,19 @@ pub mod kaspad_response_convert {
     impl_into_kaspad_response!(GetCoinSupply);
     impl_into_kaspad_response!(Ping);
     impl_into_kaspad_response!(GetMetrics);
+    impl_into_kaspad_response!(GetTransactionInclusionProof);
+    impl_into_kaspad_response!(GetTransactionAcceptanceProof);
+    impl_into_kaspad_response!(ValidateTransaction);
+    impl_into_kaspad_response!(GetBlockGhostdagData);
+    impl_into_kaspad_response!(GetBlockChildren);
+    impl_into_kaspad_response!(GetBlockAnticone);
+    impl_into_kaspad_response!(GetBlockAcceptanceData);
+    impl_into_kaspad_response!(IsChainBlock);
+    impl_into_kaspad_response!(GetUtxosByOutpoints);
+    impl_into_kaspad_response!(GetDaaScoreTimestampEstimate);
+    impl_into_kaspad_response!(GetLogLevels);
+    impl_into_kaspad_response!(SetLogLevels);
+    impl_into_kaspad_response!(RotateLogFiles);
 
     impl_into_kaspad_notify_response!(NotifyBlockAdded);
     impl_into_kaspad_notify_response!(NotifyNewBlockTemplate);
@@ -0,0 +1,20 @@
+use crate::protowire;
+use crate::{from, try_from};
+use kaspa_rpc_core::{RpcError, RpcHash};
+use std::str::FromStr;
+
+// ----------------------------------------------------------------------------
+// rpc_core to protowire
+// ----------------------------------------------------------------------------
+
+from!(item: &kaspa_rpc_core::RpcMerkleProof, protowire::RpcMerkleProof, {
+    Self { index: item.index, siblings: item.siblings.iter().map(|x| x.to_string()).collect() }
+});
+
+// ----------------------------------------------------------------------------
+// protowire to rpc_core
+// ----------------------------------------------------------------------------
+
+try_from!(item: &protowire::RpcMerkleProof, kaspa_rpc_core::RpcMerkleProof, {
+    Self { index: item.index, siblings: item.siblings.iter().map(|x| RpcHash::from_str(x)).collect::<Result<Vec<_>, _>>()? }
+});
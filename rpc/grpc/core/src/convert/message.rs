@@ -1,6 +1,7 @@
 use crate::protowire::{self, submit_block_response_message::RejectReason};
 use kaspa_rpc_core::{
     RpcContextualPeerAddress, RpcError, RpcExtraData, RpcHash, RpcIpAddress, RpcNetworkType, RpcPeerAddress, RpcResult,
+    RpcTransactionId,
 };
 use std::str::FromStr;
 
@@ -196,7 +197,9 @@ from!(item: RpcResult<&kaspa_rpc_core::GetMempoolEntriesResponse>, protowire::Ge
     Self { entries: item.mempool_entries.iter().map(|x| x.into()).collect(), error: None }
 });
 
-from!(&kaspa_rpc_core::GetConnectedPeerInfoRequest, protowire::GetConnectedPeerInfoRequestMessage);
+from!(item: &kaspa_rpc_core::GetConnectedPeerInfoRequest, protowire::GetConnectedPeerInfoRequestMessage, {
+    Self { include_stats: item.include_stats }
+});
 from!(item: RpcResult<&kaspa_rpc_core::GetConnectedPeerInfoResponse>, protowire::GetConnectedPeerInfoResponseMessage, {
     Self { infos: item.peer_info.iter().map(|x| x.into()).collect(), error: None }
 });
@@ -284,7 +287,11 @@ from!(item: &kaspa_rpc_core::GetHeadersRequest, protowire::GetHeadersRequestMess
     Self { start_hash: item.start_hash.to_string(), limit: item.limit, is_ascending: item.is_ascending }
 });
 from!(item: RpcResult<&kaspa_rpc_core::GetHeadersResponse>, protowire::GetHeadersResponseMessage, {
-    Self { headers: item.headers.iter().map(|x| x.hash.to_string()).collect(), error: None }
+    Self {
+        block_headers: item.headers.iter().map(|x| x.into()).collect(),
+        next_start_hash: item.next_start_hash.map_or(Default::default(), |x| x.to_string()),
+        error: None,
+    }
 });
 
 from!(item: &kaspa_rpc_core::GetUtxosByAddressesRequest, protowire::GetUtxosByAddressesRequestMessage, {
@@ -341,6 +348,98 @@ from!(
     { Self { entries: item.entries.iter().map(|x| x.into()).collect(), error: None } }
 );
 
+from!(item: &kaspa_rpc_core::GetTransactionInclusionProofRequest, protowire::GetTransactionInclusionProofRequestMessage, {
+    Self { block_hash: item.block_hash.to_string(), transaction_id: item.transaction_id.to_string() }
+});
+from!(item: RpcResult<&kaspa_rpc_core::GetTransactionInclusionProofResponse>, protowire::GetTransactionInclusionProofResponseMessage, {
+    Self { transaction_hash: item.transaction_hash.to_string(), proof: Some((&item.proof).into()), error: None }
+});
+
+from!(item: &kaspa_rpc_core::GetTransactionAcceptanceProofRequest, protowire::GetTransactionAcceptanceProofRequestMessage, {
+    Self { accepting_block_hash: item.accepting_block_hash.to_string(), transaction_id: item.transaction_id.to_string() }
+});
+from!(item: RpcResult<&kaspa_rpc_core::GetTransactionAcceptanceProofResponse>, protowire::GetTransactionAcceptanceProofResponseMessage, {
+    Self { proof: Some((&item.proof).into()), error: None }
+});
+
+from!(item: &kaspa_rpc_core::ValidateTransactionRequest, protowire::ValidateTransactionRequestMessage, {
+    Self { transaction: Some((&item.transaction).into()), allow_orphan: item.allow_orphan }
+});
+from!(item: RpcResult<&kaspa_rpc_core::ValidateTransactionResponse>, protowire::ValidateTransactionResponseMessage, {
+    Self {
+        transaction_id: item.transaction_id.to_string(),
+        is_accepted: item.is_accepted,
+        is_orphan: item.is_orphan,
+        mass: item.mass,
+        fee: item.fee.unwrap_or_default(),
+        feerate: item.feerate.unwrap_or_default(),
+        rejection_reason: item.rejection_reason.clone().unwrap_or_default(),
+        error: None,
+    }
+});
+
+from!(item: &kaspa_rpc_core::GetBlockGhostdagDataRequest, protowire::GetBlockGhostdagDataRequestMessage, {
+    Self { hash: item.hash.to_string() }
+});
+from!(item: RpcResult<&kaspa_rpc_core::GetBlockGhostdagDataResponse>, protowire::GetBlockGhostdagDataResponseMessage, {
+    Self { ghostdag_data: Some((&item.ghostdag_data).into()), error: None }
+});
+
+from!(item: &kaspa_rpc_core::GetBlockChildrenRequest, protowire::GetBlockChildrenRequestMessage, {
+    Self { hash: item.hash.to_string() }
+});
+from!(item: RpcResult<&kaspa_rpc_core::GetBlockChildrenResponse>, protowire::GetBlockChildrenResponseMessage, {
+    Self { children_hashes: item.children_hashes.iter().map(|x| x.to_string()).collect(), error: None }
+});
+
+from!(item: &kaspa_rpc_core::GetBlockAnticoneRequest, protowire::GetBlockAnticoneRequestMessage, {
+    Self { hash: item.hash.to_string() }
+});
+from!(item: RpcResult<&kaspa_rpc_core::GetBlockAnticoneResponse>, protowire::GetBlockAnticoneResponseMessage, {
+    Self { anticone_hashes: item.anticone_hashes.iter().map(|x| x.to_string()).collect(), error: None }
+});
+
+from!(item: &kaspa_rpc_core::GetBlockAcceptanceDataRequest, protowire::GetBlockAcceptanceDataRequestMessage, {
+    Self { hash: item.hash.to_string() }
+});
+from!(item: RpcResult<&kaspa_rpc_core::GetBlockAcceptanceDataResponse>, protowire::GetBlockAcceptanceDataResponseMessage, {
+    Self { acceptance_data: item.acceptance_data.iter().map(|x| x.into()).collect(), error: None }
+});
+
+from!(item: &kaspa_rpc_core::IsChainBlockRequest, protowire::IsChainBlockRequestMessage, {
+    Self { hash: item.hash.to_string() }
+});
+from!(item: RpcResult<&kaspa_rpc_core::IsChainBlockResponse>, protowire::IsChainBlockResponseMessage, {
+    Self { is_chain_block: item.is_chain_block, error: None }
+});
+
+from!(item: &kaspa_rpc_core::GetUtxosByOutpointsRequest, protowire::GetUtxosByOutpointsRequestMessage, {
+    Self { outpoints: item.outpoints.iter().map(|x| x.into()).collect() }
+});
+from!(item: RpcResult<&kaspa_rpc_core::GetUtxosByOutpointsResponse>, protowire::GetUtxosByOutpointsResponseMessage, {
+    Self { entries: item.entries.iter().map(|x| x.into()).collect(), error: None }
+});
+
+from!(item: &kaspa_rpc_core::GetDaaScoreTimestampEstimateRequest, protowire::GetDaaScoreTimestampEstimateRequestMessage, {
+    Self { daa_scores: item.daa_scores.clone() }
+});
+from!(item: RpcResult<&kaspa_rpc_core::GetDaaScoreTimestampEstimateResponse>, protowire::GetDaaScoreTimestampEstimateResponseMessage, {
+    Self { timestamps: item.timestamps.clone(), error: None }
+});
+
+from!(&kaspa_rpc_core::GetLogLevelsRequest, protowire::GetLogLevelsRequestMessage);
+from!(item: RpcResult<&kaspa_rpc_core::GetLogLevelsResponse>, protowire::GetLogLevelsResponseMessage, {
+    Self { root_level: item.root_level.clone(), subsystems: item.subsystems.iter().map(|x| x.into()).collect(), error: None }
+});
+
+from!(item: &kaspa_rpc_core::SetLogLevelsRequest, protowire::SetLogLevelsRequestMessage, { Self { filters: item.filters.clone() } });
+from!(item: RpcResult<&kaspa_rpc_core::SetLogLevelsResponse>, protowire::SetLogLevelsResponseMessage, {
+    Self { root_level: item.root_level.clone(), subsystems: item.subsystems.iter().map(|x| x.into()).collect(), error: None }
+});
+
+from!(&kaspa_rpc_core::RotateLogFilesRequest, protowire::RotateLogFilesRequestMessage);
+from!(RpcResult<&kaspa_rpc_core::RotateLogFilesResponse>, protowire::RotateLogFilesResponseMessage);
+
 from!(&kaspa_rpc_core::GetCoinSupplyRequest, protowire::GetCoinSupplyRequestMessage);
 from!(item: RpcResult<&kaspa_rpc_core::GetCoinSupplyResponse>, protowire::GetCoinSupplyResponseMessage, {
     Self { max_sompi: item.max_sompi, circulating_sompi: item.circulating_sompi, error: None }
@@ -349,7 +448,17 @@ from!(item: RpcResult<&kaspa_rpc_core::GetCoinSupplyResponse>, protowire::GetCoi
 from!(&kaspa_rpc_core::PingRequest, protowire::PingRequestMessage);
 from!(RpcResult<&kaspa_rpc_core::PingResponse>, protowire::PingResponseMessage);
 
-from!(&kaspa_rpc_core::GetMetricsRequest, protowire::GetMetricsRequestMessage);
+from!(item: &kaspa_rpc_core::GetMetricsRequest, protowire::GetMetricsRequestMessage, {
+    Self {
+        process_metrics: item.process_metrics,
+        consensus_metrics: item.consensus_metrics,
+        p2p_metrics: item.p2p_metrics,
+        mempool_metrics: item.mempool_metrics,
+        dag_metrics: item.dag_metrics,
+        storage_metrics: item.storage_metrics,
+        utxoindex_metrics: item.utxoindex_metrics,
+    }
+});
 from!(_item: RpcResult<&kaspa_rpc_core::GetMetricsResponse>, protowire::GetMetricsResponseMessage, {
     Self {
         // TODO @tiram
@@ -513,7 +622,9 @@ try_from!(item: &protowire::GetMempoolEntriesResponseMessage, RpcResult<kaspa_rp
     Self { mempool_entries: item.entries.iter().map(kaspa_rpc_core::RpcMempoolEntry::try_from).collect::<Result<Vec<_>, _>>()? }
 });
 
-try_from!(&protowire::GetConnectedPeerInfoRequestMessage, kaspa_rpc_core::GetConnectedPeerInfoRequest);
+try_from!(item: &protowire::GetConnectedPeerInfoRequestMessage, kaspa_rpc_core::GetConnectedPeerInfoRequest, {
+    Self { include_stats: item.include_stats }
+});
 try_from!(item: &protowire::GetConnectedPeerInfoResponseMessage, RpcResult<kaspa_rpc_core::GetConnectedPeerInfoResponse>, {
     Self { peer_info: item.infos.iter().map(kaspa_rpc_core::RpcPeerInfo::try_from).collect::<Result<Vec<_>, _>>()? }
 });
@@ -605,8 +716,10 @@ try_from!(item: &protowire::GetHeadersRequestMessage, kaspa_rpc_core::GetHeaders
     Self { start_hash: RpcHash::from_str(&item.start_hash)?, limit: item.limit, is_ascending: item.is_ascending }
 });
 try_from!(item: &protowire::GetHeadersResponseMessage, RpcResult<kaspa_rpc_core::GetHeadersResponse>, {
-    // TODO
-    Self { headers: vec![] }
+    Self {
+        headers: item.block_headers.iter().map(|x| x.try_into()).collect::<Result<Vec<_>, _>>()?,
+        next_start_hash: if item.next_start_hash.is_empty() { None } else { Some(RpcHash::from_str(&item.next_start_hash)?) },
+    }
 });
 
 try_from!(item: &protowire::GetUtxosByAddressesRequestMessage, kaspa_rpc_core::GetUtxosByAddressesRequest, {
@@ -666,6 +779,133 @@ try_from!(
     { Self { entries: item.entries.iter().map(|x| x.try_into()).collect::<Result<Vec<_>, _>>()? } }
 );
 
+try_from!(item: &protowire::GetTransactionInclusionProofRequestMessage, kaspa_rpc_core::GetTransactionInclusionProofRequest, {
+    Self { block_hash: RpcHash::from_str(&item.block_hash)?, transaction_id: RpcTransactionId::from_str(&item.transaction_id)? }
+});
+try_from!(item: &protowire::GetTransactionInclusionProofResponseMessage, RpcResult<kaspa_rpc_core::GetTransactionInclusionProofResponse>, {
+    Self {
+        transaction_hash: RpcHash::from_str(&item.transaction_hash)?,
+        proof: item
+            .proof
+            .as_ref()
+            .ok_or_else(|| RpcError::MissingRpcFieldError("GetTransactionInclusionProofResponseMessage".to_string(), "proof".to_string()))?
+            .try_into()?,
+    }
+});
+
+try_from!(item: &protowire::GetTransactionAcceptanceProofRequestMessage, kaspa_rpc_core::GetTransactionAcceptanceProofRequest, {
+    Self {
+        accepting_block_hash: RpcHash::from_str(&item.accepting_block_hash)?,
+        transaction_id: RpcTransactionId::from_str(&item.transaction_id)?,
+    }
+});
+try_from!(item: &protowire::GetTransactionAcceptanceProofResponseMessage, RpcResult<kaspa_rpc_core::GetTransactionAcceptanceProofResponse>, {
+    Self {
+        proof: item
+            .proof
+            .as_ref()
+            .ok_or_else(|| RpcError::MissingRpcFieldError("GetTransactionAcceptanceProofResponseMessage".to_string(), "proof".to_string()))?
+            .try_into()?,
+    }
+});
+
+try_from!(item: &protowire::ValidateTransactionRequestMessage, kaspa_rpc_core::ValidateTransactionRequest, {
+    Self {
+        transaction: item
+            .transaction
+            .as_ref()
+            .ok_or_else(|| RpcError::MissingRpcFieldError("ValidateTransactionRequestMessage".to_string(), "transaction".to_string()))?
+            .try_into()?,
+        allow_orphan: item.allow_orphan,
+    }
+});
+try_from!(item: &protowire::ValidateTransactionResponseMessage, RpcResult<kaspa_rpc_core::ValidateTransactionResponse>, {
+    let has_fee = item.is_accepted && !item.is_orphan;
+    Self {
+        transaction_id: RpcTransactionId::from_str(&item.transaction_id)?,
+        is_accepted: item.is_accepted,
+        is_orphan: item.is_orphan,
+        mass: item.mass,
+        fee: has_fee.then_some(item.fee),
+        feerate: has_fee.then_some(item.feerate),
+        rejection_reason: (!item.rejection_reason.is_empty()).then(|| item.rejection_reason.clone()),
+    }
+});
+
+try_from!(item: &protowire::GetBlockGhostdagDataRequestMessage, kaspa_rpc_core::GetBlockGhostdagDataRequest, {
+    Self { hash: RpcHash::from_str(&item.hash)? }
+});
+try_from!(item: &protowire::GetBlockGhostdagDataResponseMessage, RpcResult<kaspa_rpc_core::GetBlockGhostdagDataResponse>, {
+    Self {
+        ghostdag_data: item
+            .ghostdag_data
+            .as_ref()
+            .ok_or_else(|| RpcError::MissingRpcFieldError("GetBlockGhostdagDataResponseMessage".to_string(), "ghostdag_data".to_string()))?
+            .try_into()?,
+    }
+});
+
+try_from!(item: &protowire::GetBlockChildrenRequestMessage, kaspa_rpc_core::GetBlockChildrenRequest, {
+    Self { hash: RpcHash::from_str(&item.hash)? }
+});
+try_from!(item: &protowire::GetBlockChildrenResponseMessage, RpcResult<kaspa_rpc_core::GetBlockChildrenResponse>, {
+    Self { children_hashes: item.children_hashes.iter().map(|x| RpcHash::from_str(x)).collect::<Result<Vec<_>, _>>()? }
+});
+
+try_from!(item: &protowire::GetBlockAnticoneRequestMessage, kaspa_rpc_core::GetBlockAnticoneRequest, {
+    Self { hash: RpcHash::from_str(&item.hash)? }
+});
+try_from!(item: &protowire::GetBlockAnticoneResponseMessage, RpcResult<kaspa_rpc_core::GetBlockAnticoneResponse>, {
+    Self { anticone_hashes: item.anticone_hashes.iter().map(|x| RpcHash::from_str(x)).collect::<Result<Vec<_>, _>>()? }
+});
+
+try_from!(item: &protowire::GetBlockAcceptanceDataRequestMessage, kaspa_rpc_core::GetBlockAcceptanceDataRequest, {
+    Self { hash: RpcHash::from_str(&item.hash)? }
+});
+try_from!(item: &protowire::GetBlockAcceptanceDataResponseMessage, RpcResult<kaspa_rpc_core::GetBlockAcceptanceDataResponse>, {
+    Self { acceptance_data: item.acceptance_data.iter().map(|x| x.try_into()).collect::<Result<Vec<_>, _>>()? }
+});
+
+try_from!(item: &protowire::IsChainBlockRequestMessage, kaspa_rpc_core::IsChainBlockRequest, {
+    Self { hash: RpcHash::from_str(&item.hash)? }
+});
+try_from!(item: &protowire::IsChainBlockResponseMessage, RpcResult<kaspa_rpc_core::IsChainBlockResponse>, {
+    Self { is_chain_block: item.is_chain_block }
+});
+
+try_from!(item: &protowire::GetUtxosByOutpointsRequestMessage, kaspa_rpc_core::GetUtxosByOutpointsRequest, {
+    Self { outpoints: item.outpoints.iter().map(|x| x.try_into()).collect::<Result<Vec<_>, _>>()? }
+});
+try_from!(item: &protowire::GetUtxosByOutpointsResponseMessage, RpcResult<kaspa_rpc_core::GetUtxosByOutpointsResponse>, {
+    Self { entries: item.entries.iter().map(|x| x.try_into()).collect::<Result<Vec<_>, _>>()? }
+});
+
+try_from!(item: &protowire::GetDaaScoreTimestampEstimateRequestMessage, kaspa_rpc_core::GetDaaScoreTimestampEstimateRequest, {
+    Self { daa_scores: item.daa_scores.clone() }
+});
+try_from!(item: &protowire::GetDaaScoreTimestampEstimateResponseMessage, RpcResult<kaspa_rpc_core::GetDaaScoreTimestampEstimateResponse>, {
+    Self { timestamps: item.timestamps.clone() }
+});
+
+try_from!(&protowire::GetLogLevelsRequestMessage, kaspa_rpc_core::GetLogLevelsRequest);
+try_from!(item: &protowire::GetLogLevelsResponseMessage, RpcResult<kaspa_rpc_core::GetLogLevelsResponse>, {
+    Self {
+        root_level: item.root_level.clone(),
+        subsystems: item.subsystems.iter().map(|x| x.try_into()).collect::<Result<Vec<_>, _>>()?,
+    }
+});
+
+try_from!(item: &protowire::SetLogLevelsRequestMessage, kaspa_rpc_core::SetLogLevelsRequest, { Self { filters: item.filters.clone() } });
+try_from!(item: &protowire::SetLogLevelsResponseMessage, RpcResult<kaspa_rpc_core::SetLogLevelsResponse>, {
+    Self {
+        root_level: item.root_level.clone(),
+        subsystems: item.subsystems.iter().map(|x| x.try_into()).collect::<Result<Vec<_>, _>>()?,
+    }
+});
+
+try_from!(&protowire::RotateLogFilesRequestMessage, kaspa_rpc_core::RotateLogFilesRequest);
+try_from!(&protowire::RotateLogFilesResponseMessage, RpcResult<kaspa_rpc_core::RotateLogFilesResponse>);
+
 try_from!(&protowire::GetCoinSupplyRequestMessage, kaspa_rpc_core::GetCoinSupplyRequest);
 try_from!(item: &protowire::GetCoinSupplyResponseMessage, RpcResult<kaspa_rpc_core::GetCoinSupplyResponse>, {
     Self { max_sompi: item.max_sompi, circulating_sompi: item.circulating_sompi }
@@ -674,9 +914,16 @@ try_from!(item: &protowire::GetCoinSupplyResponseMessage, RpcResult<kaspa_rpc_co
 try_from!(&protowire::PingRequestMessage, kaspa_rpc_core::PingRequest);
 try_from!(&protowire::PingResponseMessage, RpcResult<kaspa_rpc_core::PingResponse>);
 
-try_from!(_item: &protowire::GetMetricsRequestMessage, kaspa_rpc_core::GetMetricsRequest, {
-    // TODO @tiram
-    Self { process_metrics: false, consensus_metrics: false }
+try_from!(item: &protowire::GetMetricsRequestMessage, kaspa_rpc_core::GetMetricsRequest, {
+    Self {
+        process_metrics: item.process_metrics,
+        consensus_metrics: item.consensus_metrics,
+        p2p_metrics: item.p2p_metrics,
+        mempool_metrics: item.mempool_metrics,
+        dag_metrics: item.dag_metrics,
+        storage_metrics: item.storage_metrics,
+        utxoindex_metrics: item.utxoindex_metrics,
+    }
 });
 try_from!(item: &protowire::GetMetricsResponseMessage, RpcResult<kaspa_rpc_core::GetMetricsResponse>, {
     Self {
@@ -684,6 +931,11 @@ try_from!(item: &protowire::GetMetricsResponseMessage, RpcResult<kaspa_rpc_core:
         server_time: 0,
         process_metrics: None,
         consensus_metrics: None,
+        p2p_metrics: None,
+        mempool_metrics: None,
+        dag_metrics: None,
+        storage_metrics: None,
+        utxoindex_metrics: None,
     }
 });
 
@@ -1,9 +1,12 @@
 pub mod address;
 pub mod block;
 pub mod error;
+pub mod ghostdag;
 pub mod header;
 pub mod kaspad;
+pub mod logging;
 pub mod mempool;
+pub mod merkle;
 pub mod message;
 pub mod notification;
 pub mod peer;
@@ -19,6 +19,31 @@ from!(item: &kaspa_rpc_core::RpcPeerInfo, protowire::GetConnectedPeerInfoMessage
         advertised_protocol_version: item.advertised_protocol_version,
         time_connected: item.time_connected as i64,
         is_ibd_peer: item.is_ibd_peer,
+        stats: item.stats.as_ref().map(|x| x.into()),
+    }
+});
+
+from!(item: &kaspa_rpc_core::RpcPeerStats, protowire::RpcPeerStats, {
+    Self {
+        messages_sent: item.messages_sent,
+        bytes_sent: item.bytes_sent,
+        messages_received: item.messages_received,
+        bytes_received: item.bytes_received,
+        invalid_messages: item.invalid_messages,
+        relayed_blocks: item.relayed_blocks,
+        relayed_transactions: item.relayed_transactions,
+        ibd_sessions: item.ibd_sessions,
+        message_types: item.message_types.iter().map(|x| x.into()).collect(),
+    }
+});
+
+from!(item: &kaspa_rpc_core::RpcMessageTypeStats, protowire::RpcMessageTypeStats, {
+    Self {
+        message_type: item.message_type.clone(),
+        messages_sent: item.messages_sent,
+        bytes_sent: item.bytes_sent,
+        messages_received: item.messages_received,
+        bytes_received: item.bytes_received,
     }
 });
 
@@ -40,6 +65,31 @@ try_from!(item: &protowire::GetConnectedPeerInfoMessage, kaspa_rpc_core::RpcPeer
         advertised_protocol_version: item.advertised_protocol_version,
         time_connected: item.time_connected as u64,
         is_ibd_peer: item.is_ibd_peer,
+        stats: item.stats.as_ref().map(|x| x.try_into()).transpose()?,
+    }
+});
+
+try_from!(item: &protowire::RpcPeerStats, kaspa_rpc_core::RpcPeerStats, {
+    Self {
+        messages_sent: item.messages_sent,
+        bytes_sent: item.bytes_sent,
+        messages_received: item.messages_received,
+        bytes_received: item.bytes_received,
+        invalid_messages: item.invalid_messages,
+        relayed_blocks: item.relayed_blocks,
+        relayed_transactions: item.relayed_transactions,
+        ibd_sessions: item.ibd_sessions,
+        message_types: item.message_types.iter().map(|x| x.try_into()).collect::<Result<Vec<_>, _>>()?,
+    }
+});
+
+try_from!(item: &protowire::RpcMessageTypeStats, kaspa_rpc_core::RpcMessageTypeStats, {
+    Self {
+        message_type: item.message_type.clone(),
+        messages_sent: item.messages_sent,
+        bytes_sent: item.bytes_sent,
+        messages_received: item.messages_received,
+        bytes_received: item.bytes_received,
     }
 });
 
@@ -81,6 +81,21 @@ from!(item: &kaspa_rpc_core::RpcAcceptedTransactionIds, protowire::RpcAcceptedTr
     }
 });
 
+from!(item: &kaspa_rpc_core::RpcUtxosByOutpointsEntry, protowire::RpcUtxosByOutpointsEntry, {
+    Self { outpoint: Some((&item.outpoint).into()), utxo_entry: item.utxo_entry.as_ref().map(|x| x.into()) }
+});
+
+from!(item: &kaspa_rpc_core::RpcMergesetBlockAcceptanceData, protowire::RpcMergesetBlockAcceptanceData, {
+    Self {
+        block_hash: item.block_hash.to_string(),
+        accepted_transactions: item.accepted_transactions.iter().map(|x| x.into()).collect(),
+    }
+});
+
+from!(item: &kaspa_rpc_core::RpcAcceptedTransactionEntry, protowire::RpcAcceptedTransactionEntry, {
+    Self { transaction_id: item.transaction_id.to_string(), index_within_block: item.index_within_block }
+});
+
 from!(item: &kaspa_rpc_core::RpcUtxosByAddressesEntry, protowire::RpcUtxosByAddressesEntry, {
     Self {
         address: item.address.as_ref().map_or("".to_string(), |x| x.into()),
@@ -187,6 +202,28 @@ try_from!(item: &protowire::RpcAcceptedTransactionIds, kaspa_rpc_core::RpcAccept
     }
 });
 
+try_from!(item: &protowire::RpcUtxosByOutpointsEntry, kaspa_rpc_core::RpcUtxosByOutpointsEntry, {
+    Self {
+        outpoint: item
+            .outpoint
+            .as_ref()
+            .ok_or_else(|| RpcError::MissingRpcFieldError("UtxosByOutpointsEntry".to_string(), "outpoint".to_string()))?
+            .try_into()?,
+        utxo_entry: item.utxo_entry.as_ref().map(|x| x.try_into()).transpose()?,
+    }
+});
+
+try_from!(item: &protowire::RpcMergesetBlockAcceptanceData, kaspa_rpc_core::RpcMergesetBlockAcceptanceData, {
+    Self {
+        block_hash: RpcHash::from_str(&item.block_hash)?,
+        accepted_transactions: item.accepted_transactions.iter().map(|x| x.try_into()).collect::<Result<Vec<_>, _>>()?,
+    }
+});
+
+try_from!(item: &protowire::RpcAcceptedTransactionEntry, kaspa_rpc_core::RpcAcceptedTransactionEntry, {
+    Self { transaction_id: RpcHash::from_str(&item.transaction_id)?, index_within_block: item.index_within_block }
+});
+
 try_from!(item: &protowire::RpcUtxosByAddressesEntry, kaspa_rpc_core::RpcUtxosByAddressesEntry, {
     let address = if item.address.is_empty() { None } else { Some(item.address.as_str().try_into()?) };
     Self {
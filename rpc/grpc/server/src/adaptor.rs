@@ -1,13 +1,14 @@
-use crate::{connection_handler::ConnectionHandler, manager::Manager};
+use crate::{connection_handler::ConnectionHandler, error::GrpcServerResult, manager::Manager};
 use kaspa_notify::notifier::Notifier;
 use kaspa_rpc_core::{api::rpc::DynRpcService, notify::connection::ChannelConnection, Notification, RpcResult};
+use kaspa_rpc_service::limits::RpcLimiter;
 use kaspa_utils::networking::NetAddress;
 use std::{ops::Deref, sync::Arc};
 use tokio::sync::oneshot::Sender as OneshotSender;
 
 pub struct Adaptor {
-    /// If a server was started, it will get cleaned up when this sender is dropped or invoked
-    _server_termination: Option<OneshotSender<()>>,
+    /// If servers were started, each will get cleaned up when its sender is dropped or invoked
+    _server_terminations: Vec<OneshotSender<()>>,
 
     /// An object for handling new connections coming from clients
     connection_handler: Arc<ConnectionHandler>,
@@ -15,35 +16,39 @@ pub struct Adaptor {
     /// An object for managing a list of active connections
     manager: Manager,
 
-    /// The network address of the server
-    serve_address: NetAddress,
+    /// The network addresses the server is listening on
+    serve_addresses: Vec<NetAddress>,
 }
 
 impl Adaptor {
     fn new(
-        server_termination: Option<OneshotSender<()>>,
+        server_terminations: Vec<OneshotSender<()>>,
         connection_handler: Arc<ConnectionHandler>,
         manager: Manager,
-        serve_address: NetAddress,
+        serve_addresses: Vec<NetAddress>,
     ) -> Self {
-        Self { _server_termination: server_termination, connection_handler, manager, serve_address }
+        Self { _server_terminations: server_terminations, connection_handler, manager, serve_addresses }
     }
 
     pub fn server(
-        serve_address: NetAddress,
+        serve_addresses: Vec<NetAddress>,
         core_service: DynRpcService,
         core_notifier: Arc<Notifier<Notification, ChannelConnection>>,
         max_connections: usize,
-    ) -> Arc<Self> {
+        rpc_limiter: Arc<RpcLimiter>,
+    ) -> GrpcServerResult<Arc<Self>> {
         let manager = Manager::new(max_connections);
-        let connection_handler = Arc::new(ConnectionHandler::new(core_service.clone(), core_notifier, manager.clone()));
-        let server_termination = connection_handler.serve(serve_address);
+        let connection_handler = Arc::new(ConnectionHandler::new(core_service.clone(), core_notifier, manager.clone(), rpc_limiter));
+        let server_terminations = serve_addresses
+            .iter()
+            .map(|serve_address| connection_handler.serve(*serve_address))
+            .collect::<GrpcServerResult<Vec<_>>>()?;
         connection_handler.start();
-        Arc::new(Adaptor::new(Some(server_termination), connection_handler, manager, serve_address))
+        Ok(Arc::new(Adaptor::new(server_terminations, connection_handler, manager, serve_addresses)))
     }
 
-    pub fn serve_address(&self) -> NetAddress {
-        self.serve_address
+    pub fn serve_addresses(&self) -> &[NetAddress] {
+        &self.serve_addresses
     }
 
     pub async fn terminate(&self) -> RpcResult<()> {
@@ -15,8 +15,10 @@ use kaspa_notify::{
         VirtualDaaScoreChangedScope,
     },
     subscriber::SubscriptionManager,
+    subscription::Command,
 };
-use kaspa_rpc_core::{api::rpc::DynRpcService, Notification};
+use kaspa_rpc_core::{api::rpc::DynRpcService, Notification, RpcResult};
+use kaspa_rpc_service::limits::{ConnectionLimiter, RequestPermit};
 use once_cell::unsync::Lazy;
 use parking_lot::Mutex;
 use std::{fmt::Display, io::ErrorKind, net::SocketAddr, sync::Arc};
@@ -41,6 +43,9 @@ struct Inner {
     /// The manager of active connections
     manager: Manager,
 
+    /// The limits applying to this client
+    limiter: ConnectionLimiter,
+
     /// Used on connection close to signal the connection receive loop to exit
     shutdown_signal: Mutex<Option<OneshotSender<()>>>,
 }
@@ -62,12 +67,19 @@ impl Connection {
         core_service: DynRpcService,
         manager: Manager,
         notifier: Arc<Notifier<Notification, Connection>>,
+        limiter: ConnectionLimiter,
         mut incoming_stream: Streaming<KaspadRequest>,
         outgoing_route: GrpcSender,
     ) -> Self {
         let (shutdown_sender, mut shutdown_receiver) = oneshot_channel();
         let connection = Self {
-            inner: Arc::new(Inner { net_address, outgoing_route, manager, shutdown_signal: Mutex::new(Some(shutdown_sender)) }),
+            inner: Arc::new(Inner {
+                net_address,
+                outgoing_route,
+                manager,
+                limiter,
+                shutdown_signal: Mutex::new(Some(shutdown_sender)),
+            }),
         };
         let connection_clone = connection.clone();
         let outgoing_route = connection.inner.outgoing_route.clone();
@@ -92,9 +104,18 @@ impl Connection {
                                 true => {
                                     // Initialize the listener id locally to ensure thread safety
                                     let listener_id = *listener_id;
-                                    Self::handle_subscription(request, listener_id, &notifier).await
+                                    Self::handle_subscription(request, listener_id, &notifier, &connection.inner.limiter).await
+                                },
+                                false => {
+                                    // The request is admitted before being processed so that the requests in flight are accounted for
+                                    let admission = connection.inner.limiter.admit_request();
+                                    if connection.inner.limiter.limits_concurrent_requests() {
+                                        // Requests are processed concurrently up to the connection limit, which is enforced by the admission
+                                        Self::spawn_request(connection.clone(), request, core_service.clone(), admission);
+                                        continue;
+                                    }
+                                    Self::handle_request(request, &core_service, admission).await
                                 },
-                                false => Self::handle_request(request, &core_service).await,
                             };
                             match response {
                                 Ok(response) => {
@@ -154,132 +175,217 @@ impl Connection {
         self.inner.net_address
     }
 
-    async fn handle_request(request: KaspadRequest, core_service: &DynRpcService) -> GrpcServerResult<KaspadResponse> {
+    /// Processes a request in a task of its own, sending the response once done
+    fn spawn_request(
+        connection: Connection,
+        request: KaspadRequest,
+        core_service: DynRpcService,
+        admission: RpcResult<RequestPermit>,
+    ) {
+        tokio::spawn(async move {
+            match Self::handle_request(request, &core_service, admission).await {
+                Ok(response) => {
+                    if let Err(e) = connection.inner.outgoing_route.send(Ok(response)).await {
+                        debug!("GRPC: Connection request task - send error {} for client: {}", e, connection);
+                        connection.close();
+                    }
+                }
+                Err(e) => {
+                    debug!("GRPC: Connection request task - request handling error {} for client: {}", e, connection);
+                    connection.close();
+                }
+            }
+        });
+    }
+
+    async fn handle_request(
+        request: KaspadRequest,
+        core_service: &DynRpcService,
+        admission: RpcResult<RequestPermit>,
+    ) -> GrpcServerResult<KaspadResponse> {
+        // A request exceeding the limits is answered with an error response of the matching type.
+        // Otherwise the permit is held until the request is processed.
+        let (admitted, _permit) = match admission {
+            Ok(permit) => (Ok(()), Some(permit)),
+            Err(err) => (Err(err), None),
+        };
         let mut response: KaspadResponse = if let Some(payload) = request.payload {
             match payload {
-                Payload::GetMetricsRequest(ref request) => match request.try_into() {
+                Payload::GetMetricsRequest(ref request) => match admitted.and(request.try_into()) {
                     Ok(request) => core_service.get_metrics_call(request).await.into(),
                     Err(err) => GetMetricsResponseMessage::from(err).into(),
                 },
-                Payload::PingRequest(ref request) => match request.try_into() {
+                Payload::PingRequest(ref request) => match admitted.and(request.try_into()) {
                     Ok(request) => core_service.ping_call(request).await.into(),
                     Err(err) => PingResponseMessage::from(err).into(),
                 },
-                Payload::GetCoinSupplyRequest(ref request) => match request.try_into() {
+                Payload::GetCoinSupplyRequest(ref request) => match admitted.and(request.try_into()) {
                     Ok(request) => core_service.get_coin_supply_call(request).await.into(),
                     Err(err) => GetCoinSupplyResponseMessage::from(err).into(),
                 },
-                Payload::GetMempoolEntriesByAddressesRequest(ref request) => match request.try_into() {
+                Payload::GetTransactionInclusionProofRequest(ref request) => match admitted.and(request.try_into()) {
+                    Ok(request) => core_service.get_transaction_inclusion_proof_call(request).await.into(),
+                    Err(err) => GetTransactionInclusionProofResponseMessage::from(err).into(),
+                },
+                Payload::GetTransactionAcceptanceProofRequest(ref request) => match admitted.and(request.try_into()) {
+                    Ok(request) => core_service.get_transaction_acceptance_proof_call(request).await.into(),
+                    Err(err) => GetTransactionAcceptanceProofResponseMessage::from(err).into(),
+                },
+                Payload::ValidateTransactionRequest(ref request) => match admitted.and(request.try_into()) {
+                    Ok(request) => core_service.validate_transaction_call(request).await.into(),
+                    Err(err) => ValidateTransactionResponseMessage::from(err).into(),
+                },
+                Payload::GetBlockGhostdagDataRequest(ref request) => match admitted.and(request.try_into()) {
+                    Ok(request) => core_service.get_block_ghostdag_data_call(request).await.into(),
+                    Err(err) => GetBlockGhostdagDataResponseMessage::from(err).into(),
+                },
+                Payload::GetBlockChildrenRequest(ref request) => match admitted.and(request.try_into()) {
+                    Ok(request) => core_service.get_block_children_call(request).await.into(),
+                    Err(err) => GetBlockChildrenResponseMessage::from(err).into(),
+                },
+                Payload::GetBlockAnticoneRequest(ref request) => match admitted.and(request.try_into()) {
+                    Ok(request) => core_service.get_block_anticone_call(request).await.into(),
+                    Err(err) => GetBlockAnticoneResponseMessage::from(err).into(),
+                },
+                Payload::GetBlockAcceptanceDataRequest(ref request) => match admitted.and(request.try_into()) {
+                    Ok(request) => core_service.get_block_acceptance_data_call(request).await.into(),
+                    Err(err) => GetBlockAcceptanceDataResponseMessage::from(err).into(),
+                },
+                Payload::IsChainBlockRequest(ref request) => match admitted.and(request.try_into()) {
+                    Ok(request) => core_service.is_chain_block_call(request).await.into(),
+                    Err(err) => IsChainBlockResponseMessage::from(err).into(),
+                },
+                Payload::GetUtxosByOutpointsRequest(ref request) => match admitted.and(request.try_into()) {
+                    Ok(request) => core_service.get_utxos_by_outpoints_call(request).await.into(),
+                    Err(err) => GetUtxosByOutpointsResponseMessage::from(err).into(),
+                },
+                Payload::GetDaaScoreTimestampEstimateRequest(ref request) => match admitted.and(request.try_into()) {
+                    Ok(request) => core_service.get_daa_score_timestamp_estimate_call(request).await.into(),
+                    Err(err) => GetDaaScoreTimestampEstimateResponseMessage::from(err).into(),
+                },
+                Payload::GetLogLevelsRequest(ref request) => match admitted.and(request.try_into()) {
+                    Ok(request) => core_service.get_log_levels_call(request).await.into(),
+                    Err(err) => GetLogLevelsResponseMessage::from(err).into(),
+                },
+                Payload::SetLogLevelsRequest(ref request) => match admitted.and(request.try_into()) {
+                    Ok(request) => core_service.set_log_levels_call(request).await.into(),
+                    Err(err) => SetLogLevelsResponseMessage::from(err).into(),
+                },
+                Payload::RotateLogFilesRequest(ref request) => match admitted.and(request.try_into()) {
+                    Ok(request) => core_service.rotate_log_files_call(request).await.into(),
+                    Err(err) => RotateLogFilesResponseMessage::from(err).into(),
+                },
+                Payload::GetMempoolEntriesByAddressesRequest(ref request) => match admitted.and(request.try_into()) {
                     Ok(request) => core_service.get_mempool_entries_by_addresses_call(request).await.into(),
                     Err(err) => GetMempoolEntriesByAddressesResponseMessage::from(err).into(),
                 },
-                Payload::GetBalancesByAddressesRequest(ref request) => match request.try_into() {
+                Payload::GetBalancesByAddressesRequest(ref request) => match admitted.and(request.try_into()) {
                     Ok(request) => core_service.get_balances_by_addresses_call(request).await.into(),
                     Err(err) => GetBalancesByAddressesResponseMessage::from(err).into(),
                 },
-                Payload::GetBalanceByAddressRequest(ref request) => match request.try_into() {
+                Payload::GetBalanceByAddressRequest(ref request) => match admitted.and(request.try_into()) {
                     Ok(request) => core_service.get_balance_by_address_call(request).await.into(),
                     Err(err) => GetBalanceByAddressResponseMessage::from(err).into(),
                 },
-                Payload::EstimateNetworkHashesPerSecondRequest(ref request) => match request.try_into() {
+                Payload::EstimateNetworkHashesPerSecondRequest(ref request) => match admitted.and(request.try_into()) {
                     Ok(request) => core_service.estimate_network_hashes_per_second_call(request).await.into(),
                     Err(err) => EstimateNetworkHashesPerSecondResponseMessage::from(err).into(),
                 },
-                Payload::UnbanRequest(ref request) => match request.try_into() {
+                Payload::UnbanRequest(ref request) => match admitted.and(request.try_into()) {
                     Ok(request) => core_service.unban_call(request).await.into(),
                     Err(err) => UnbanResponseMessage::from(err).into(),
                 },
-                Payload::BanRequest(ref request) => match request.try_into() {
+                Payload::BanRequest(ref request) => match admitted.and(request.try_into()) {
                     Ok(request) => core_service.ban_call(request).await.into(),
                     Err(err) => BanResponseMessage::from(err).into(),
                 },
-                Payload::GetSinkBlueScoreRequest(ref request) => match request.try_into() {
+                Payload::GetSinkBlueScoreRequest(ref request) => match admitted.and(request.try_into()) {
                     Ok(request) => core_service.get_sink_blue_score_call(request).await.into(),
                     Err(err) => GetSinkBlueScoreResponseMessage::from(err).into(),
                 },
-                Payload::GetUtxosByAddressesRequest(ref request) => match request.try_into() {
+                Payload::GetUtxosByAddressesRequest(ref request) => match admitted.and(request.try_into()) {
                     Ok(request) => core_service.get_utxos_by_addresses_call(request).await.into(),
                     Err(err) => GetUtxosByAddressesResponseMessage::from(err).into(),
                 },
-                Payload::GetHeadersRequest(ref request) => match request.try_into() {
+                Payload::GetHeadersRequest(ref request) => match admitted.and(request.try_into()) {
                     Ok(request) => core_service.get_headers_call(request).await.into(),
-                    Err(err) => ShutdownResponseMessage::from(err).into(),
+                    Err(err) => GetHeadersResponseMessage::from(err).into(),
                 },
-                Payload::ShutdownRequest(ref request) => match request.try_into() {
+                Payload::ShutdownRequest(ref request) => match admitted.and(request.try_into()) {
                     Ok(request) => core_service.shutdown_call(request).await.into(),
                     Err(err) => ShutdownResponseMessage::from(err).into(),
                 },
-                Payload::GetMempoolEntriesRequest(ref request) => match request.try_into() {
+                Payload::GetMempoolEntriesRequest(ref request) => match admitted.and(request.try_into()) {
                     Ok(request) => core_service.get_mempool_entries_call(request).await.into(),
                     Err(err) => GetMempoolEntriesResponseMessage::from(err).into(),
                 },
-                Payload::ResolveFinalityConflictRequest(ref request) => match request.try_into() {
+                Payload::ResolveFinalityConflictRequest(ref request) => match admitted.and(request.try_into()) {
                     Ok(request) => core_service.resolve_finality_conflict_call(request).await.into(),
                     Err(err) => ResolveFinalityConflictResponseMessage::from(err).into(),
                 },
-                Payload::GetBlockDagInfoRequest(ref request) => match request.try_into() {
+                Payload::GetBlockDagInfoRequest(ref request) => match admitted.and(request.try_into()) {
                     Ok(request) => core_service.get_block_dag_info_call(request).await.into(),
                     Err(err) => GetBlockDagInfoResponseMessage::from(err).into(),
                 },
-                Payload::GetBlockCountRequest(ref request) => match request.try_into() {
+                Payload::GetBlockCountRequest(ref request) => match admitted.and(request.try_into()) {
                     Ok(request) => core_service.get_block_count_call(request).await.into(),
                     Err(err) => GetBlockCountResponseMessage::from(err).into(),
                 },
-                Payload::GetBlocksRequest(ref request) => match request.try_into() {
+                Payload::GetBlocksRequest(ref request) => match admitted.and(request.try_into()) {
                     Ok(request) => core_service.get_blocks_call(request).await.into(),
                     Err(err) => GetBlocksResponseMessage::from(err).into(),
                 },
-                Payload::GetVirtualChainFromBlockRequest(ref request) => match request.try_into() {
+                Payload::GetVirtualChainFromBlockRequest(ref request) => match admitted.and(request.try_into()) {
                     Ok(request) => core_service.get_virtual_chain_from_block_call(request).await.into(),
                     Err(err) => GetVirtualChainFromBlockResponseMessage::from(err).into(),
                 },
-                Payload::GetSubnetworkRequest(ref request) => match request.try_into() {
+                Payload::GetSubnetworkRequest(ref request) => match admitted.and(request.try_into()) {
                     Ok(request) => core_service.get_subnetwork_call(request).await.into(),
                     Err(err) => GetSubnetworkResponseMessage::from(err).into(),
                 },
-                Payload::SubmitTransactionRequest(ref request) => match request.try_into() {
+                Payload::SubmitTransactionRequest(ref request) => match admitted.and(request.try_into()) {
                     Ok(request) => core_service.submit_transaction_call(request).await.into(),
                     Err(err) => SubmitTransactionResponseMessage::from(err).into(),
                 },
-                Payload::AddPeerRequest(ref request) => match request.try_into() {
+                Payload::AddPeerRequest(ref request) => match admitted.and(request.try_into()) {
                     Ok(request) => core_service.add_peer_call(request).await.into(),
                     Err(err) => AddPeerResponseMessage::from(err).into(),
                 },
-                Payload::GetConnectedPeerInfoRequest(ref request) => match request.try_into() {
+                Payload::GetConnectedPeerInfoRequest(ref request) => match admitted.and(request.try_into()) {
                     Ok(request) => core_service.get_connected_peer_info_call(request).await.into(),
                     Err(err) => GetConnectedPeerInfoResponseMessage::from(err).into(),
                 },
-                Payload::GetMempoolEntryRequest(ref request) => match request.try_into() {
+                Payload::GetMempoolEntryRequest(ref request) => match admitted.and(request.try_into()) {
                     Ok(request) => core_service.get_mempool_entry_call(request).await.into(),
                     Err(err) => GetMempoolEntryResponseMessage::from(err).into(),
                 },
-                Payload::GetSelectedTipHashRequest(ref request) => match request.try_into() {
+                Payload::GetSelectedTipHashRequest(ref request) => match admitted.and(request.try_into()) {
                     Ok(request) => core_service.get_selected_tip_hash_call(request).await.into(),
                     Err(err) => GetSelectedTipHashResponseMessage::from(err).into(),
                 },
-                Payload::GetPeerAddressesRequest(ref request) => match request.try_into() {
+                Payload::GetPeerAddressesRequest(ref request) => match admitted.and(request.try_into()) {
                     Ok(request) => core_service.get_peer_addresses_call(request).await.into(),
                     Err(err) => GetPeerAddressesResponseMessage::from(err).into(),
                 },
-                Payload::GetCurrentNetworkRequest(ref request) => match request.try_into() {
+                Payload::GetCurrentNetworkRequest(ref request) => match admitted.and(request.try_into()) {
                     Ok(request) => core_service.get_current_network_call(request).await.into(),
                     Err(err) => GetCurrentNetworkResponseMessage::from(err).into(),
                 },
-                Payload::SubmitBlockRequest(ref request) => match request.try_into() {
+                Payload::SubmitBlockRequest(ref request) => match admitted.and(request.try_into()) {
                     Ok(request) => core_service.submit_block_call(request).await.into(),
                     Err(err) => SubmitBlockResponseMessage::from(err).into(),
                 },
-                Payload::GetBlockTemplateRequest(ref request) => match request.try_into() {
+                Payload::GetBlockTemplateRequest(ref request) => match admitted.and(request.try_into()) {
                     Ok(request) => core_service.get_block_template_call(request).await.into(),
                     Err(err) => GetBlockTemplateResponseMessage::from(err).into(),
                 },
 
-                Payload::GetBlockRequest(ref request) => match request.try_into() {
+                Payload::GetBlockRequest(ref request) => match admitted.and(request.try_into()) {
                     Ok(request) => core_service.get_block_call(request).await.into(),
                     Err(err) => GetBlockResponseMessage::from(err).into(),
                 },
 
-                Payload::GetInfoRequest(ref request) => match request.try_into() {
+                Payload::GetInfoRequest(ref request) => match admitted.and(request.try_into()) {
                     Ok(request) => core_service.get_info_call(request).await.into(),
                     Err(err) => GetInfoResponseMessage::from(err).into(),
                 },
@@ -300,16 +406,21 @@ impl Connection {
         request: KaspadRequest,
         listener_id: ListenerId,
         notifier: &Arc<Notifier<Notification, Connection>>,
+        limiter: &ConnectionLimiter,
     ) -> GrpcServerResult<KaspadResponse> {
         let mut response: KaspadResponse = if let Some(payload) = request.payload {
             match payload {
                 Payload::NotifyBlockAddedRequest(ref request) => match kaspa_rpc_core::NotifyBlockAddedRequest::try_from(request) {
                     Ok(request) => {
                         let listener_id = listener_id;
-                        let result = notifier
-                            .clone()
-                            .execute_subscribe_command(listener_id, Scope::BlockAdded(BlockAddedScope::default()), request.command)
-                            .await;
+                        let result = Self::execute_subscribe_command(
+                            notifier,
+                            limiter,
+                            listener_id,
+                            Scope::BlockAdded(BlockAddedScope::default()),
+                            request.command,
+                        )
+                        .await;
                         NotifyBlockAddedResponseMessage::from(result).into()
                     }
                     Err(err) => NotifyBlockAddedResponseMessage::from(err).into(),
@@ -319,16 +430,14 @@ impl Connection {
                     match kaspa_rpc_core::NotifyVirtualChainChangedRequest::try_from(request) {
                         Ok(request) => {
                             let listener_id = listener_id;
-                            let result = notifier
-                                .clone()
-                                .execute_subscribe_command(
-                                    listener_id,
-                                    Scope::VirtualChainChanged(VirtualChainChangedScope::new(
-                                        request.include_accepted_transaction_ids,
-                                    )),
-                                    request.command,
-                                )
-                                .await;
+                            let result = Self::execute_subscribe_command(
+                                notifier,
+                                limiter,
+                                listener_id,
+                                Scope::VirtualChainChanged(VirtualChainChangedScope::new(request.include_accepted_transaction_ids)),
+                                request.command,
+                            )
+                            .await;
                             NotifyVirtualChainChangedResponseMessage::from(result).into()
                         }
                         Err(err) => NotifyVirtualChainChangedResponseMessage::from(err).into(),
@@ -339,24 +448,24 @@ impl Connection {
                     match kaspa_rpc_core::NotifyFinalityConflictRequest::try_from(request) {
                         Ok(request) => {
                             let listener_id = listener_id;
-                            let result = notifier
-                                .clone()
-                                .execute_subscribe_command(
+                            let result = Self::execute_subscribe_command(
+                                notifier,
+                                limiter,
+                                listener_id,
+                                Scope::FinalityConflict(FinalityConflictScope::default()),
+                                request.command,
+                            )
+                            .await
+                            .and(
+                                Self::execute_subscribe_command(
+                                    notifier,
+                                    limiter,
                                     listener_id,
-                                    Scope::FinalityConflict(FinalityConflictScope::default()),
+                                    Scope::FinalityConflictResolved(FinalityConflictResolvedScope::default()),
                                     request.command,
                                 )
-                                .await
-                                .and(
-                                    notifier
-                                        .clone()
-                                        .execute_subscribe_command(
-                                            listener_id,
-                                            Scope::FinalityConflictResolved(FinalityConflictResolvedScope::default()),
-                                            request.command,
-                                        )
-                                        .await,
-                                );
+                                .await,
+                            );
                             NotifyFinalityConflictResponseMessage::from(result).into()
                         }
                         Err(err) => NotifyFinalityConflictResponseMessage::from(err).into(),
@@ -367,14 +476,14 @@ impl Connection {
                     match kaspa_rpc_core::NotifyUtxosChangedRequest::try_from(request) {
                         Ok(request) => {
                             let listener_id = listener_id;
-                            let result = notifier
-                                .clone()
-                                .execute_subscribe_command(
-                                    listener_id,
-                                    Scope::UtxosChanged(UtxosChangedScope::new(request.addresses)),
-                                    request.command,
-                                )
-                                .await;
+                            let result = Self::execute_subscribe_command(
+                                notifier,
+                                limiter,
+                                listener_id,
+                                Scope::UtxosChanged(UtxosChangedScope::new(request.addresses)),
+                                request.command,
+                            )
+                            .await;
                             NotifyUtxosChangedResponseMessage::from(result).into()
                         }
                         Err(err) => NotifyUtxosChangedResponseMessage::from(err).into(),
@@ -385,14 +494,14 @@ impl Connection {
                     match kaspa_rpc_core::NotifySinkBlueScoreChangedRequest::try_from(request) {
                         Ok(request) => {
                             let listener_id = listener_id;
-                            let result = notifier
-                                .clone()
-                                .execute_subscribe_command(
-                                    listener_id,
-                                    Scope::SinkBlueScoreChanged(SinkBlueScoreChangedScope::default()),
-                                    request.command,
-                                )
-                                .await;
+                            let result = Self::execute_subscribe_command(
+                                notifier,
+                                limiter,
+                                listener_id,
+                                Scope::SinkBlueScoreChanged(SinkBlueScoreChangedScope::default()),
+                                request.command,
+                            )
+                            .await;
                             NotifySinkBlueScoreChangedResponseMessage::from(result).into()
                         }
                         Err(err) => NotifySinkBlueScoreChangedResponseMessage::from(err).into(),
@@ -403,14 +512,14 @@ impl Connection {
                     match kaspa_rpc_core::NotifyVirtualDaaScoreChangedRequest::try_from(request) {
                         Ok(request) => {
                             let listener_id = listener_id;
-                            let result = notifier
-                                .clone()
-                                .execute_subscribe_command(
-                                    listener_id,
-                                    Scope::VirtualDaaScoreChanged(VirtualDaaScoreChangedScope::default()),
-                                    request.command,
-                                )
-                                .await;
+                            let result = Self::execute_subscribe_command(
+                                notifier,
+                                limiter,
+                                listener_id,
+                                Scope::VirtualDaaScoreChanged(VirtualDaaScoreChangedScope::default()),
+                                request.command,
+                            )
+                            .await;
                             NotifyVirtualDaaScoreChangedResponseMessage::from(result).into()
                         }
                         Err(err) => NotifyVirtualDaaScoreChangedResponseMessage::from(err).into(),
@@ -421,14 +530,14 @@ impl Connection {
                     match kaspa_rpc_core::NotifyPruningPointUtxoSetOverrideRequest::try_from(request) {
                         Ok(request) => {
                             let listener_id = listener_id;
-                            let result = notifier
-                                .clone()
-                                .execute_subscribe_command(
-                                    listener_id,
-                                    Scope::PruningPointUtxoSetOverride(PruningPointUtxoSetOverrideScope::default()),
-                                    request.command,
-                                )
-                                .await;
+                            let result = Self::execute_subscribe_command(
+                                notifier,
+                                limiter,
+                                listener_id,
+                                Scope::PruningPointUtxoSetOverride(PruningPointUtxoSetOverrideScope::default()),
+                                request.command,
+                            )
+                            .await;
                             NotifyPruningPointUtxoSetOverrideResponseMessage::from(result).into()
                         }
                         Err(err) => NotifyPruningPointUtxoSetOverrideResponseMessage::from(err).into(),
@@ -439,14 +548,14 @@ impl Connection {
                     match kaspa_rpc_core::NotifyNewBlockTemplateRequest::try_from(request) {
                         Ok(request) => {
                             let listener_id = listener_id;
-                            let result = notifier
-                                .clone()
-                                .execute_subscribe_command(
-                                    listener_id,
-                                    Scope::NewBlockTemplate(NewBlockTemplateScope::default()),
-                                    request.command,
-                                )
-                                .await;
+                            let result = Self::execute_subscribe_command(
+                                notifier,
+                                limiter,
+                                listener_id,
+                                Scope::NewBlockTemplate(NewBlockTemplateScope::default()),
+                                request.command,
+                            )
+                            .await;
                             NotifyNewBlockTemplateResponseMessage::from(result).into()
                         }
                         Err(err) => NotifyNewBlockTemplateResponseMessage::from(err).into(),
@@ -459,14 +568,14 @@ impl Connection {
                         match kaspa_rpc_core::NotifyUtxosChangedRequest::try_from(&notify_request) {
                             Ok(request) => {
                                 let listener_id = listener_id;
-                                let result = notifier
-                                    .clone()
-                                    .execute_subscribe_command(
-                                        listener_id,
-                                        Scope::UtxosChanged(UtxosChangedScope::new(request.addresses)),
-                                        request.command,
-                                    )
-                                    .await;
+                                let result = Self::execute_subscribe_command(
+                                    notifier,
+                                    limiter,
+                                    listener_id,
+                                    Scope::UtxosChanged(UtxosChangedScope::new(request.addresses)),
+                                    request.command,
+                                )
+                                .await;
                                 NotifyUtxosChangedResponseMessage::from(result).into()
                             }
                             Err(err) => NotifyUtxosChangedResponseMessage::from(err).into(),
@@ -480,14 +589,14 @@ impl Connection {
                         match kaspa_rpc_core::NotifyPruningPointUtxoSetOverrideRequest::try_from(&notify_request) {
                             Ok(request) => {
                                 let listener_id = listener_id;
-                                let result = notifier
-                                    .clone()
-                                    .execute_subscribe_command(
-                                        listener_id,
-                                        Scope::PruningPointUtxoSetOverride(PruningPointUtxoSetOverrideScope::default()),
-                                        request.command,
-                                    )
-                                    .await;
+                                let result = Self::execute_subscribe_command(
+                                    notifier,
+                                    limiter,
+                                    listener_id,
+                                    Scope::PruningPointUtxoSetOverride(PruningPointUtxoSetOverrideScope::default()),
+                                    request.command,
+                                )
+                                .await;
                                 NotifyPruningPointUtxoSetOverrideResponseMessage::from(result).into()
                             }
                             Err(err) => NotifyPruningPointUtxoSetOverrideResponseMessage::from(err).into(),
@@ -509,6 +618,21 @@ impl Connection {
 
         Ok(response)
     }
+
+    async fn execute_subscribe_command(
+        notifier: &Arc<Notifier<Notification, Connection>>,
+        limiter: &ConnectionLimiter,
+        listener_id: ListenerId,
+        scope: Scope,
+        command: Command,
+    ) -> Result<(), NotificationError> {
+        let change = limiter.admit_subscription(&scope, command).map_err(|err| NotificationError::General(err.to_string()))?;
+        let result = notifier.clone().execute_subscribe_command(listener_id, scope, command).await;
+        if result.is_err() {
+            limiter.revert_subscription(change);
+        }
+        result
+    }
 }
 
 fn match_for_io_error(err_status: &tonic::Status) -> Option<&std::io::Error> {
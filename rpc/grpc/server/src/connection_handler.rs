@@ -1,6 +1,7 @@
 use crate::{
     collector::{GrpcServiceCollector, GrpcServiceConverter},
     connection::Connection,
+    error::GrpcServerResult,
     manager::Manager,
 };
 use futures::{FutureExt, Stream};
@@ -18,6 +19,7 @@ use kaspa_rpc_core::{
     notify::{channel::NotificationChannel, connection::ChannelConnection},
     Notification, RpcResult,
 };
+use kaspa_rpc_service::limits::RpcLimiter;
 use kaspa_utils::networking::NetAddress;
 use std::{
     pin::Pin,
@@ -26,9 +28,10 @@ use std::{
         Arc,
     },
 };
+use tokio::net::TcpListener;
 use tokio::sync::mpsc::channel as mpsc_channel;
 use tokio::sync::oneshot::{channel as oneshot_channel, Sender as OneshotSender};
-use tokio_stream::wrappers::ReceiverStream;
+use tokio_stream::wrappers::{ReceiverStream, TcpListenerStream};
 use tonic::{codec::CompressionEncoding, transport::Server as TonicServer, Request, Response};
 
 /// A protowire gRPC connections handler.
@@ -36,13 +39,19 @@ pub struct ConnectionHandler {
     core_service: DynRpcService,
     manager: Manager,
     notifier: Arc<Notifier<Notification, Connection>>,
+    rpc_limiter: Arc<RpcLimiter>,
     running: AtomicBool,
 }
 
 const GRPC_SERVER: &str = "grpc-server";
 
 impl ConnectionHandler {
-    pub fn new(core_service: DynRpcService, core_notifier: Arc<Notifier<Notification, ChannelConnection>>, manager: Manager) -> Self {
+    pub fn new(
+        core_service: DynRpcService,
+        core_notifier: Arc<Notifier<Notification, ChannelConnection>>,
+        manager: Manager,
+        rpc_limiter: Arc<RpcLimiter>,
+    ) -> Self {
         // Prepare core objects
         let core_channel = NotificationChannel::default();
         let core_listener_id =
@@ -56,11 +65,12 @@ impl ConnectionHandler {
         let notifier: Arc<Notifier<Notification, Connection>> =
             Arc::new(Notifier::new(GRPC_SERVER, core_events, vec![collector], vec![subscriber], 10));
 
-        Self { core_service, manager, notifier, running: AtomicBool::new(false) }
+        Self { core_service, manager, notifier, rpc_limiter, running: AtomicBool::new(false) }
     }
 
-    /// Launches a gRPC server listener loop
-    pub(crate) fn serve(self: &Arc<Self>, serve_address: NetAddress) -> OneshotSender<()> {
+    /// Binds a gRPC server listener and launches its loop
+    pub(crate) fn serve(self: &Arc<Self>, serve_address: NetAddress) -> GrpcServerResult<OneshotSender<()>> {
+        let listener = TcpListener::from_std(serve_address.bind_tcp_listener()?)?;
         let (termination_sender, termination_receiver) = oneshot_channel::<()>();
         let connection_handler = self.clone();
         info!("GRPC Server starting on: {}", serve_address);
@@ -73,7 +83,7 @@ impl ConnectionHandler {
             // TODO: check whether we should set tcp_keepalive
             let serve_result = TonicServer::builder()
                 .add_service(protowire_server)
-                .serve_with_shutdown(serve_address.into(), termination_receiver.map(drop))
+                .serve_with_incoming_shutdown(TcpListenerStream::new(listener), termination_receiver.map(drop))
                 .await;
 
             match serve_result {
@@ -81,7 +91,7 @@ impl ConnectionHandler {
                 Err(err) => panic!("GRPC Server {serve_address} stopped with error: {err:?}"),
             }
         });
-        termination_sender
+        Ok(termination_sender)
     }
 
     #[inline(always)]
@@ -143,6 +153,11 @@ impl Rpc for ConnectionHandler {
             ));
         }
 
+        let limiter = self
+            .rpc_limiter
+            .register_connection(remote_address)
+            .map_err(|err| tonic::Status::new(tonic::Code::ResourceExhausted, err.to_string()))?;
+
         debug!("GRPC: incoming message stream from {:?}", remote_address);
 
         // Build the in/out pipes
@@ -155,6 +170,7 @@ impl Rpc for ConnectionHandler {
             self.core_service.clone(),
             self.manager.clone(),
             self.notifier(),
+            limiter,
             incoming_stream,
             outgoing_route,
         );
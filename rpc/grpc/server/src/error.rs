@@ -14,6 +14,9 @@ pub enum GrpcServerError {
 
     #[error("Subscription has no valid payload")]
     InvalidSubscriptionPayload,
+
+    #[error("{0}")]
+    IoError(#[from] std::io::Error),
 }
 
 impl From<GrpcServerError> for kaspa_rpc_core::error::RpcError {
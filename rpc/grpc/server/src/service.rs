@@ -1,6 +1,6 @@
 use crate::adaptor::Adaptor;
 use kaspa_core::{
-    task::service::{AsyncService, AsyncServiceFuture},
+    task::service::{AsyncService, AsyncServiceError, AsyncServiceFuture},
     trace, warn,
 };
 use kaspa_rpc_service::service::RpcCoreService;
@@ -10,15 +10,15 @@ use std::sync::Arc;
 const GRPC_SERVICE: &str = "grpc-service";
 
 pub struct GrpcService {
-    net_address: NetAddress,
+    net_addresses: Vec<NetAddress>,
     core_service: Arc<RpcCoreService>,
     rpc_max_clients: usize,
     shutdown: SingleTrigger,
 }
 
 impl GrpcService {
-    pub fn new(address: NetAddress, core_service: Arc<RpcCoreService>, rpc_max_clients: usize) -> Self {
-        Self { net_address: address, core_service, rpc_max_clients, shutdown: SingleTrigger::default() }
+    pub fn new(addresses: Vec<NetAddress>, core_service: Arc<RpcCoreService>, rpc_max_clients: usize) -> Self {
+        Self { net_addresses: addresses, core_service, rpc_max_clients, shutdown: SingleTrigger::default() }
     }
 }
 
@@ -33,8 +33,18 @@ impl AsyncService for GrpcService {
         // Prepare a shutdown signal receiver
         let shutdown_signal = self.shutdown.listener.clone();
 
-        let grpc_adaptor =
-            Adaptor::server(self.net_address, self.core_service.clone(), self.core_service.notifier(), self.rpc_max_clients);
+        let grpc_adaptor = match Adaptor::server(
+            self.net_addresses.clone(),
+            self.core_service.clone(),
+            self.core_service.notifier(),
+            self.rpc_max_clients,
+            self.core_service.rpc_limiter(),
+        ) {
+            Ok(adaptor) => adaptor,
+            Err(err) => {
+                return Box::pin(async move { Err(AsyncServiceError::Service(format!("{GRPC_SERVICE} failed to listen: {err}"))) })
+            }
+        };
 
         // Launch the service and wait for a shutdown signal
         Box::pin(async move {
@@ -1,8 +1,10 @@
 use super::rpc_core_mock::RpcCoreMock;
 use crate::adaptor::Adaptor;
+use futures::future::join_all;
 use kaspa_core::info;
 use kaspa_grpc_client::GrpcClient;
-use kaspa_rpc_core::notify::mode::NotificationMode;
+use kaspa_rpc_core::{api::rpc::RpcApi, notify::mode::NotificationMode, RpcError, RpcHash};
+use kaspa_rpc_service::limits::{RpcLimiter, RpcLimits};
 use kaspa_utils::networking::{ContextualNetAddress, NetAddress};
 use std::sync::Arc;
 
@@ -15,14 +17,14 @@ async fn test_client_server_connections() {
     core_service.start();
 
     // Create and start the server
-    let server = create_server(core_service.clone());
+    let server = create_server(core_service.clone(), RpcLimiter::unlimited());
     assert!(!server.has_connections(), "server should have no client when just started");
 
     info!("=================================================================================");
     info!("2 clients connecting and disconnecting themselves");
 
-    let client1 = create_client(server.serve_address()).await;
-    let client2 = create_client(server.serve_address()).await;
+    let client1 = create_client(server.serve_addresses()[0]).await;
+    let client2 = create_client(server.serve_addresses()[0]).await;
 
     assert_eq!(server.active_connections().len(), 2, "one or more clients failed to connect to the server");
 
@@ -35,8 +37,8 @@ async fn test_client_server_connections() {
     info!("=================================================================================");
     info!("2 clients connecting and server disconnecting them");
 
-    let client1 = create_client(server.serve_address()).await;
-    let client2 = create_client(server.serve_address()).await;
+    let client1 = create_client(server.serve_addresses()[0]).await;
+    let client2 = create_client(server.serve_addresses()[0]).await;
 
     assert_eq!(server.active_connections().len(), 2, "one or more clients failed to connect to the server");
 
@@ -50,8 +52,8 @@ async fn test_client_server_connections() {
     info!("=================================================================================");
     info!("2 clients connecting, 1 disconnecting itself, server shutting down");
 
-    let client1 = create_client(server.serve_address()).await;
-    let client2 = create_client(server.serve_address()).await;
+    let client1 = create_client(server.serve_addresses()[0]).await;
+    let client2 = create_client(server.serve_addresses()[0]).await;
 
     assert_eq!(server.active_connections().len(), 2, "one or more clients failed to connect to the server");
 
@@ -75,8 +77,54 @@ async fn test_client_server_connections() {
     tokio::time::sleep(std::time::Duration::from_millis(100)).await;
 }
 
-fn create_server(core_service: Arc<RpcCoreMock>) -> Arc<Adaptor> {
-    Adaptor::server(get_free_net_address(), core_service.clone(), core_service.core_notifier(), 128)
+#[tokio::test]
+async fn test_concurrent_requests_limit() {
+    kaspa_core::log::try_init_logger("info, kaspa_grpc_core=trace, kaspa_grpc_server=trace, kaspa_grpc_client=trace");
+
+    let core_service = Arc::new(RpcCoreMock::new());
+    core_service.start();
+    let server = create_server(core_service.clone(), RpcLimiter::new(RpcLimits { max_concurrent_requests: 2, ..Default::default() }));
+    let client = create_client(server.serve_addresses()[0]).await;
+
+    // The requests of a connection are processed concurrently up to the limit, the exceeding ones being rejected
+    let results = join_all((0..3).map(|_| client.ping())).await;
+    assert_eq!(results.iter().filter(|result| result.is_ok()).count(), 2);
+    assert_eq!(results.iter().filter(|result| result.is_err()).count(), 1);
+
+    // The slots are released once the requests are processed
+    assert!(join_all((0..2).map(|_| client.ping())).await.iter().all(|result| result.is_ok()));
+
+    assert!(client.disconnect().await.is_ok(), "client failed to disconnect");
+    core_service.join().await;
+    assert!(server.terminate().await.is_ok(), "error stopping the server");
+}
+
+#[tokio::test]
+async fn test_rejected_get_headers() {
+    kaspa_core::log::try_init_logger("info, kaspa_grpc_core=trace, kaspa_grpc_server=trace, kaspa_grpc_client=trace");
+
+    let core_service = Arc::new(RpcCoreMock::new());
+    core_service.start();
+    let server = create_server(core_service.clone(), RpcLimiter::new(RpcLimits { max_requests_per_second: 2, ..Default::default() }));
+    let client = create_client(server.serve_addresses()[0]).await;
+
+    // Requests exceeding the rate limit are rejected with a response matching the request
+    let errors = join_all((0..8).map(|_| client.get_headers(RpcHash::from_u64_word(1), 10, true)))
+        .await
+        .into_iter()
+        .map(|result| result.unwrap_err().to_string())
+        .collect::<Vec<_>>();
+    let rate_limited = RpcError::RequestRateLimitExceeded(2).to_string();
+    assert!(errors.iter().any(|err| err.contains(&rate_limited)), "{errors:?}");
+    assert!(errors.iter().all(|err| err.contains(&rate_limited) || err.contains(&RpcError::NotImplemented.to_string())), "{errors:?}");
+
+    assert!(client.disconnect().await.is_ok(), "client failed to disconnect");
+    core_service.join().await;
+    assert!(server.terminate().await.is_ok(), "error stopping the server");
+}
+
+fn create_server(core_service: Arc<RpcCoreMock>, limiter: RpcLimiter) -> Arc<Adaptor> {
+    Adaptor::server(vec![get_free_net_address()], core_service.clone(), core_service.core_notifier(), 128, Arc::new(limiter)).unwrap()
 }
 
 async fn create_client(server_address: NetAddress) -> GrpcClient {
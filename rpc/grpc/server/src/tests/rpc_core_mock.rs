@@ -52,8 +52,10 @@ impl RpcApi for RpcCoreMock {
         })
     }
 
+    // Pings take some time to process so that concurrent requests can be issued
     async fn ping_call(&self, _request: PingRequest) -> RpcResult<PingResponse> {
-        Err(RpcError::NotImplemented)
+        tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+        Ok(PingResponse {})
     }
 
     async fn get_metrics_call(&self, _request: GetMetricsRequest) -> RpcResult<GetMetricsResponse> {
@@ -195,6 +197,70 @@ impl RpcApi for RpcCoreMock {
         Err(RpcError::NotImplemented)
     }
 
+    async fn get_transaction_inclusion_proof_call(
+        &self,
+        _request: GetTransactionInclusionProofRequest,
+    ) -> RpcResult<GetTransactionInclusionProofResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_transaction_acceptance_proof_call(
+        &self,
+        _request: GetTransactionAcceptanceProofRequest,
+    ) -> RpcResult<GetTransactionAcceptanceProofResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn validate_transaction_call(&self, _request: ValidateTransactionRequest) -> RpcResult<ValidateTransactionResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_block_ghostdag_data_call(&self, _request: GetBlockGhostdagDataRequest) -> RpcResult<GetBlockGhostdagDataResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_block_children_call(&self, _request: GetBlockChildrenRequest) -> RpcResult<GetBlockChildrenResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_block_anticone_call(&self, _request: GetBlockAnticoneRequest) -> RpcResult<GetBlockAnticoneResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_block_acceptance_data_call(
+        &self,
+        _request: GetBlockAcceptanceDataRequest,
+    ) -> RpcResult<GetBlockAcceptanceDataResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn is_chain_block_call(&self, _request: IsChainBlockRequest) -> RpcResult<IsChainBlockResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_utxos_by_outpoints_call(&self, _request: GetUtxosByOutpointsRequest) -> RpcResult<GetUtxosByOutpointsResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_daa_score_timestamp_estimate_call(
+        &self,
+        _request: GetDaaScoreTimestampEstimateRequest,
+    ) -> RpcResult<GetDaaScoreTimestampEstimateResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_log_levels_call(&self, _request: GetLogLevelsRequest) -> RpcResult<GetLogLevelsResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn set_log_levels_call(&self, _request: SetLogLevelsRequest) -> RpcResult<SetLogLevelsResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn rotate_log_files_call(&self, _request: RotateLogFilesRequest) -> RpcResult<RotateLogFilesResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
     // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
     // Notification API
 
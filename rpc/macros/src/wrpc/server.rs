@@ -53,6 +53,8 @@ impl ToTokens for RpcTable {
                     interface.method(#rpc_api_ops::#handler, method!(|server_ctx: #server_ctx_type, connection_ctx: #connection_ctx_type, request: #request_type| async move {
                         let verbose = server_ctx.verbose();
                         if verbose { workflow_log::log_info!("request: {:?}",request); }
+                        // The permit is held until the request is processed
+                        let _permit = connection_ctx.admit_request().map_err(|e|ServerError::Text(e.to_string()))?;
                         let response: #response_type = server_ctx.rpc_service(&connection_ctx).#fn_call(request).await
                             .map_err(|e|ServerError::Text(e.to_string()))?;
                         if verbose { workflow_log::log_info!("response: {:?}",response); }
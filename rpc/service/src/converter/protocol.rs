@@ -1,8 +1,8 @@
-use std::sync::Arc;
+use std::{collections::BTreeMap, sync::Arc};
 
 use kaspa_p2p_flows::flow_context::FlowContext;
-use kaspa_p2p_lib::{Peer, PeerKey};
-use kaspa_rpc_core::RpcPeerInfo;
+use kaspa_p2p_lib::{Peer, PeerKey, PeerStats};
+use kaspa_rpc_core::{P2pMetrics, RpcMessageTypeStats, RpcPeerInfo, RpcPeerStats};
 
 pub struct ProtocolConverter {
     flow_context: Arc<FlowContext>,
@@ -13,7 +13,7 @@ impl ProtocolConverter {
         Self { flow_context }
     }
 
-    fn get_peer_info(&self, peer: &Peer, ibd_peer_key: &Option<PeerKey>) -> RpcPeerInfo {
+    fn get_peer_info(&self, peer: &Peer, ibd_peer_key: &Option<PeerKey>, include_stats: bool) -> RpcPeerInfo {
         let properties = peer.properties();
         RpcPeerInfo {
             id: peer.identity(),
@@ -25,11 +25,51 @@ impl ProtocolConverter {
             user_agent: properties.user_agent.clone(),
             advertised_protocol_version: properties.advertised_protocol_version,
             time_connected: peer.time_connected(),
+            stats: peer.stats().filter(|_| include_stats).map(|stats| self.get_peer_stats(stats)),
         }
     }
 
-    pub fn get_peers_info(&self, peers: &[Peer]) -> Vec<RpcPeerInfo> {
+    pub fn get_peers_info(&self, peers: &[Peer], include_stats: bool) -> Vec<RpcPeerInfo> {
         let ibd_peer_key = self.flow_context.ibd_peer_key();
-        peers.iter().map(|x| self.get_peer_info(x, &ibd_peer_key)).collect()
+        peers.iter().map(|x| self.get_peer_info(x, &ibd_peer_key, include_stats)).collect()
+    }
+
+    pub fn get_peer_stats(&self, stats: &PeerStats) -> RpcPeerStats {
+        let total_sent = stats.total_sent();
+        let total_received = stats.total_received();
+        let mut message_types = BTreeMap::<String, RpcMessageTypeStats>::new();
+        for (msg_type, counters) in stats.sent.iter() {
+            let entry = message_types.entry(format!("{:?}", msg_type)).or_default();
+            entry.messages_sent = counters.messages;
+            entry.bytes_sent = counters.bytes;
+        }
+        for (msg_type, counters) in stats.received.iter() {
+            let entry = message_types.entry(format!("{:?}", msg_type)).or_default();
+            entry.messages_received = counters.messages;
+            entry.bytes_received = counters.bytes;
+        }
+        let message_types =
+            message_types.into_iter().map(|(message_type, entry)| RpcMessageTypeStats { message_type, ..entry }).collect();
+        RpcPeerStats {
+            messages_sent: total_sent.messages,
+            bytes_sent: total_sent.bytes,
+            messages_received: total_received.messages,
+            bytes_received: total_received.bytes,
+            invalid_messages: stats.invalid_messages,
+            relayed_blocks: stats.relayed_blocks,
+            relayed_transactions: stats.relayed_transactions,
+            ibd_sessions: stats.ibd_sessions,
+            message_types,
+        }
+    }
+
+    /// Builds the P2P metrics of the active `peers`, with statistics accumulated on top of `closed_peers_stats`
+    pub fn get_p2p_metrics(&self, peers: &[Peer], closed_peers_stats: PeerStats) -> P2pMetrics {
+        let outbound_peers = peers.iter().filter(|x| x.is_outbound()).count() as u64;
+        let stats = peers.iter().filter_map(|peer| peer.stats()).fold(closed_peers_stats, |mut stats, peer_stats| {
+            stats.merge(peer_stats);
+            stats
+        });
+        P2pMetrics { inbound_peers: peers.len() as u64 - outbound_peers, outbound_peers, peer_stats: self.get_peer_stats(&stats) }
     }
 }
@@ -1,3 +1,4 @@
 pub mod collector;
 pub mod converter;
+pub mod limits;
 pub mod service;
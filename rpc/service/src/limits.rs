@@ -0,0 +1,418 @@
+use kaspa_addresses::Address;
+use kaspa_consensus_core::config::Config;
+use kaspa_notify::{events::EventType, scope::Scope, subscription::Command};
+use kaspa_rpc_core::{RpcError, RpcResult};
+use parking_lot::Mutex;
+use std::{
+    collections::{HashMap, HashSet},
+    net::{IpAddr, SocketAddr},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::Instant,
+};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+/// Limits applied to RPC clients. A value of zero disables the matching limit.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RpcLimits {
+    /// Maximum number of simultaneous connections from a single IP address
+    pub max_connections_per_ip: usize,
+
+    /// Maximum number of requests per second issued by a single connection
+    pub max_requests_per_second: u32,
+
+    /// Maximum number of requests of a single connection processed concurrently.
+    /// When disabled, gRPC connections process their requests one at a time.
+    pub max_concurrent_requests: usize,
+
+    /// Maximum number of notification subscriptions held by a single connection,
+    /// each address of a UTXO changed subscription counting as one subscription
+    pub max_subscriptions: usize,
+}
+
+impl From<&Config> for RpcLimits {
+    fn from(config: &Config) -> Self {
+        Self {
+            max_connections_per_ip: config.rpc_max_connections_per_ip,
+            max_requests_per_second: config.rpc_max_requests_per_second,
+            max_concurrent_requests: config.rpc_max_concurrent_requests,
+            max_subscriptions: config.rpc_max_subscriptions,
+        }
+    }
+}
+
+/// Counts the violations of the [`RpcLimits`]
+#[derive(Debug, Default)]
+pub struct RpcLimitCounters {
+    pub rejected_connections: AtomicU64,
+    pub rate_limited_requests: AtomicU64,
+    pub concurrency_limited_requests: AtomicU64,
+    pub rejected_subscriptions: AtomicU64,
+}
+
+/// Enforces the [`RpcLimits`] over all the connections of the RPC servers
+#[derive(Debug)]
+pub struct RpcLimiter {
+    limits: RpcLimits,
+    connections_per_ip: Mutex<HashMap<IpAddr, usize>>,
+    counters: RpcLimitCounters,
+}
+
+impl RpcLimiter {
+    pub fn new(limits: RpcLimits) -> Self {
+        Self { limits, connections_per_ip: Default::default(), counters: Default::default() }
+    }
+
+    /// A limiter enforcing no limit at all
+    pub fn unlimited() -> Self {
+        Self::new(RpcLimits::default())
+    }
+
+    pub fn limits(&self) -> RpcLimits {
+        self.limits
+    }
+
+    pub fn counters(&self) -> &RpcLimitCounters {
+        &self.counters
+    }
+
+    /// Registers a new connection from `address`, failing if its IP already reached the maximum number of connections.
+    ///
+    /// The connection slot is released when the returned [`ConnectionLimiter`] is dropped.
+    pub fn register_connection(self: &Arc<Self>, address: SocketAddr) -> RpcResult<ConnectionLimiter> {
+        let ip = address.ip();
+        if self.limits.max_connections_per_ip > 0 {
+            let mut connections_per_ip = self.connections_per_ip.lock();
+            let connections = connections_per_ip.entry(ip).or_default();
+            if *connections >= self.limits.max_connections_per_ip {
+                self.counters.rejected_connections.fetch_add(1, Ordering::Relaxed);
+                return Err(RpcError::ConnectionLimitPerIpReached(self.limits.max_connections_per_ip));
+            }
+            *connections += 1;
+        }
+        Ok(ConnectionLimiter {
+            limiter: self.clone(),
+            ip,
+            rate: Mutex::new(TokenBucket::new(self.limits.max_requests_per_second)),
+            concurrent_requests: (self.limits.max_concurrent_requests > 0)
+                .then(|| Arc::new(Semaphore::new(self.limits.max_concurrent_requests))),
+            subscriptions: Default::default(),
+        })
+    }
+
+    fn unregister_connection(&self, ip: IpAddr) {
+        if self.limits.max_connections_per_ip > 0 {
+            let mut connections_per_ip = self.connections_per_ip.lock();
+            if let Some(connections) = connections_per_ip.get_mut(&ip) {
+                *connections -= 1;
+                if *connections == 0 {
+                    connections_per_ip.remove(&ip);
+                }
+            }
+        }
+    }
+}
+
+/// A permit to process a request, releasing its concurrency slot when dropped
+#[derive(Debug)]
+pub struct RequestPermit {
+    _permit: Option<OwnedSemaphorePermit>,
+}
+
+/// Enforces the [`RpcLimits`] applying to a single connection
+#[derive(Debug)]
+pub struct ConnectionLimiter {
+    limiter: Arc<RpcLimiter>,
+    ip: IpAddr,
+    rate: Mutex<TokenBucket>,
+    concurrent_requests: Option<Arc<Semaphore>>,
+    subscriptions: Mutex<Subscriptions>,
+}
+
+impl ConnectionLimiter {
+    /// Admits a request of this connection, checking both the request rate of the connection and the
+    /// number of its requests being processed concurrently.
+    ///
+    /// The returned permit must be held until the request is fully processed.
+    pub fn admit_request(&self) -> RpcResult<RequestPermit> {
+        if !self.rate.lock().try_take() {
+            self.limiter.counters.rate_limited_requests.fetch_add(1, Ordering::Relaxed);
+            return Err(RpcError::RequestRateLimitExceeded(self.limiter.limits.max_requests_per_second));
+        }
+        let permit = match self.concurrent_requests.as_ref() {
+            Some(semaphore) => match semaphore.clone().try_acquire_owned() {
+                Ok(permit) => Some(permit),
+                Err(_) => {
+                    self.limiter.counters.concurrency_limited_requests.fetch_add(1, Ordering::Relaxed);
+                    return Err(RpcError::ConcurrentRequestLimitReached(self.limiter.limits.max_concurrent_requests));
+                }
+            },
+            None => None,
+        };
+        Ok(RequestPermit { _permit: permit })
+    }
+
+    /// Indicates whether the number of requests of this connection processed concurrently is limited
+    pub fn limits_concurrent_requests(&self) -> bool {
+        self.concurrent_requests.is_some()
+    }
+
+    /// Admits a subscribe or unsubscribe command of this connection and records the resulting subscriptions.
+    /// Unsubscribing is always admitted.
+    ///
+    /// The returned change must be passed to [`Self::revert_subscription`] if the command eventually fails.
+    pub fn admit_subscription(&self, scope: &Scope, command: Command) -> RpcResult<SubscriptionChange> {
+        let mut subscriptions = self.subscriptions.lock();
+        let delta = match command {
+            Command::Start => {
+                let max_subscriptions = self.limiter.limits.max_subscriptions;
+                if max_subscriptions > 0 && subscriptions.len() + subscriptions.added_by(scope) > max_subscriptions {
+                    self.limiter.counters.rejected_subscriptions.fetch_add(1, Ordering::Relaxed);
+                    return Err(RpcError::SubscriptionLimitReached(max_subscriptions));
+                }
+                subscriptions.start(scope)
+            }
+            Command::Stop => subscriptions.stop(scope),
+        };
+        Ok(SubscriptionChange { command, delta })
+    }
+
+    /// Rolls back the subscriptions recorded by an admitted command which failed
+    pub fn revert_subscription(&self, change: SubscriptionChange) {
+        let mut subscriptions = self.subscriptions.lock();
+        match change.command {
+            Command::Start => subscriptions.remove(change.delta),
+            Command::Stop => subscriptions.insert(change.delta),
+        }
+    }
+}
+
+impl Drop for ConnectionLimiter {
+    fn drop(&mut self) {
+        self.limiter.unregister_connection(self.ip);
+    }
+}
+
+/// A token bucket holding up to one second worth of requests
+#[derive(Debug)]
+struct TokenBucket {
+    rate: u32,
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    fn new(rate: u32) -> Self {
+        Self { rate, tokens: rate as f64, last_refill: Instant::now() }
+    }
+
+    fn try_take(&mut self) -> bool {
+        if self.rate == 0 {
+            return true;
+        }
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last_refill).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.rate as f64).min(self.rate as f64);
+        self.last_refill = now;
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// The subscriptions actually added or removed by an admitted subscription command
+#[derive(Debug)]
+pub struct SubscriptionChange {
+    command: Command,
+    delta: Subscriptions,
+}
+
+/// The notification subscriptions of a connection
+#[derive(Debug, Default)]
+struct Subscriptions {
+    events: HashSet<EventType>,
+    utxo_addresses: HashSet<Address>,
+}
+
+impl Subscriptions {
+    fn len(&self) -> usize {
+        self.events.len() + self.utxo_addresses.len()
+    }
+
+    /// Number of subscriptions a start command for `scope` would add
+    fn added_by(&self, scope: &Scope) -> usize {
+        match scope {
+            Scope::UtxosChanged(scope) if !scope.addresses.is_empty() => {
+                scope.addresses.iter().filter(|address| !self.utxo_addresses.contains(address)).count()
+            }
+            scope => (!self.events.contains(&scope.into())) as usize,
+        }
+    }
+
+    /// Starts the subscriptions of `scope`, returning the ones which were not already started
+    fn start(&mut self, scope: &Scope) -> Subscriptions {
+        let mut added = Subscriptions::default();
+        match scope {
+            Scope::UtxosChanged(scope) if !scope.addresses.is_empty() => {
+                for address in scope.addresses.iter() {
+                    if self.utxo_addresses.insert(address.clone()) {
+                        added.utxo_addresses.insert(address.clone());
+                    }
+                }
+            }
+            scope => {
+                let event = scope.into();
+                if self.events.insert(event) {
+                    added.events.insert(event);
+                }
+            }
+        }
+        added
+    }
+
+    /// Stops the subscriptions of `scope`, returning the ones which were actually started
+    fn stop(&mut self, scope: &Scope) -> Subscriptions {
+        let mut removed = Subscriptions::default();
+        match scope {
+            Scope::UtxosChanged(scope) if !scope.addresses.is_empty() => {
+                for address in scope.addresses.iter() {
+                    if self.utxo_addresses.remove(address) {
+                        removed.utxo_addresses.insert(address.clone());
+                    }
+                }
+            }
+            Scope::UtxosChanged(_) => {
+                removed.utxo_addresses = std::mem::take(&mut self.utxo_addresses);
+                if self.events.remove(&EventType::UtxosChanged) {
+                    removed.events.insert(EventType::UtxosChanged);
+                }
+            }
+            scope => {
+                let event = scope.into();
+                if self.events.remove(&event) {
+                    removed.events.insert(event);
+                }
+            }
+        }
+        removed
+    }
+
+    fn insert(&mut self, other: Subscriptions) {
+        self.events.extend(other.events);
+        self.utxo_addresses.extend(other.utxo_addresses);
+    }
+
+    fn remove(&mut self, other: Subscriptions) {
+        other.events.iter().for_each(|event| {
+            self.events.remove(event);
+        });
+        other.utxo_addresses.iter().for_each(|address| {
+            self.utxo_addresses.remove(address);
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kaspa_addresses::{Prefix, Version};
+    use kaspa_notify::scope::{BlockAddedScope, UtxosChangedScope};
+
+    fn address(i: u8) -> Address {
+        Address::new(Prefix::Mainnet, Version::PubKey, &[i; 32])
+    }
+
+    #[test]
+    fn test_connections_per_ip() {
+        let limiter = Arc::new(RpcLimiter::new(RpcLimits { max_connections_per_ip: 2, ..Default::default() }));
+        let first = limiter.register_connection("10.0.0.1:1000".parse().unwrap()).unwrap();
+        let _second = limiter.register_connection("10.0.0.1:1001".parse().unwrap()).unwrap();
+        assert!(limiter.register_connection("10.0.0.1:1002".parse().unwrap()).is_err());
+        assert!(limiter.register_connection("10.0.0.2:1000".parse().unwrap()).is_ok());
+
+        // Dropping a connection releases its slot
+        drop(first);
+        assert!(limiter.register_connection("10.0.0.1:1003".parse().unwrap()).is_ok());
+        assert_eq!(limiter.counters().rejected_connections.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn test_request_limits() {
+        let limiter =
+            Arc::new(RpcLimiter::new(RpcLimits { max_requests_per_second: 3, max_concurrent_requests: 2, ..Default::default() }));
+        let connection = limiter.register_connection("10.0.0.1:1000".parse().unwrap()).unwrap();
+
+        let first = connection.admit_request().unwrap();
+        let _second = connection.admit_request().unwrap();
+        assert!(matches!(connection.admit_request(), Err(RpcError::ConcurrentRequestLimitReached(2))));
+
+        // The rate limit applies once the concurrency slots are released
+        drop(first);
+        assert!(matches!(connection.admit_request(), Err(RpcError::RequestRateLimitExceeded(3))));
+
+        let counters = limiter.counters();
+        assert_eq!(counters.concurrency_limited_requests.load(Ordering::Relaxed), 1);
+        assert_eq!(counters.rate_limited_requests.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn test_concurrent_requests_per_connection() {
+        let limiter = Arc::new(RpcLimiter::new(RpcLimits { max_concurrent_requests: 2, ..Default::default() }));
+        let busy = limiter.register_connection("10.0.0.1:1000".parse().unwrap()).unwrap();
+        let other = limiter.register_connection("10.0.0.2:1000".parse().unwrap()).unwrap();
+
+        let _permits = (0..2).map(|_| busy.admit_request().unwrap()).collect::<Vec<_>>();
+        assert!(matches!(busy.admit_request(), Err(RpcError::ConcurrentRequestLimitReached(2))));
+
+        // A connection reaching its cap does not block the other ones
+        let _other_permits = (0..2).map(|_| other.admit_request().unwrap()).collect::<Vec<_>>();
+        assert!(other.admit_request().is_err());
+        assert_eq!(limiter.counters().concurrency_limited_requests.load(Ordering::Relaxed), 2);
+    }
+
+    #[test]
+    fn test_subscription_limits() {
+        let limiter = Arc::new(RpcLimiter::new(RpcLimits { max_subscriptions: 3, ..Default::default() }));
+        let connection = limiter.register_connection("10.0.0.1:1000".parse().unwrap()).unwrap();
+
+        let block_added = Scope::BlockAdded(BlockAddedScope::default());
+        let utxos_changed = |addresses: Vec<Address>| Scope::UtxosChanged(UtxosChangedScope::new(addresses));
+
+        connection.admit_subscription(&block_added, Command::Start).unwrap();
+        // Subscribing twice to the same scope does not count twice
+        connection.admit_subscription(&block_added, Command::Start).unwrap();
+        connection.admit_subscription(&utxos_changed(vec![address(1), address(2)]), Command::Start).unwrap();
+        assert!(connection.admit_subscription(&utxos_changed(vec![address(3)]), Command::Start).is_err());
+
+        // Unsubscribing frees some room
+        connection.admit_subscription(&utxos_changed(vec![address(1)]), Command::Stop).unwrap();
+        connection.admit_subscription(&utxos_changed(vec![address(3)]), Command::Start).unwrap();
+        assert_eq!(limiter.counters().rejected_subscriptions.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn test_subscription_revert() {
+        let limiter = Arc::new(RpcLimiter::new(RpcLimits { max_subscriptions: 2, ..Default::default() }));
+        let connection = limiter.register_connection("10.0.0.1:1000".parse().unwrap()).unwrap();
+
+        let utxos_changed = |addresses: Vec<Address>| Scope::UtxosChanged(UtxosChangedScope::new(addresses));
+
+        connection.admit_subscription(&utxos_changed(vec![address(1)]), Command::Start).unwrap();
+        // A failed subscription releases its slots but keeps the previously started ones
+        let change = connection.admit_subscription(&utxos_changed(vec![address(1), address(2)]), Command::Start).unwrap();
+        connection.revert_subscription(change);
+        connection.admit_subscription(&utxos_changed(vec![address(3)]), Command::Start).unwrap();
+        assert!(connection.admit_subscription(&utxos_changed(vec![address(4)]), Command::Start).is_err());
+
+        // A failed unsubscription restores the stopped subscriptions
+        let change = connection.admit_subscription(&utxos_changed(vec![]), Command::Stop).unwrap();
+        connection.revert_subscription(change);
+        assert!(connection.admit_subscription(&utxos_changed(vec![address(4)]), Command::Start).is_err());
+        assert!(connection.admit_subscription(&utxos_changed(vec![address(1)]), Command::Start).is_ok());
+    }
+}
@@ -2,6 +2,7 @@
 
 use super::collector::{CollectorFromConsensus, CollectorFromIndex};
 use crate::converter::{consensus::ConsensusConverter, index::IndexConverter, protocol::ProtocolConverter};
+use crate::limits::{RpcLimiter, RpcLimits};
 use crate::service::NetworkType::{Mainnet, Testnet};
 use async_trait::async_trait;
 use kaspa_consensus::pipeline::ProcessingCounters;
@@ -10,6 +11,8 @@ use kaspa_consensus_core::{
     coinbase::MinerData,
     config::Config,
     constants::MAX_SOMPI,
+    hashing,
+    merkle::{create_accepted_id_merkle_proof, create_hash_merkle_proof},
     network::NetworkType,
     tx::{Transaction, COINBASE_TRANSACTION_INDEX},
 };
@@ -20,13 +23,15 @@ use kaspa_consensus_notify::{
 use kaspa_consensusmanager::ConsensusManager;
 use kaspa_core::{
     core::Core,
-    debug,
+    debug, info,
     kaspad_env::version,
     signals::Shutdown,
     task::service::{AsyncService, AsyncServiceError, AsyncServiceFuture},
     task::tick::TickService,
+    time::unix_now,
     trace, warn,
 };
+use kaspa_database::registry::DatabaseStorePrefixes;
 use kaspa_index_core::{
     connection::IndexChannelConnection, indexed_utxos::UtxoSetByScriptPublicKey, notification::Notification as IndexNotification,
     notifier::IndexNotifier,
@@ -45,8 +50,9 @@ use kaspa_p2p_flows::flow_context::FlowContext;
 use kaspa_perf_monitor::{counters::CountersSnapshot, Monitor as PerfMonitor};
 use kaspa_rpc_core::{
     api::{
+        headers::MAX_GET_HEADERS_LIMIT,
         ops::RPC_API_VERSION,
-        rpc::{RpcApi, MAX_SAFE_WINDOW_SIZE},
+        rpc::{RpcApi, MAX_DAA_SCORE_TIMESTAMP_ESTIMATES, MAX_SAFE_ANTICONE_TRAVERSAL, MAX_SAFE_WINDOW_SIZE, MAX_UTXOS_BY_OUTPOINTS},
     },
     model::*,
     notify::connection::ChannelConnection,
@@ -94,6 +100,7 @@ pub struct RpcCoreService {
     processing_counters: Arc<ProcessingCounters>,
     wrpc_borsh_counters: Arc<WrpcServerCounters>,
     wrpc_json_counters: Arc<WrpcServerCounters>,
+    rpc_limiter: Arc<RpcLimiter>,
     shutdown: SingleTrigger,
     perf_monitor: Arc<PerfMonitor<Arc<TickService>>>,
 }
@@ -161,6 +168,9 @@ impl RpcCoreService {
         // Create the rcp-core notifier
         let notifier = Arc::new(Notifier::new(RPC_CORE, EVENT_TYPE_ARRAY[..].into(), collectors, subscribers, 1));
 
+        // Limits shared by all the RPC servers
+        let rpc_limiter = Arc::new(RpcLimiter::new(RpcLimits::from(config.as_ref())));
+
         Self {
             consensus_manager,
             notifier,
@@ -175,6 +185,7 @@ impl RpcCoreService {
             processing_counters,
             wrpc_borsh_counters,
             wrpc_json_counters,
+            rpc_limiter,
             shutdown: SingleTrigger::default(),
             perf_monitor,
         }
@@ -195,6 +206,11 @@ impl RpcCoreService {
         self.notifier.clone()
     }
 
+    #[inline(always)]
+    pub fn rpc_limiter(&self) -> Arc<RpcLimiter> {
+        self.rpc_limiter.clone()
+    }
+
     async fn get_utxo_set_by_script_public_key<'a>(
         &self,
         addresses: impl Iterator<Item = &'a RpcAddress>,
@@ -211,6 +227,105 @@ impl RpcCoreService {
         // Other network types can be used in an isolated environment without peers
         !matches!(self.flow_context.config.net.network_type, Mainnet | Testnet) || self.flow_context.hub().has_peers()
     }
+
+    async fn get_mempool_metrics(&self) -> MempoolMetrics {
+        let stats = self.mining_manager.clone().mempool_stats().await;
+        MempoolMetrics {
+            transaction_count: stats.transaction_count,
+            orphan_count: stats.orphan_count,
+            total_mass: stats.total_mass,
+            total_fees: stats.total_fees,
+            fee_rate_histogram: stats
+                .fee_rate_histogram
+                .into_iter()
+                .map(|bucket| RpcFeeRateBucket {
+                    min_fee_rate: bucket.min_fee_rate,
+                    transaction_count: bucket.transaction_count,
+                    total_mass: bucket.total_mass,
+                })
+                .collect(),
+        }
+    }
+
+    async fn get_dag_metrics(&self) -> RpcResult<DagMetrics> {
+        let consensus = self.consensus_manager.consensus();
+        let queue_depths = consensus.pipeline_queue_depths();
+        let session = consensus.session().await;
+        let tip_count = session.async_get_tips().await.len() as u64;
+        let virtual_daa_score = session.async_get_virtual_daa_score().await;
+        let pruning_point = session.async_get_header(session.async_pruning_point().await).await?;
+
+        let counters = self.processing_counters.snapshot();
+        let average = |micros: u64, count: u64| micros.checked_div(count).unwrap_or_default();
+
+        Ok(DagMetrics {
+            tip_count,
+            virtual_daa_score,
+            pruning_point_daa_score: pruning_point.daa_score,
+            pruning_point_daa_age: virtual_daa_score.saturating_sub(pruning_point.daa_score),
+            pruning_point_time_age: unix_now().saturating_sub(pruning_point.timestamp),
+            header_queue_depth: queue_depths.header_processor as u64,
+            body_queue_depth: queue_depths.body_processor as u64,
+            virtual_queue_depth: queue_depths.virtual_processor as u64,
+            pruning_queue_depth: queue_depths.pruning_processor as u64,
+            header_processing_avg_micros: average(counters.header_processing_micros, counters.header_counts),
+            body_processing_avg_micros: average(counters.body_processing_micros, counters.body_counts),
+            virtual_processing_avg_micros: average(counters.virtual_processing_micros, counters.virtual_resolve_counts),
+        })
+    }
+
+    async fn get_storage_metrics(&self) -> RpcResult<StorageMetrics> {
+        let session = self.consensus_manager.consensus().session().await;
+        let mut stores = session
+            .async_get_store_stats()
+            .await?
+            .into_iter()
+            .map(|s| RpcStoreMetrics {
+                prefix: s.prefix,
+                name: s.name,
+                size: s.size,
+                entries: s.entries,
+                cache_hits: s.cache_hits,
+                cache_misses: s.cache_misses,
+            })
+            .collect::<Vec<_>>();
+        if let Some(utxoindex) = self.utxoindex.clone() {
+            let utxoindex_stats = utxoindex.get_store_stats().await.map_err(|e| RpcError::General(e.to_string()))?;
+            stores.extend(utxoindex_stats.into_iter().map(|s| RpcStoreMetrics {
+                prefix: s.prefix,
+                name: s.name,
+                size: s.size,
+                entries: s.entries,
+                cache_hits: s.cache_hits,
+                cache_misses: s.cache_misses,
+            }));
+        }
+        Ok(StorageMetrics { stores })
+    }
+
+    async fn get_utxoindex_metrics(&self) -> RpcResult<Option<UtxoIndexMetrics>> {
+        let Some(utxoindex) = self.utxoindex.clone() else {
+            return Ok(None);
+        };
+        let circulating_supply = utxoindex.clone().get_circulating_supply().await.map_err(|e| RpcError::General(e.to_string()))?;
+        let entries = utxoindex
+            .get_store_stats()
+            .await
+            .map_err(|e| RpcError::General(e.to_string()))?
+            .into_iter()
+            .find(|s| s.prefix == DatabaseStorePrefixes::UtxoIndex as u8)
+            .map_or(0, |s| s.entries);
+        Ok(Some(UtxoIndexMetrics { entries, circulating_supply }))
+    }
+
+    fn extract_log_levels(log_levels: kaspa_core::log::LogLevels) -> (String, Vec<RpcSubsystemLogLevel>) {
+        let subsystems = log_levels
+            .subsystems
+            .into_iter()
+            .map(|(subsystem, level)| RpcSubsystemLogLevel::new(subsystem, level.to_string().to_lowercase()))
+            .collect();
+        (log_levels.root.to_string().to_lowercase(), subsystems)
+    }
 }
 
 #[async_trait]
@@ -332,7 +447,7 @@ impl RpcApi for RpcCoreService {
         // If the high hash is equal to sink it means get_hashes_between didn't skip any hashes, and
         // there's space to add the sink anticone, otherwise we cannot add the anticone because
         // there's no guarantee that all of the anticone root ancestors will be present.
-        let sink_anticone = if high_hash == sink_hash { session.async_get_anticone(sink_hash).await? } else { vec![] };
+        let sink_anticone = if high_hash == sink_hash { session.async_get_anticone(sink_hash, None).await? } else { vec![] };
         // Prepend low hash to make it inclusive and append the sink anticone
         let block_hashes = once(low_hash).chain(block_hashes).chain(sink_anticone).collect::<Vec<_>>();
         let blocks = if request.include_blocks {
@@ -419,6 +534,9 @@ impl RpcApi for RpcCoreService {
     }
 
     async fn submit_transaction_call(&self, request: SubmitTransactionRequest) -> RpcResult<SubmitTransactionResponse> {
+        if self.config.disable_rpc_submit {
+            return Err(RpcError::SubmitTransactionDisabled);
+        }
         if !self.config.unsafe_rpc && request.allow_orphan {
             warn!("SubmitTransaction RPC command called with AllowOrphan enabled while node in safe RPC mode -- ignoring.");
             return Err(RpcError::UnavailableInSafeMode);
@@ -439,6 +557,31 @@ impl RpcApi for RpcCoreService {
         Ok(SubmitTransactionResponse::new(transaction_id))
     }
 
+    async fn validate_transaction_call(&self, request: ValidateTransactionRequest) -> RpcResult<ValidateTransactionResponse> {
+        if !self.config.unsafe_rpc && request.allow_orphan {
+            warn!("ValidateTransaction RPC command called with AllowOrphan enabled while node in safe RPC mode -- ignoring.");
+            return Err(RpcError::UnavailableInSafeMode);
+        }
+
+        let transaction: Transaction = (&request.transaction).try_into()?;
+        let transaction_id = transaction.id();
+        let session = self.consensus_manager.consensus().session().await;
+        let orphan = match request.allow_orphan {
+            true => Orphan::Allowed,
+            false => Orphan::Forbidden,
+        };
+        let validation = self.mining_manager.clone().validate_transaction(&session, transaction, orphan).await;
+        Ok(ValidateTransactionResponse::new(
+            transaction_id,
+            validation.is_valid(),
+            validation.is_orphan,
+            validation.mass,
+            validation.fee,
+            validation.fee_rate(),
+            validation.rejection.map(|err| err.to_string()),
+        ))
+    }
+
     async fn get_current_network_call(&self, _: GetCurrentNetworkRequest) -> RpcResult<GetCurrentNetworkResponse> {
         Ok(GetCurrentNetworkResponse::new(*self.config.net))
     }
@@ -522,12 +665,98 @@ impl RpcApi for RpcCoreService {
         Ok(GetCoinSupplyResponse::new(MAX_SOMPI, circulating_sompi))
     }
 
+    async fn get_transaction_inclusion_proof_call(
+        &self,
+        request: GetTransactionInclusionProofRequest,
+    ) -> RpcResult<GetTransactionInclusionProofResponse> {
+        let session = self.consensus_manager.consensus().session().await;
+        let block = session.async_get_block(request.block_hash).await?;
+        let proof = create_hash_merkle_proof(&block.transactions, request.transaction_id)
+            .ok_or(RpcError::TransactionNotInBlock(request.transaction_id, request.block_hash))?;
+        let transaction_hash = hashing::tx::hash(&block.transactions[proof.index as usize]);
+        Ok(GetTransactionInclusionProofResponse::new(transaction_hash, proof))
+    }
+
+    async fn get_transaction_acceptance_proof_call(
+        &self,
+        request: GetTransactionAcceptanceProofRequest,
+    ) -> RpcResult<GetTransactionAcceptanceProofResponse> {
+        let session = self.consensus_manager.consensus().session().await;
+        let accepted_tx_ids = session.async_get_accepted_transaction_ids(request.accepting_block_hash).await?;
+        let proof = create_accepted_id_merkle_proof(&accepted_tx_ids, request.transaction_id)
+            .ok_or(RpcError::TransactionNotAcceptedByBlock(request.transaction_id, request.accepting_block_hash))?;
+        Ok(GetTransactionAcceptanceProofResponse::new(proof))
+    }
+
+    async fn get_block_ghostdag_data_call(&self, request: GetBlockGhostdagDataRequest) -> RpcResult<GetBlockGhostdagDataResponse> {
+        let session = self.consensus_manager.consensus().session().await;
+        Ok(GetBlockGhostdagDataResponse::new(session.async_get_ghostdag_data(request.hash).await?.into()))
+    }
+
+    async fn get_block_children_call(&self, request: GetBlockChildrenRequest) -> RpcResult<GetBlockChildrenResponse> {
+        let session = self.consensus_manager.consensus().session().await;
+        let children = session.async_get_block_children(request.hash).await.ok_or(RpcError::BlockNotFound(request.hash))?;
+        Ok(GetBlockChildrenResponse::new(children.as_ref().clone()))
+    }
+
+    async fn get_block_anticone_call(&self, request: GetBlockAnticoneRequest) -> RpcResult<GetBlockAnticoneResponse> {
+        // The anticone of a deep block requires traversing its whole future, hence the traversal is bounded in safe mode
+        let max_traversal_allowed = (!self.config.unsafe_rpc).then_some(MAX_SAFE_ANTICONE_TRAVERSAL);
+        let session = self.consensus_manager.consensus().session().await;
+        Ok(GetBlockAnticoneResponse::new(session.async_get_anticone(request.hash, max_traversal_allowed).await?))
+    }
+
+    async fn get_block_acceptance_data_call(
+        &self,
+        request: GetBlockAcceptanceDataRequest,
+    ) -> RpcResult<GetBlockAcceptanceDataResponse> {
+        let session = self.consensus_manager.consensus().session().await;
+        let acceptance_data = session.async_get_block_acceptance_data(request.hash).await?;
+        Ok(GetBlockAcceptanceDataResponse::new(acceptance_data.iter().map(RpcMergesetBlockAcceptanceData::from).collect()))
+    }
+
+    async fn is_chain_block_call(&self, request: IsChainBlockRequest) -> RpcResult<IsChainBlockResponse> {
+        let session = self.consensus_manager.consensus().session().await;
+        Ok(IsChainBlockResponse::new(session.async_is_chain_block(request.hash).await?))
+    }
+
+    async fn get_utxos_by_outpoints_call(&self, request: GetUtxosByOutpointsRequest) -> RpcResult<GetUtxosByOutpointsResponse> {
+        if request.outpoints.len() > MAX_UTXOS_BY_OUTPOINTS {
+            return Err(RpcError::RequestSizeExceedingMaximum(request.outpoints.len(), MAX_UTXOS_BY_OUTPOINTS));
+        }
+        let session = self.consensus_manager.consensus().session().await;
+        let utxo_entries = session.async_get_virtual_utxos_by_outpoints(request.outpoints.clone()).await;
+        let entries = request
+            .outpoints
+            .into_iter()
+            .zip(utxo_entries)
+            .map(|(outpoint, utxo_entry)| RpcUtxosByOutpointsEntry { outpoint, utxo_entry })
+            .collect();
+        Ok(GetUtxosByOutpointsResponse::new(entries))
+    }
+
+    async fn get_daa_score_timestamp_estimate_call(
+        &self,
+        request: GetDaaScoreTimestampEstimateRequest,
+    ) -> RpcResult<GetDaaScoreTimestampEstimateResponse> {
+        if request.daa_scores.len() > MAX_DAA_SCORE_TIMESTAMP_ESTIMATES {
+            return Err(RpcError::RequestSizeExceedingMaximum(request.daa_scores.len(), MAX_DAA_SCORE_TIMESTAMP_ESTIMATES));
+        }
+        let session = self.consensus_manager.consensus().session().await;
+        Ok(GetDaaScoreTimestampEstimateResponse::new(session.async_get_daa_score_timestamp_estimates(request.daa_scores).await))
+    }
+
     async fn ping_call(&self, _: PingRequest) -> RpcResult<PingResponse> {
         Ok(PingResponse {})
     }
 
-    async fn get_headers_call(&self, _request: GetHeadersRequest) -> RpcResult<GetHeadersResponse> {
-        Err(RpcError::NotImplemented)
+    async fn get_headers_call(&self, request: GetHeadersRequest) -> RpcResult<GetHeadersResponse> {
+        let session = self.consensus_manager.consensus().session().await;
+        let limit = request.limit.min(MAX_GET_HEADERS_LIMIT) as usize;
+        // Fetch one extra header to tell whether the response is truncated and where the next one starts
+        let mut headers = session.async_get_headers(request.start_hash, limit + 1, request.is_ascending).await?;
+        let next_start_hash = if headers.len() > limit { headers.drain(limit..).next().map(|header| header.hash) } else { None };
+        Ok(GetHeadersResponse::new(headers.iter().map(|header| (**header).clone()).collect(), next_start_hash))
     }
 
     async fn get_block_dag_info_call(&self, _: GetBlockDagInfoRequest) -> RpcResult<GetBlockDagInfoResponse> {
@@ -616,9 +845,10 @@ impl RpcApi for RpcCoreService {
         Ok(UnbanResponse {})
     }
 
-    async fn get_connected_peer_info_call(&self, _: GetConnectedPeerInfoRequest) -> RpcResult<GetConnectedPeerInfoResponse> {
-        let peers = self.flow_context.hub().active_peers();
-        let peer_info = self.protocol_converter.get_peers_info(&peers);
+    async fn get_connected_peer_info_call(&self, request: GetConnectedPeerInfoRequest) -> RpcResult<GetConnectedPeerInfoResponse> {
+        let hub = self.flow_context.hub();
+        let peers = if request.include_stats { hub.active_peers_with_stats() } else { hub.active_peers() };
+        let peer_info = self.protocol_converter.get_peers_info(&peers, request.include_stats);
         Ok(GetConnectedPeerInfoResponse::new(peer_info))
     }
 
@@ -641,13 +871,46 @@ impl RpcApi for RpcCoreService {
 
     async fn resolve_finality_conflict_call(
         &self,
-        _request: ResolveFinalityConflictRequest,
+        request: ResolveFinalityConflictRequest,
     ) -> RpcResult<ResolveFinalityConflictResponse> {
         if !self.config.unsafe_rpc {
             warn!("ResolveFinalityConflict RPC command called while node in safe RPC mode -- ignoring.");
             return Err(RpcError::UnavailableInSafeMode);
         }
-        Err(RpcError::NotImplemented)
+        let session = self.consensus_manager.consensus().session().await;
+        session.async_resolve_finality_conflict(request.finality_block_hash).await?;
+        Ok(ResolveFinalityConflictResponse {})
+    }
+
+    async fn get_log_levels_call(&self, _: GetLogLevelsRequest) -> RpcResult<GetLogLevelsResponse> {
+        if !self.config.unsafe_rpc {
+            warn!("GetLogLevels RPC command called while node in safe RPC mode -- ignoring.");
+            return Err(RpcError::UnavailableInSafeMode);
+        }
+        let log_levels = kaspa_core::log::get_log_levels().map_err(|err| RpcError::General(err.to_string()))?;
+        let (root_level, subsystems) = Self::extract_log_levels(log_levels);
+        Ok(GetLogLevelsResponse::new(root_level, subsystems))
+    }
+
+    async fn set_log_levels_call(&self, request: SetLogLevelsRequest) -> RpcResult<SetLogLevelsResponse> {
+        if !self.config.unsafe_rpc {
+            warn!("SetLogLevels RPC command called while node in safe RPC mode -- ignoring.");
+            return Err(RpcError::UnavailableInSafeMode);
+        }
+        let log_levels = kaspa_core::log::set_log_levels(&request.filters).map_err(|err| RpcError::General(err.to_string()))?;
+        info!("Log levels updated by RPC command with filters '{}'", request.filters);
+        let (root_level, subsystems) = Self::extract_log_levels(log_levels);
+        Ok(SetLogLevelsResponse::new(root_level, subsystems))
+    }
+
+    async fn rotate_log_files_call(&self, _: RotateLogFilesRequest) -> RpcResult<RotateLogFilesResponse> {
+        if !self.config.unsafe_rpc {
+            warn!("RotateLogFiles RPC command called while node in safe RPC mode -- ignoring.");
+            return Err(RpcError::UnavailableInSafeMode);
+        }
+        kaspa_core::log::rotate_log_files().map_err(|err| RpcError::General(err.to_string()))?;
+        info!("Log files rotated by RPC command");
+        Ok(RotateLogFilesResponse {})
     }
 
     // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
@@ -682,6 +945,10 @@ impl RpcApi for RpcCoreService {
             json_live_connections: self.wrpc_json_counters.live_connections.load(Ordering::Relaxed),
             json_connection_attempts: self.wrpc_json_counters.connection_attempts.load(Ordering::Relaxed),
             json_handshake_failures: self.wrpc_json_counters.handshake_failures.load(Ordering::Relaxed),
+            rpc_rejected_connections: self.rpc_limiter.counters().rejected_connections.load(Ordering::Relaxed),
+            rpc_rate_limited_requests: self.rpc_limiter.counters().rate_limited_requests.load(Ordering::Relaxed),
+            rpc_concurrency_limited_requests: self.rpc_limiter.counters().concurrency_limited_requests.load(Ordering::Relaxed),
+            rpc_rejected_subscriptions: self.rpc_limiter.counters().rejected_subscriptions.load(Ordering::Relaxed),
         });
 
         let consensus_metrics = req.consensus_metrics.then_some(ConsensusMetrics {
@@ -694,11 +961,29 @@ impl RpcApi for RpcCoreService {
             mass_counts: self.processing_counters.mass_counts.load(Ordering::SeqCst),
         });
 
+        let p2p_metrics = req.p2p_metrics.then(|| {
+            let hub = self.flow_context.hub();
+            self.protocol_converter.get_p2p_metrics(&hub.active_peers_with_stats(), hub.closed_peers_stats())
+        });
+        let mempool_metrics = if req.mempool_metrics { Some(self.get_mempool_metrics().await) } else { None };
+        let dag_metrics = if req.dag_metrics { Some(self.get_dag_metrics().await?) } else { None };
+        let storage_metrics = if req.storage_metrics { Some(self.get_storage_metrics().await?) } else { None };
+        let utxoindex_metrics = if req.utxoindex_metrics { self.get_utxoindex_metrics().await? } else { None };
+
         let start = SystemTime::now();
         let since_the_epoch = start.duration_since(UNIX_EPOCH).unwrap();
         let server_time = since_the_epoch.as_millis();
 
-        let response = GetMetricsResponse { server_time, process_metrics, consensus_metrics };
+        let response = GetMetricsResponse {
+            server_time,
+            process_metrics,
+            consensus_metrics,
+            p2p_metrics,
+            mempool_metrics,
+            dag_metrics,
+            storage_metrics,
+            utxoindex_metrics,
+        };
 
         Ok(response)
     }
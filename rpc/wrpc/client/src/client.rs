@@ -350,6 +350,8 @@ impl RpcApi for KaspaRpcClient {
             GetSelectedTipHash,
             GetSyncStatus,
             GetSubnetwork,
+            GetTransactionAcceptanceProof,
+            GetTransactionInclusionProof,
             GetUtxosByAddresses,
             GetSinkBlueScore,
             GetVirtualChainFromBlock,
@@ -359,6 +361,17 @@ impl RpcApi for KaspaRpcClient {
             SubmitBlock,
             SubmitTransaction,
             Unban,
+            ValidateTransaction,
+            GetBlockGhostdagData,
+            GetBlockChildren,
+            GetBlockAnticone,
+            GetBlockAcceptanceData,
+            IsChainBlock,
+            GetUtxosByOutpoints,
+            GetDaaScoreTimestampEstimate,
+            GetLogLevels,
+            SetLogLevels,
+            RotateLogFiles,
         ]
     );
 
@@ -295,11 +295,13 @@ build_wrpc_wasm_bindgen_interface!(
         GetCoinSupply,
         GetConnectedPeerInfo,
         GetInfo,
+        GetLogLevels,
         GetPeerAddresses,
         GetMetrics,
         GetSelectedTipHash,
         GetSinkBlueScore,
         Ping,
+        RotateLogFiles,
         Shutdown,
         GetServerInfo,
         GetSyncStatus,
@@ -312,17 +314,27 @@ build_wrpc_wasm_bindgen_interface!(
         GetBalanceByAddress,
         GetBalancesByAddresses,
         GetBlock,
+        GetBlockAcceptanceData,
+        GetBlockAnticone,
+        GetBlockChildren,
+        GetBlockGhostdagData,
         GetBlocks,
         GetBlockTemplate,
         GetCurrentNetwork,
+        GetDaaScoreTimestampEstimate,
         GetHeaders,
         GetMempoolEntries,
         GetMempoolEntriesByAddresses,
         GetMempoolEntry,
         GetSubnetwork,
+        GetTransactionAcceptanceProof,
+        GetTransactionInclusionProof,
         // GetUtxosByAddresses,
+        GetUtxosByOutpoints,
         GetVirtualChainFromBlock,
+        IsChainBlock,
         ResolveFinalityConflict,
+        SetLogLevels,
         SubmitBlock,
         // SubmitTransaction,
         Unban,
@@ -350,6 +362,23 @@ impl RpcClient {
         to_value(&response).map_err(|err| err.into())
     }
 
+    /// Validates a transaction against the mempool rules without submitting it.
+    #[wasm_bindgen(js_name = validateTransaction)]
+    pub async fn js_validate_transaction(&self, js_value: JsValue, allow_orphan: Option<bool>) -> Result<JsValue> {
+        let transaction = if let Ok(signable) = SignableTransaction::try_from(&js_value) {
+            Transaction::from(signable)
+        } else if let Ok(transaction) = Transaction::try_from(js_value) {
+            transaction
+        } else {
+            return Err(Error::custom("invalid transaction data"));
+        };
+
+        let request = ValidateTransactionRequest::new(RpcTransaction::from(transaction), allow_orphan.unwrap_or(false));
+        let result: RpcResult<ValidateTransactionResponse> = self.client.validate_transaction_call(request).await;
+        let response: ValidateTransactionResponse = result.map_err(|err| wasm_bindgen::JsError::new(&err.to_string()))?;
+        to_value(&response).map_err(|err| err.into())
+    }
+
     /// This call accepts an `Array` of `Address` or an Array of address strings.
     #[wasm_bindgen(js_name = getUtxosByAddresses)]
     pub async fn get_utxos_by_addresses(&self, request: JsValue) -> Result<JsValue> {
@@ -30,9 +30,9 @@ struct Args {
     #[clap(long)]
     devnet: bool,
 
-    /// proxy:port for gRPC server (grpc://127.0.0.1:16110)
+    /// proxy:port for gRPC servers (grpc://127.0.0.1:16110), multiple upstream nodes can be supplied for failover
     #[clap(name = "grpc")]
-    grpc_proxy_address: Option<String>,
+    grpc_proxy_addresses: Vec<String>,
 
     // /// wRPC port
     /// interface:port for wRPC server (wrpc://127.0.0.1:17110)
@@ -51,7 +51,7 @@ struct Args {
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    let Args { testnet, simnet, devnet, grpc_proxy_address, interface, verbose, threads, encoding } = Args::parse();
+    let Args { testnet, simnet, devnet, mut grpc_proxy_addresses, interface, verbose, threads, encoding } = Args::parse();
 
     let network_type = if testnet {
         NetworkType::Testnet
@@ -71,19 +71,26 @@ async fn main() -> Result<()> {
         Encoding::SerdeJson => network_type.default_json_rpc_port(),
     };
 
+    if grpc_proxy_addresses.is_empty() {
+        grpc_proxy_addresses.push(format!("grpc://127.0.0.1:{kaspad_port}"));
+    }
+
     let options = Arc::new(Options {
         listen_address: interface.unwrap_or_else(|| format!("wrpc://127.0.0.1:{proxy_port}")),
-        grpc_proxy_address: Some(grpc_proxy_address.unwrap_or_else(|| format!("grpc://127.0.0.1:{kaspad_port}"))),
+        grpc_proxy_addresses,
         verbose,
         // ..Options::default()
     });
     log_info!("");
-    log_info!("Proxy routing to `{}` on {}", network_type, options.grpc_proxy_address.as_ref().unwrap());
+    log_info!("Proxy routing to `{}` on {}", network_type, options.grpc_proxy_addresses.join(", "));
 
     let counters = Arc::new(WrpcServerCounters::default());
     let tasks = threads.unwrap_or_else(num_cpus::get);
     let rpc_handler = Arc::new(KaspaRpcHandler::new(tasks, encoding, None, options.clone(), counters));
 
+    // Start the upstream health monitor
+    rpc_handler.server.start();
+
     let router = Arc::new(Router::new(rpc_handler.server.clone()));
     let server =
         RpcServer::new_with_encoding::<Server, Connection, RpcApiOps, Id64>(encoding, rpc_handler.clone(), router.interface.clone());
@@ -1,3 +1,4 @@
+use crate::upstream::Upstream;
 use kaspa_grpc_client::{GrpcClient, GrpcClientNotify};
 use kaspa_notify::{
     connection::Connection as ConnectionT,
@@ -5,8 +6,11 @@ use kaspa_notify::{
     listener::ListenerId,
     notification::Notification as NotificationT,
     notifier::Notify,
+    scope::Scope,
+    subscription::Command,
 };
-use kaspa_rpc_core::{api::ops::RpcApiOps, notify::mode::NotificationMode, Notification};
+use kaspa_rpc_core::{api::ops::RpcApiOps, notify::mode::NotificationMode, Notification, RpcResult};
+use kaspa_rpc_service::limits::{ConnectionLimiter, RequestPermit, SubscriptionChange};
 use std::{
     fmt::Debug,
     sync::{Arc, Mutex},
@@ -47,9 +51,13 @@ struct ConnectionInner {
     pub id: u64,
     pub peer: SocketAddr,
     pub messenger: Arc<Messenger>,
-    pub grpc_client: Option<Arc<GrpcClient>>,
+    // gRPC client and the upstream node it is connected to, replaced on upstream failover
+    pub grpc_client: Mutex<Option<Arc<GrpcClient>>>,
+    pub upstream: Mutex<Option<Arc<Upstream>>>,
     // not using an atomic in case an Id will change type in the future...
     pub listener_id: Mutex<Option<ListenerId>>,
+    // limits applying to this connection, only available when connected to rpc core
+    pub limiter: Option<ConnectionLimiter>,
 }
 
 impl ConnectionInner {
@@ -76,12 +84,21 @@ pub struct Connection {
 }
 
 impl Connection {
-    pub fn new(id: u64, peer: &SocketAddr, messenger: Arc<Messenger>, grpc_client: Option<Arc<GrpcClient>>) -> Connection {
+    pub fn new(
+        id: u64,
+        peer: &SocketAddr,
+        messenger: Arc<Messenger>,
+        grpc_client: Option<Arc<GrpcClient>>,
+        upstream: Option<Arc<Upstream>>,
+        limiter: Option<ConnectionLimiter>,
+    ) -> Connection {
         // If a GrpcClient is provided, it has to come configured in direct mode
         assert!(grpc_client.is_none() || grpc_client.as_ref().unwrap().notification_mode() == NotificationMode::Direct);
         // Should a gRPC client be provided, no listener_id is required for subscriptions so the listener id is set to default
         let listener_id = Mutex::new(grpc_client.clone().map(|_| ListenerId::default()));
-        Connection { inner: Arc::new(ConnectionInner { id, peer: *peer, messenger, grpc_client, listener_id }) }
+        let grpc_client = Mutex::new(grpc_client);
+        let upstream = Mutex::new(upstream);
+        Connection { inner: Arc::new(ConnectionInner { id, peer: *peer, messenger, grpc_client, upstream, listener_id, limiter }) }
     }
 
     /// Obtain the connection id
@@ -97,11 +114,27 @@ impl Connection {
     pub fn grpc_client(&self) -> Arc<GrpcClient> {
         self.inner
             .grpc_client
-            .as_ref()
-            .cloned()
+            .lock()
+            .unwrap()
+            .clone()
             .unwrap_or_else(|| panic!("Incorrect use: `server::Connection` does not carry RpcApi references"))
     }
 
+    /// The upstream node serving this connection when running as a proxy
+    pub fn upstream(&self) -> Option<Arc<Upstream>> {
+        self.inner.upstream.lock().unwrap().clone()
+    }
+
+    /// Replaces the gRPC client and upstream of a proxied connection, returning the previous ones
+    pub(crate) fn replace_upstream(&self, grpc_client: Arc<GrpcClient>, upstream: Arc<Upstream>) -> (Arc<GrpcClient>, Arc<Upstream>) {
+        let previous_client = self.inner.grpc_client.lock().unwrap().replace(grpc_client);
+        let previous_upstream = self.inner.upstream.lock().unwrap().replace(upstream);
+        (
+            previous_client.expect("Incorrect use: `server::Connection` does not carry RpcApi references"),
+            previous_upstream.expect("Incorrect use: `server::Connection` is not proxied"),
+        )
+    }
+
     pub fn grpc_client_notify_target(&self) -> GrpcClientNotify {
         self.inner.clone()
     }
@@ -118,6 +151,23 @@ impl Connection {
         &self.inner.peer
     }
 
+    /// Admits a request of this connection, see [`ConnectionLimiter::admit_request`]
+    pub fn admit_request(&self) -> RpcResult<Option<RequestPermit>> {
+        self.inner.limiter.as_ref().map(|limiter| limiter.admit_request()).transpose()
+    }
+
+    /// Admits a subscription command of this connection, see [`ConnectionLimiter::admit_subscription`]
+    pub fn admit_subscription(&self, scope: &Scope, command: Command) -> RpcResult<Option<SubscriptionChange>> {
+        self.inner.limiter.as_ref().map(|limiter| limiter.admit_subscription(scope, command)).transpose()
+    }
+
+    /// Rolls back an admitted subscription command which failed, see [`ConnectionLimiter::revert_subscription`]
+    pub fn revert_subscription(&self, change: Option<SubscriptionChange>) {
+        if let (Some(limiter), Some(change)) = (self.inner.limiter.as_ref(), change) {
+            limiter.revert_subscription(change);
+        }
+    }
+
     /// Creates a WebSocket [`Message`] that can be posted to the connection ([`Messenger`]) sink
     /// directly.
     pub fn create_serialized_notification_message<Ops, Msg>(encoding: Encoding, op: Ops, msg: Msg) -> WrpcResult<Message>
@@ -20,6 +20,9 @@ pub enum Error {
 
     #[error("Notify error: {0}")]
     NotifyError(#[from] NotifyError),
+
+    #[error("No synced upstream node is available")]
+    NoUpstreamAvailable,
 }
 
 impl<T> From<PoisonError<T>> for Error {
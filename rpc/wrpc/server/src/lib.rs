@@ -7,3 +7,4 @@ pub mod result;
 pub mod router;
 pub mod server;
 pub mod service;
+pub mod upstream;
@@ -57,6 +57,8 @@ impl Router {
                 GetSelectedTipHash,
                 GetSubnetwork,
                 GetSyncStatus,
+                GetTransactionAcceptanceProof,
+                GetTransactionInclusionProof,
                 GetUtxosByAddresses,
                 GetSinkBlueScore,
                 GetVirtualChainFromBlock,
@@ -66,6 +68,17 @@ impl Router {
                 SubmitBlock,
                 SubmitTransaction,
                 Unban,
+                ValidateTransaction,
+                GetBlockGhostdagData,
+                GetBlockChildren,
+                GetBlockAnticone,
+                GetBlockAcceptanceData,
+                IsChainBlock,
+                GetUtxosByOutpoints,
+                GetDaaScoreTimestampEstimate,
+                GetLogLevels,
+                SetLogLevels,
+                RotateLogFiles,
             ]
         );
 
@@ -1,17 +1,23 @@
 use crate::{
     collector::{WrpcServiceCollector, WrpcServiceConverter},
     connection::Connection,
+    error::Error,
     result::Result,
     service::Options,
+    upstream::{Upstream, Upstreams, UPSTREAM_FAILOVER_TIMEOUT, UPSTREAM_HEALTH_CHECK_INTERVAL},
+};
+use futures::future::join_all;
+use kaspa_notify::{
+    connection::ChannelType, events::EVENT_TYPE_ARRAY, listener::ListenerId, notifier::Notifier, scope::Scope, subscriber::Subscriber,
+    subscription::Command,
 };
-use kaspa_grpc_client::GrpcClient;
-use kaspa_notify::{connection::ChannelType, events::EVENT_TYPE_ARRAY, notifier::Notifier, scope::Scope, subscriber::Subscriber};
 use kaspa_rpc_core::{
     api::rpc::{DynRpcService, RpcApi},
-    notify::{channel::NotificationChannel, connection::ChannelConnection, mode::NotificationMode},
+    notify::{channel::NotificationChannel, connection::ChannelConnection},
     Notification, RpcResult,
 };
 use kaspa_rpc_service::service::RpcCoreService;
+use kaspa_utils::triggers::SingleTrigger;
 use std::{
     collections::HashMap,
     sync::{
@@ -34,7 +40,9 @@ struct ServerInner {
     pub _encoding: Encoding,
     pub sockets: Mutex<HashMap<u64, Connection>>,
     pub rpc_core: Option<RpcCore>,
+    pub upstreams: Option<Arc<Upstreams>>,
     pub options: Arc<Options>,
+    pub shutdown: SingleTrigger,
 }
 
 #[derive(Clone)]
@@ -49,8 +57,8 @@ impl Server {
         // Either get a core service or be called from the proxy and rely each connection having its own gRPC client
         assert_eq!(
             core_service.is_none(),
-            options.grpc_proxy_address.is_some(),
-            "invalid setup: Server must exclusively get either a core service or a gRPC server address"
+            !options.grpc_proxy_addresses.is_empty(),
+            "invalid setup: Server must exclusively get either a core service or gRPC server addresses"
         );
 
         let rpc_core = if let Some(service) = core_service {
@@ -70,13 +78,17 @@ impl Server {
             None
         };
 
+        let upstreams = (!options.grpc_proxy_addresses.is_empty()).then(|| Arc::new(Upstreams::new(&options.grpc_proxy_addresses)));
+
         Server {
             inner: Arc::new(ServerInner {
                 next_connection_id: AtomicU64::new(0),
                 _encoding: encoding,
                 sockets: Mutex::new(HashMap::new()),
                 rpc_core,
+                upstreams,
                 options,
+                shutdown: SingleTrigger::default(),
             }),
         }
     }
@@ -86,31 +98,130 @@ impl Server {
             // Start the internal notifier
             rpc_core.wrpc_notifier.clone().start();
         }
+
+        if let Some(upstreams) = self.inner.upstreams.clone() {
+            // Start monitoring the upstream nodes
+            let server = self.clone();
+            tokio::spawn(async move { server.monitor_upstreams(upstreams).await });
+        }
+    }
+
+    /// Periodically health-checks the upstream nodes, migrating the connections
+    /// of unavailable upstreams to available ones
+    async fn monitor_upstreams(self, upstreams: Arc<Upstreams>) {
+        let shutdown = self.inner.shutdown.listener.clone();
+        loop {
+            upstreams.check().await;
+
+            // Fail the connections over concurrently so that a slow upstream does not hold up the others
+            let connections = self.inner.sockets.lock().unwrap().values().cloned().collect::<Vec<_>>();
+            let failovers = connections
+                .into_iter()
+                .filter(|connection| {
+                    let is_available = connection.upstream().map(|upstream| upstream.is_available()).unwrap_or(false);
+                    !is_available || !connection.grpc_client().is_connected()
+                })
+                .map(|connection| {
+                    let (server, upstreams) = (&self, &upstreams);
+                    async move {
+                        match tokio::time::timeout(UPSTREAM_FAILOVER_TIMEOUT, server.failover(&connection, upstreams)).await {
+                            Ok(Ok(())) => {}
+                            Ok(Err(err)) => log_warning!("Unable to fail over wrpc://{}: {err}", connection.peer()),
+                            Err(_) => log_warning!("Unable to fail over wrpc://{}: timed out", connection.peer()),
+                        }
+                    }
+                });
+            join_all(failovers).await;
+
+            tokio::select! {
+                _ = tokio::time::sleep(UPSTREAM_HEALTH_CHECK_INTERVAL) => {}
+                _ = shutdown.clone() => break,
+            }
+        }
+    }
+
+    /// Selects an available upstream node according to the status observed by the last periodic health check.
+    /// Upstreams are only checked here if no health check has completed yet (i.e. right after startup).
+    async fn select_upstream(&self, upstreams: &Upstreams, exclude: Option<&Arc<Upstream>>) -> Result<Arc<Upstream>> {
+        upstreams.ensure_checked().await;
+        upstreams.select(exclude).ok_or(Error::NoUpstreamAvailable)
+    }
+
+    /// Routes the connection to another upstream node, re-establishing its notification subscriptions
+    async fn failover(&self, connection: &Connection, upstreams: &Upstreams) -> Result<()> {
+        // The current upstream is only eligible again if it is available (i.e. the gRPC client alone got disconnected)
+        let current = connection.upstream().filter(|upstream| !upstream.is_available());
+        let upstream = self.select_upstream(upstreams, current.as_ref()).await?;
+        let grpc_client = Arc::new(upstream.connect().await.map_err(|err| WebSocketError::Other(err.to_string()))?);
+        grpc_client.start(Some(connection.grpc_client_notify_target())).await;
+
+        let subscriptions = connection.grpc_client().subscriptions().await;
+        for scope in subscriptions.iter().cloned() {
+            if let Err(err) = grpc_client.start_notify(ListenerId::default(), scope).await {
+                let _ = grpc_client.disconnect().await;
+                let _ = grpc_client.join().await;
+                return Err(err.into());
+            }
+        }
+
+        // The sockets lock guards against a concurrent disconnection of the websocket
+        let previous = {
+            let sockets = self.inner.sockets.lock()?;
+            sockets.contains_key(&connection.id()).then(|| {
+                upstream.register_connection();
+                connection.replace_upstream(grpc_client.clone(), upstream.clone())
+            })
+        };
+
+        match previous {
+            Some((previous_client, previous_upstream)) => {
+                previous_upstream.unregister_connection();
+                log_info!(
+                    "Routing wrpc://{} -> {} (failover from {}, {} subscription(s) renewed)",
+                    connection.peer(),
+                    upstream.address(),
+                    previous_upstream.address(),
+                    subscriptions.len()
+                );
+                let _ = previous_client.disconnect().await;
+                let _ = previous_client.join().await;
+            }
+            None => {
+                let _ = grpc_client.disconnect().await;
+                let _ = grpc_client.join().await;
+            }
+        }
+
+        Ok(())
     }
 
     pub async fn connect(&self, peer: &SocketAddr, messenger: Arc<Messenger>) -> Result<Connection> {
         log_info!("WebSocket connected: {}", peer);
         let id = self.inner.next_connection_id.fetch_add(1, Ordering::SeqCst);
 
-        let grpc_client = if let Some(grpc_proxy_address) = &self.inner.options.grpc_proxy_address {
+        // Enforce the per-IP connection limit when connected to rpc core
+        let limiter =
+            self.inner.rpc_core.as_ref().map(|rpc_core| rpc_core.service.rpc_limiter().register_connection(*peer)).transpose()?;
+
+        let (grpc_client, upstream) = if let Some(upstreams) = &self.inner.upstreams {
             // Provider::GrpcClient
 
-            log_info!("Routing wrpc://{peer} -> {grpc_proxy_address}");
-            let grpc_client = GrpcClient::connect(NotificationMode::Direct, grpc_proxy_address.to_owned(), false, None, true, None)
-                .await
-                .map_err(|e| WebSocketError::Other(e.to_string()))?;
+            let upstream = self.select_upstream(upstreams, None).await?;
+            log_info!("Routing wrpc://{peer} -> {}", upstream.address());
+            let grpc_client = upstream.connect().await.map_err(|e| WebSocketError::Other(e.to_string()))?;
             // log_trace!("Creating proxy relay...");
-            Some(Arc::new(grpc_client))
+            (Some(Arc::new(grpc_client)), Some(upstream))
         } else {
             // Provider::RpcCore
 
-            None
+            (None, None)
         };
-        let connection = Connection::new(id, peer, messenger, grpc_client);
-        if self.inner.options.grpc_proxy_address.is_some() {
+        let connection = Connection::new(id, peer, messenger, grpc_client, upstream.clone(), limiter);
+        if let Some(upstream) = upstream {
             // log_trace!("starting gRPC");
             connection.grpc_client().start(Some(connection.grpc_client_notify_target())).await;
             // log_trace!("gRPC started...");
+            upstream.register_connection();
         }
         self.inner.sockets.lock()?.insert(id, connection.clone());
         Ok(connection)
@@ -118,6 +229,9 @@ impl Server {
 
     pub async fn disconnect(&self, connection: Connection) {
         log_info!("WebSocket disconnected: {}", connection.peer());
+        // Removing the connection first prevents any concurrent upstream failover from replacing its gRPC client
+        self.inner.sockets.lock().unwrap().remove(&connection.id());
+
         if let Some(rpc_core) = &self.inner.rpc_core {
             if let Some(listener_id) = connection.listener_id() {
                 rpc_core.wrpc_notifier.unregister_listener(listener_id).unwrap_or_else(|err| {
@@ -127,14 +241,20 @@ impl Server {
         } else {
             let _ = connection.grpc_client().disconnect().await;
             let _ = connection.grpc_client().join().await;
+            if let Some(upstream) = connection.upstream() {
+                upstream.unregister_connection();
+            }
         }
 
-        self.inner.sockets.lock().unwrap().remove(&connection.id());
-
         // FIXME: determine if messenger should be closed explicitly
         // connection.close();
     }
 
+    /// Upstream gRPC nodes when running as a proxy
+    pub fn upstreams(&self) -> Option<Arc<Upstreams>> {
+        self.inner.upstreams.clone()
+    }
+
     #[inline(always)]
     pub fn notifier(&self) -> Option<Arc<WrpcNotifier>> {
         self.inner.rpc_core.as_ref().map(|x| x.wrpc_notifier.clone())
@@ -149,6 +269,15 @@ impl Server {
     }
 
     pub async fn start_notify(&self, connection: &Connection, scope: Scope) -> RpcResult<()> {
+        let change = connection.admit_subscription(&scope, Command::Start)?;
+        let result = self.execute_start_notify(connection, scope).await;
+        if result.is_err() {
+            connection.revert_subscription(change);
+        }
+        result
+    }
+
+    async fn execute_start_notify(&self, connection: &Connection, scope: Scope) -> RpcResult<()> {
         let listener_id = if let Some(listener_id) = connection.listener_id() {
             listener_id
         } else {
@@ -171,6 +300,15 @@ impl Server {
     }
 
     pub async fn stop_notify(&self, connection: &Connection, scope: Scope) -> RpcResult<()> {
+        let change = connection.admit_subscription(&scope, Command::Stop)?;
+        let result = self.execute_stop_notify(connection, scope).await;
+        if result.is_err() {
+            connection.revert_subscription(change);
+        }
+        result
+    }
+
+    async fn execute_stop_notify(&self, connection: &Connection, scope: Scope) -> RpcResult<()> {
         if let Some(listener_id) = connection.listener_id() {
             workflow_log::log_trace!("notification unsubscribe[0x{listener_id:x}] {scope:?}");
             if let Some(rpc_core) = &self.inner.rpc_core {
@@ -193,6 +331,8 @@ impl Server {
             // Wait for the internal notifier to stop
             rpc_core.wrpc_notifier.join().await?;
         } else {
+            // Stop monitoring the upstream nodes
+            self.inner.shutdown.trigger.trigger();
             // FIXME: check if all existing connections are actually getting a call to self.disconnect(connection)
             //        else do it here
         }
@@ -20,13 +20,14 @@ static MAX_WRPC_MESSAGE_SIZE: usize = 1024 * 1024 * 128; // 128MB
 /// Options for configuring the wRPC server
 pub struct Options {
     pub listen_address: String,
-    pub grpc_proxy_address: Option<String>,
+    /// Upstream gRPC nodes when running as a proxy, connections are routed to synced nodes only
+    pub grpc_proxy_addresses: Vec<String>,
     pub verbose: bool,
 }
 
 impl Default for Options {
     fn default() -> Self {
-        Options { listen_address: "127.0.0.1:17110".to_owned(), verbose: false, grpc_proxy_address: None }
+        Options { listen_address: "127.0.0.1:17110".to_owned(), verbose: false, grpc_proxy_addresses: vec![] }
     }
 }
 
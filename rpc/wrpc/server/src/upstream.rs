@@ -0,0 +1,259 @@
+//!
+//! Upstream gRPC nodes used by the wRPC proxy.
+//!
+//! Each upstream is health-checked periodically via `get_server_info` and
+//! `get_sync_status`. Only connected and synced upstreams are eligible for
+//! routing new connections. Connections routed to an upstream that becomes
+//! unavailable are migrated to another one by the [`Server`](crate::server::Server).
+//!
+
+use kaspa_grpc_client::GrpcClient;
+use kaspa_rpc_core::{api::rpc::RpcApi, notify::mode::NotificationMode};
+use std::{
+    fmt::Debug,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+use workflow_log::*;
+
+/// Interval between two consecutive health checks of the upstream nodes
+pub const UPSTREAM_HEALTH_CHECK_INTERVAL: Duration = Duration::from_secs(5);
+/// Maximum duration of the failover of a single connection to another upstream node
+pub const UPSTREAM_FAILOVER_TIMEOUT: Duration = Duration::from_secs(10);
+/// Timeout (in milliseconds) of the health check requests
+const UPSTREAM_HEALTH_CHECK_TIMEOUT: u64 = 3_000;
+
+/// Health of an upstream node as observed by the last health check
+#[derive(Debug, Clone, Default)]
+pub struct UpstreamStatus {
+    pub is_connected: bool,
+    pub is_synced: bool,
+    pub server_version: Option<String>,
+    pub virtual_daa_score: u64,
+    pub error: Option<String>,
+}
+
+impl UpstreamStatus {
+    pub fn is_available(&self) -> bool {
+        self.is_connected && self.is_synced
+    }
+}
+
+pub struct Upstream {
+    address: String,
+    status: Mutex<UpstreamStatus>,
+    connections: AtomicUsize,
+    /// gRPC client dedicated to health checks
+    health_client: tokio::sync::Mutex<Option<Arc<GrpcClient>>>,
+}
+
+impl Debug for Upstream {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Upstream").field("address", &self.address).field("status", &self.status()).finish()
+    }
+}
+
+impl Upstream {
+    pub fn new(address: String) -> Self {
+        Self { address, status: Mutex::default(), connections: AtomicUsize::new(0), health_client: Default::default() }
+    }
+
+    pub fn address(&self) -> &str {
+        &self.address
+    }
+
+    pub fn status(&self) -> UpstreamStatus {
+        self.status.lock().unwrap().clone()
+    }
+
+    pub fn is_available(&self) -> bool {
+        self.status.lock().unwrap().is_available()
+    }
+
+    /// Number of wRPC connections currently routed to this upstream
+    pub fn connections(&self) -> usize {
+        self.connections.load(Ordering::SeqCst)
+    }
+
+    pub(crate) fn register_connection(&self) {
+        self.connections.fetch_add(1, Ordering::SeqCst);
+    }
+
+    pub(crate) fn unregister_connection(&self) {
+        self.connections.fetch_sub(1, Ordering::SeqCst);
+    }
+
+    /// Opens a new gRPC client (in direct notification mode) to this upstream
+    pub async fn connect(&self) -> kaspa_grpc_client::error::Result<GrpcClient> {
+        GrpcClient::connect(NotificationMode::Direct, self.address.clone(), false, None, true, None).await
+    }
+
+    /// Checks the upstream health, updating its status and returning it
+    pub async fn check(&self) -> UpstreamStatus {
+        let status = self.try_check().await.unwrap_or_else(|err| UpstreamStatus { error: Some(err), ..Default::default() });
+
+        let previous = std::mem::replace(&mut *self.status.lock().unwrap(), status.clone());
+        if previous.is_available() != status.is_available() || previous.error != status.error {
+            if status.is_available() {
+                log_info!("Upstream {} is available (version {})", self.address, status.server_version.as_deref().unwrap_or("n/a"));
+            } else if let Some(err) = &status.error {
+                log_warning!("Upstream {} is unavailable: {err}", self.address);
+            } else {
+                log_warning!("Upstream {} is not synced", self.address);
+            }
+        }
+
+        status
+    }
+
+    async fn try_check(&self) -> std::result::Result<UpstreamStatus, String> {
+        let mut health_client = self.health_client.lock().await;
+        if !health_client.as_ref().map(|client| client.is_connected()).unwrap_or(false) {
+            let client = GrpcClient::connect(
+                NotificationMode::Direct,
+                self.address.clone(),
+                false,
+                None,
+                true,
+                Some(UPSTREAM_HEALTH_CHECK_TIMEOUT),
+            )
+            .await
+            .map_err(|err| err.to_string())?;
+            health_client.replace(Arc::new(client));
+        }
+        let client = health_client.as_ref().unwrap();
+
+        let result = async {
+            let server_info = client.get_server_info().await?;
+            let is_synced = client.get_sync_status().await?;
+            Ok::<_, kaspa_rpc_core::RpcError>(UpstreamStatus {
+                is_connected: true,
+                is_synced: server_info.is_synced && is_synced,
+                server_version: Some(server_info.server_version),
+                virtual_daa_score: server_info.virtual_daa_score,
+                error: None,
+            })
+        }
+        .await;
+
+        if result.is_err() {
+            // drop the client so a new one is created during the next health check
+            if let Some(client) = health_client.take() {
+                let _ = client.disconnect().await;
+            }
+        }
+        result.map_err(|err| err.to_string())
+    }
+}
+
+/// Set of upstream gRPC nodes the proxy routes connections to
+#[derive(Debug)]
+pub struct Upstreams {
+    upstreams: Vec<Arc<Upstream>>,
+    /// Set once a health check of all upstreams has completed
+    checked: tokio::sync::OnceCell<()>,
+}
+
+impl Upstreams {
+    pub fn new(addresses: &[String]) -> Self {
+        let upstreams = addresses.iter().map(|address| Arc::new(Upstream::new(address.clone()))).collect();
+        Self { upstreams, checked: Default::default() }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.upstreams.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Arc<Upstream>> {
+        self.upstreams.iter()
+    }
+
+    /// Health-checks all upstreams concurrently
+    pub async fn check(&self) {
+        futures::future::join_all(self.upstreams.iter().map(|upstream| upstream.check())).await;
+        let _ = self.checked.set(());
+    }
+
+    /// Health-checks all upstreams unless a health check has already completed.
+    /// Concurrent callers share a single health check.
+    pub async fn ensure_checked(&self) {
+        self.checked
+            .get_or_init(|| async {
+                futures::future::join_all(self.upstreams.iter().map(|upstream| upstream.check())).await;
+            })
+            .await;
+    }
+
+    /// Selects the available upstream serving the fewest connections, skipping `exclude`.
+    /// Ties are resolved by the order in which upstreams were supplied.
+    pub fn select(&self, exclude: Option<&Arc<Upstream>>) -> Option<Arc<Upstream>> {
+        self.upstreams
+            .iter()
+            .filter(|upstream| upstream.is_available() && !exclude.map(|exclude| Arc::ptr_eq(exclude, upstream)).unwrap_or(false))
+            .min_by_key(|upstream| upstream.connections())
+            .cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn upstreams(count: usize) -> Upstreams {
+        Upstreams::new(&(0..count).map(|i| format!("grpc://127.0.0.1:{}", 16110 + i)).collect::<Vec<_>>())
+    }
+
+    fn set_status(upstream: &Upstream, is_connected: bool, is_synced: bool) {
+        *upstream.status.lock().unwrap() = UpstreamStatus { is_connected, is_synced, ..Default::default() };
+    }
+
+    fn address(upstream: Option<Arc<Upstream>>) -> Option<String> {
+        upstream.map(|upstream| upstream.address().to_string())
+    }
+
+    #[test]
+    fn test_upstreams_select_available() {
+        let upstreams = upstreams(3);
+        let [a, b, c] = [0, 1, 2].map(|i| upstreams.upstreams[i].clone());
+
+        // upstreams are unavailable until checked
+        assert!(upstreams.select(None).is_none());
+
+        set_status(&a, true, false);
+        set_status(&b, false, false);
+        set_status(&c, true, true);
+        assert_eq!(address(upstreams.select(None)), Some(c.address().to_string()));
+        assert!(upstreams.select(Some(&c)).is_none());
+
+        set_status(&c, false, false);
+        assert!(upstreams.select(None).is_none());
+    }
+
+    #[test]
+    fn test_upstreams_select_least_connections() {
+        let upstreams = upstreams(3);
+        let [a, b, c] = [0, 1, 2].map(|i| upstreams.upstreams[i].clone());
+        [&a, &b, &c].iter().for_each(|upstream| set_status(upstream, true, true));
+
+        // ties are resolved by the order of the upstreams
+        assert_eq!(address(upstreams.select(None)), Some(a.address().to_string()));
+
+        a.register_connection();
+        b.register_connection();
+        assert_eq!(address(upstreams.select(None)), Some(c.address().to_string()));
+
+        c.register_connection();
+        c.register_connection();
+        assert_eq!(address(upstreams.select(None)), Some(a.address().to_string()));
+        // excluding an upstream falls back to the next least loaded one
+        assert_eq!(address(upstreams.select(Some(&a))), Some(b.address().to_string()));
+
+        a.unregister_connection();
+        b.unregister_connection();
+        assert_eq!(address(upstreams.select(Some(&a))), Some(b.address().to_string()));
+        assert_eq!(address(upstreams.select(Some(&b))), Some(a.address().to_string()));
+    }
+}
@@ -46,10 +46,10 @@ impl Daemon {
         drop(socket3);
         drop(socket4);
 
-        args.rpclisten = Some(format!("0.0.0.0:{rpc_port}").try_into().unwrap());
-        args.listen = Some(format!("0.0.0.0:{p2p_port}").try_into().unwrap());
-        args.rpclisten_json = Some(format!("0.0.0.0:{rpc_json_port}").parse().unwrap());
-        args.rpclisten_borsh = Some(format!("0.0.0.0:{rpc_borsh_port}").parse().unwrap());
+        args.rpclisten = vec![format!("0.0.0.0:{rpc_port}").try_into().unwrap()];
+        args.listen = vec![format!("0.0.0.0:{p2p_port}").try_into().unwrap()];
+        args.rpclisten_json = vec![format!("0.0.0.0:{rpc_json_port}").parse().unwrap()];
+        args.rpclisten_borsh = vec![format!("0.0.0.0:{rpc_borsh_port}").parse().unwrap()];
         let appdir_tempdir = get_kaspa_tempdir();
         args.appdir = Some(appdir_tempdir.path().to_str().unwrap().to_owned());
 
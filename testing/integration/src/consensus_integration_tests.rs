@@ -10,6 +10,8 @@ use kaspa_consensus::consensus::test_consensus::{TestConsensus, TestConsensusFac
 use kaspa_consensus::model::stores::block_transactions::{
     BlockTransactionsStore, BlockTransactionsStoreReader, DbBlockTransactionsStore,
 };
+use kaspa_consensus::model::stores::daa_score_timestamps::{daa_score_sample_bucket, DaaScoreTimestamp, DaaScoreTimestampStoreReader};
+use kaspa_consensus::model::stores::finality_conflicts::FinalityConflictsStoreReader;
 use kaspa_consensus::model::stores::ghostdag::{GhostdagStoreReader, KType as GhostdagKType};
 use kaspa_consensus::model::stores::headers::HeaderStoreReader;
 use kaspa_consensus::model::stores::reachability::DbReachabilityStore;
@@ -26,11 +28,16 @@ use kaspa_consensus_core::blockhash::new_unique;
 use kaspa_consensus_core::blockstatus::BlockStatus;
 use kaspa_consensus_core::constants::BLOCK_VERSION;
 use kaspa_consensus_core::errors::block::{BlockProcessResult, RuleError};
+use kaspa_consensus_core::errors::consensus::ConsensusError;
+use kaspa_consensus_core::errors::traversal::TraversalError;
 use kaspa_consensus_core::header::Header;
+use kaspa_consensus_core::merkle::{create_accepted_id_merkle_proof, create_hash_merkle_proof};
 use kaspa_consensus_core::network::{NetworkId, NetworkType::Mainnet};
 use kaspa_consensus_core::subnets::SubnetworkId;
 use kaspa_consensus_core::trusted::{ExternalGhostdagData, TrustedBlock};
-use kaspa_consensus_core::tx::{ScriptPublicKey, Transaction, TransactionInput, TransactionOutpoint, TransactionOutput, UtxoEntry};
+use kaspa_consensus_core::tx::{
+    ScriptPublicKey, Transaction, TransactionId, TransactionInput, TransactionOutpoint, TransactionOutput, UtxoEntry,
+};
 use kaspa_consensus_core::{blockhash, hashing, BlockHashMap, BlueWorkType};
 use kaspa_consensus_notify::root::ConsensusNotificationRoot;
 use kaspa_consensus_notify::service::NotifyService;
@@ -48,7 +55,7 @@ use kaspa_core::info;
 use kaspa_core::signals::Shutdown;
 use kaspa_core::task::runtime::AsyncRuntime;
 use kaspa_database::create_temp_db;
-use kaspa_database::prelude::ConnBuilder;
+use kaspa_database::prelude::{ConnBuilder, StoreResultExtensions};
 use kaspa_index_processor::service::IndexService;
 use kaspa_math::Uint256;
 use kaspa_muhash::MuHash;
@@ -1042,6 +1049,30 @@ async fn json_test(file_path: &str, concurrency: bool) {
     assert_eq!(virtual_utxos.len(), utxoindex_utxos.len());
     assert!(virtual_utxos.is_subset(&utxoindex_utxos));
     assert!(utxoindex_utxos.is_subset(&virtual_utxos));
+
+    // Assert that DAA score timestamp estimates are exact for the samples taken while pruning
+    let consensus = tc.consensus_clone();
+    let sink_daa_score = tc.get_header(tc.get_sink()).unwrap().daa_score;
+    let samples = (0..=daa_score_sample_bucket(sink_daa_score))
+        .filter_map(|bucket| consensus.daa_score_timestamp_store.get(bucket).unwrap_option())
+        .collect_vec();
+    if tc.pruning_point() != config.genesis.hash {
+        assert!(!samples.is_empty(), "chain blocks below the pruning point are expected to be sampled");
+    }
+    if let Some(pruning_point) = pruning_point {
+        // The imported pruning point is sampled, providing a timestamp reference for the history which was never synced
+        let header = tc.get_header(pruning_point).unwrap();
+        let sample = DaaScoreTimestamp { daa_score: header.daa_score, timestamp: header.timestamp };
+        assert_eq!(consensus.daa_score_timestamp_store.get(daa_score_sample_bucket(header.daa_score)).unwrap(), sample);
+    }
+    let estimates = tc.get_daa_score_timestamp_estimates(samples.iter().map(|sample| sample.daa_score).collect());
+    assert_eq!(estimates, samples.iter().map(|sample| sample.timestamp).collect_vec());
+
+    // Assert that point lookups by outpoint match the virtual UTXO set, and that unknown outpoints are missing
+    let (outpoints, entries): (Vec<_>, Vec<_>) = tc.get_virtual_utxos(None, 100, false).into_iter().unzip();
+    let unknown_outpoint = TransactionOutpoint::new(TransactionId::default(), u32::MAX);
+    let lookup = tc.get_virtual_utxos_by_outpoints(&outpoints.iter().copied().chain(std::iter::once(unknown_outpoint)).collect_vec());
+    assert_eq!(lookup, entries.into_iter().map(Some).chain(std::iter::once(None)).collect_vec());
 }
 
 fn submit_header_chunk(
@@ -1289,6 +1320,193 @@ async fn bounded_merge_depth_test() {
     consensus.shutdown(wait_handles);
 }
 
+#[tokio::test]
+async fn finality_conflict_test() {
+    let config = ConfigBuilder::new(DEVNET_PARAMS)
+        .skip_proof_of_work()
+        .edit_consensus_params(|p| {
+            p.finality_depth = 10;
+        })
+        .build();
+    let consensus = TestConsensus::new(&config);
+    let wait_handles = consensus.init();
+
+    let mut selected_chain = vec![config.genesis.hash];
+    for i in 1..=20u64 {
+        let hash: Hash = i.into();
+        consensus.add_utxo_valid_block_with_parents(hash, vec![*selected_chain.last().unwrap()], vec![]).await.unwrap();
+        selected_chain.push(hash);
+    }
+    assert_ne!(consensus.finality_point(), config.genesis.hash);
+
+    // A longer chain forking from genesis has more blue work but violates finality
+    let selected_blue_work = consensus.ghostdag_store().get_blue_work(*selected_chain.last().unwrap()).unwrap();
+    let mut conflicting_chain = vec![config.genesis.hash];
+    while consensus.ghostdag_store().get_blue_work(*conflicting_chain.last().unwrap()).unwrap() <= selected_blue_work {
+        let hash: Hash = (conflicting_chain.len() as u64 + 100).into();
+        consensus.add_utxo_valid_block_with_parents(hash, vec![*conflicting_chain.last().unwrap()], vec![]).await.unwrap();
+        conflicting_chain.push(hash);
+    }
+    assert_eq!(consensus.get_sink(), *selected_chain.last().unwrap());
+    let violating_blocks = consensus.finality_conflicts_store.read().get().unwrap().violating_blocks.clone();
+    assert!(violating_blocks.contains_key(conflicting_chain.last().unwrap()));
+    assert!(!violating_blocks.contains_key(&conflicting_chain[1]));
+    // Only the latest violating block of the conflicting chain is kept
+    assert_eq!(violating_blocks.len(), 1);
+
+    // A block which is neither on the virtual chain nor in the past of a violating block cannot resolve the conflict
+    consensus.add_utxo_valid_block_with_parents(200.into(), vec![selected_chain[5]], vec![]).await.unwrap();
+    assert!(matches!(consensus.resolve_finality_conflict(200.into()), Err(ConsensusError::NotInFinalityConflict(_))));
+
+    // Choosing the current chain dismisses the conflict
+    consensus.resolve_finality_conflict(selected_chain[3]).unwrap();
+    assert!(consensus.finality_conflicts_store.read().get().unwrap().violating_blocks.is_empty());
+    assert!(consensus.resolve_finality_conflict(selected_chain[3]).is_err());
+
+    // Choosing the conflicting chain moves the virtual chain to it on the next virtual resolution
+    let hash: Hash = 1000.into();
+    consensus.add_utxo_valid_block_with_parents(hash, vec![*conflicting_chain.last().unwrap()], vec![]).await.unwrap();
+    conflicting_chain.push(hash);
+    assert_eq!(consensus.get_sink(), *selected_chain.last().unwrap());
+    let violating_blocks = consensus.finality_conflicts_store.read().get().unwrap().violating_blocks.clone();
+    assert_eq!(violating_blocks.keys().copied().collect::<Vec<_>>(), vec![hash]);
+    consensus.resolve_finality_conflict(conflicting_chain[1]).unwrap();
+
+    let hash: Hash = 1001.into();
+    consensus.add_utxo_valid_block_with_parents(hash, vec![*conflicting_chain.last().unwrap()], vec![]).await.unwrap();
+    assert_eq!(consensus.get_sink(), hash);
+    let conflicts = consensus.finality_conflicts_store.read().get().unwrap();
+    assert!(conflicts.violating_blocks.is_empty());
+    assert!(conflicts.resolution.is_none(), "the chosen block is final, hence the resolution is expected to be completed");
+
+    consensus.shutdown(wait_handles);
+}
+
+#[tokio::test]
+async fn get_headers_test() {
+    let config = ConfigBuilder::new(DEVNET_PARAMS).skip_proof_of_work().build();
+    let consensus = TestConsensus::new(&config);
+    let wait_handles = consensus.init();
+
+    // Build a chain of 10 blocks where block 5 also merges a side block pointing at block 2
+    let side_block: Hash = 100.into();
+    let mut chain = vec![config.genesis.hash];
+    for i in 1..=10u64 {
+        let hash: Hash = i.into();
+        let mut parents = vec![*chain.last().unwrap()];
+        if i == 5 {
+            consensus.add_utxo_valid_block_with_parents(side_block, vec![chain[2]], vec![]).await.unwrap();
+            parents.push(side_block);
+        }
+        consensus.add_utxo_valid_block_with_parents(hash, parents, vec![]).await.unwrap();
+        chain.push(hash);
+    }
+    assert_eq!(consensus.get_sink(), chain[10]);
+
+    let hashes = |headers: Vec<Arc<Header>>| headers.iter().map(|header| header.hash).collect::<Vec<_>>();
+
+    assert_eq!(hashes(consensus.get_headers(chain[10], 5, false).unwrap()), chain[6..=10].iter().rev().copied().collect::<Vec<_>>());
+    assert_eq!(hashes(consensus.get_headers(chain[3], 100, false).unwrap()), chain[..=3].iter().rev().copied().collect::<Vec<_>>());
+    assert_eq!(hashes(consensus.get_headers(side_block, 2, false).unwrap()), vec![side_block, chain[2]]);
+
+    assert_eq!(hashes(consensus.get_headers(config.genesis.hash, 100, true).unwrap()), chain);
+    assert_eq!(hashes(consensus.get_headers(chain[7], 100, true).unwrap()), chain[7..].to_vec());
+    // An off-chain block continues with the first chain block in its future
+    assert_eq!(hashes(consensus.get_headers(side_block, 3, true).unwrap()), vec![side_block, chain[5], chain[6]]);
+
+    assert!(consensus.get_headers(chain[4], 0, true).unwrap().is_empty());
+    assert!(matches!(consensus.get_headers(1000.into(), 10, true), Err(ConsensusError::HeaderNotFound(_))));
+
+    consensus.shutdown(wait_handles);
+}
+
+#[tokio::test]
+async fn merkle_proofs_test() {
+    let config = ConfigBuilder::new(DEVNET_PARAMS).skip_proof_of_work().build();
+    let consensus = TestConsensus::new(&config);
+    let wait_handles = consensus.init();
+
+    let mut chain = vec![config.genesis.hash];
+    for i in 1..=5u64 {
+        consensus.add_utxo_valid_block_with_parents(i.into(), vec![*chain.last().unwrap()], vec![]).await.unwrap();
+        chain.push(i.into());
+    }
+    // A block merged by the next chain block
+    consensus.add_utxo_valid_block_with_parents(100.into(), vec![chain[3]], vec![]).await.unwrap();
+    consensus.add_utxo_valid_block_with_parents(6.into(), vec![chain[5], 100.into()], vec![]).await.unwrap();
+    chain.push(6.into());
+
+    for &hash in chain[1..].iter() {
+        let block = consensus.get_block(hash).unwrap();
+        let coinbase_id = block.transactions[0].id();
+        let proof = create_hash_merkle_proof(&block.transactions, coinbase_id).unwrap();
+        assert!(proof.verify(hashing::tx::hash(&block.transactions[0]), block.header.hash_merkle_root));
+
+        let accepted_tx_ids = consensus.get_accepted_transaction_ids(hash).unwrap();
+        assert_eq!(kaspa_merkle::calc_merkle_root(accepted_tx_ids.iter().copied()), block.header.accepted_id_merkle_root);
+        for &tx_id in accepted_tx_ids.iter() {
+            let proof = create_accepted_id_merkle_proof(&accepted_tx_ids, tx_id).unwrap();
+            assert!(proof.verify(tx_id, block.header.accepted_id_merkle_root));
+        }
+        assert!(create_accepted_id_merkle_proof(&accepted_tx_ids, coinbase_id).is_none());
+    }
+    assert!(consensus.get_accepted_transaction_ids(100.into()).is_err());
+
+    consensus.shutdown(wait_handles);
+}
+
+#[tokio::test]
+async fn anticone_test() {
+    let config = ConfigBuilder::new(DEVNET_PARAMS).skip_proof_of_work().build();
+    let consensus = TestConsensus::new(&config);
+    let wait_handles = consensus.init();
+
+    // A chain of 5 blocks along with a side block pointing at genesis
+    let mut chain = vec![config.genesis.hash];
+    for i in 1..=5u64 {
+        consensus.add_utxo_valid_block_with_parents(i.into(), vec![*chain.last().unwrap()], vec![]).await.unwrap();
+        chain.push(i.into());
+    }
+    let side_block: Hash = 100.into();
+    consensus.add_utxo_valid_block_with_parents(side_block, vec![config.genesis.hash], vec![]).await.unwrap();
+
+    let mut anticone = consensus.get_anticone(side_block, None).unwrap();
+    anticone.sort();
+    assert_eq!(anticone, chain[1..].to_vec());
+    assert_eq!(consensus.get_anticone(chain[5], None).unwrap(), vec![side_block]);
+
+    // The traversal covers all the blocks of the anticone, hence a lower bound fails
+    assert_eq!(consensus.get_anticone(side_block, Some(5)).unwrap().len(), 5);
+    assert!(matches!(
+        consensus.get_anticone(side_block, Some(4)),
+        Err(ConsensusError::TraversalError(TraversalError::ReachedMaxTraversalAllowed(5, 4)))
+    ));
+
+    consensus.shutdown(wait_handles);
+}
+
+#[tokio::test]
+async fn daa_score_timestamp_estimates_test() {
+    let config = ConfigBuilder::new(DEVNET_PARAMS).skip_proof_of_work().build();
+    let consensus = TestConsensus::new(&config);
+    let wait_handles = consensus.init();
+
+    let mut chain = vec![config.genesis.hash];
+    for i in 1..=10u64 {
+        consensus.add_utxo_valid_block_with_parents(i.into(), vec![*chain.last().unwrap()], vec![]).await.unwrap();
+        chain.push(i.into());
+    }
+
+    // Each selected chain block maps its DAA score to its timestamp, and scores beyond the sink map to the sink.
+    // Genesis is skipped since it shares its DAA score with its child
+    let headers = chain.iter().map(|&hash| consensus.get_header(hash).unwrap()).skip(1).collect_vec();
+    let daa_scores = headers.iter().map(|header| header.daa_score).chain(std::iter::once(u64::MAX)).collect_vec();
+    let timestamps = headers.iter().map(|header| header.timestamp).chain(std::iter::once(headers[9].timestamp)).collect_vec();
+    assert_eq!(consensus.get_daa_score_timestamp_estimates(daa_scores), timestamps);
+
+    consensus.shutdown(wait_handles);
+}
+
 #[tokio::test]
 async fn difficulty_test() {
     async fn add_block(consensus: &TestConsensus, block_time: Option<u64>, parents: Vec<Hash>) -> Header {
@@ -1,9 +1,20 @@
+use async_trait::async_trait;
 use kaspa_addresses::Address;
-use kaspa_rpc_core::api::rpc::RpcApi;
+use kaspa_consensus_core::{subnets::SUBNETWORK_ID_NATIVE, tx::TransactionId};
+use kaspa_p2p_lib::{
+    common::ProtocolError,
+    convert::model::version::Version,
+    make_message,
+    pb::{kaspad_message::Payload, InvTransactionsMessage},
+    Adaptor, ConnectionInitializer, Hub, IncomingRoute, KaspadHandshake, KaspadMessagePayloadType, Router,
+};
+use kaspa_rpc_core::{api::rpc::RpcApi, GetConnectedPeerInfoRequest, RpcError, RpcTransaction};
 use kaspad::args::Args;
+use parking_lot::Mutex;
+use uuid::Uuid;
 
 use crate::common::daemon::Daemon;
-use std::time::Duration;
+use std::{sync::Arc, time::Duration};
 
 #[tokio::test]
 async fn daemon_sanity_test() {
@@ -49,4 +60,104 @@ async fn daemon_mining_test() {
     tokio::time::sleep(Duration::from_secs(1)).await;
     // Expect the blocks to be relayed to daemon #2
     assert_eq!(rpc_client2.get_block_dag_info().await.unwrap().block_count, 10);
+
+    // Expect the relay traffic to be reflected by the peer statistics of daemon #2
+    let peer_info = rpc_client2.get_connected_peer_info_call(GetConnectedPeerInfoRequest::new(true)).await.unwrap().peer_info;
+    let stats = peer_info[0].stats.as_ref().expect("stats were requested");
+    assert!(stats.messages_sent > 0 && stats.bytes_sent > 0);
+    assert!(stats.messages_received > 0 && stats.bytes_received > stats.messages_received);
+    assert_eq!(stats.invalid_messages, 0);
+    assert!(stats.relayed_blocks + stats.ibd_sessions > 0);
+    assert!(stats.message_types.iter().any(|x| x.message_type == "InvRelayBlock" && x.messages_received > 0));
+    assert!(rpc_client2.get_connected_peer_info().await.unwrap().peer_info[0].stats.is_none());
+}
+
+/// A bare P2P peer which performs the handshake and keeps the routes of the messages a node sends on its own initiative
+struct BarePeerInitializer {
+    network: String,
+    transaction_requests: Mutex<Option<IncomingRoute>>,
+    other_routes: Mutex<Vec<IncomingRoute>>,
+}
+
+impl BarePeerInitializer {
+    fn new(network: String) -> Self {
+        Self { network, transaction_requests: Mutex::new(None), other_routes: Mutex::new(vec![]) }
+    }
+}
+
+#[async_trait]
+impl ConnectionInitializer for BarePeerInitializer {
+    async fn initialize_connection(&self, router: Arc<Router>) -> Result<(), ProtocolError> {
+        let mut handshake = KaspadHandshake::new(&router);
+        router.start();
+        let version = Version::new(None, Uuid::new_v4().into(), self.network.clone(), None, 5);
+        handshake.handshake(version.into()).await?;
+        *self.transaction_requests.lock() = Some(router.subscribe(vec![KaspadMessagePayloadType::RequestTransactions]));
+        self.other_routes.lock().push(router.subscribe(vec![
+            KaspadMessagePayloadType::RequestAddresses,
+            KaspadMessagePayloadType::Ping,
+            KaspadMessagePayloadType::InvRelayBlock,
+            KaspadMessagePayloadType::InvTransactions,
+        ]));
+        handshake.exchange_ready_messages().await?;
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn daemon_blocks_only_test() {
+    kaspa_core::log::try_init_logger("INFO");
+
+    let args = Args { simnet: true, unsafe_rpc: true, enable_unsynced_mining: true, ..Default::default() };
+    let mut kaspad1 = Daemon::new_random_with_args(args.clone());
+    let mut kaspad2 = Daemon::new_random_with_args(Args { blocks_only: true, disable_rpc_submit: true, ..args });
+    let rpc_client1 = kaspad1.start().await;
+    let rpc_client2 = kaspad2.start().await;
+
+    // Mine a block to each daemon so that both are synced and thus relay transactions unless running in blocks-only mode
+    for (kaspad, rpc_client) in [(&kaspad1, &rpc_client1), (&kaspad2, &rpc_client2)] {
+        let template = rpc_client
+            .get_block_template(Address::new(kaspad.network.into(), kaspa_addresses::Version::PubKey, &[0; 32]), vec![])
+            .await
+            .unwrap();
+        rpc_client.submit_block(template.block, false).await.unwrap();
+    }
+
+    // Announce a transaction to each daemon from a bare peer and collect the resulting transaction requests
+    let mut transaction_requests = vec![];
+    let mut adaptors = vec![];
+    for kaspad in [&kaspad1, &kaspad2] {
+        let initializer = Arc::new(BarePeerInitializer::new(kaspad.network.to_prefixed()));
+        let adaptor = Adaptor::client_only(Hub::new(), initializer.clone());
+        let peer_key = adaptor.connect_peer(format!("127.0.0.1:{}", kaspad.p2p_port)).await.unwrap();
+        let inv =
+            make_message!(Payload::InvTransactions, InvTransactionsMessage { ids: vec![TransactionId::from_u64_word(1).into()] });
+        assert!(adaptor.send(peer_key, inv).await.unwrap());
+        transaction_requests.push(initializer.transaction_requests.lock().take().unwrap());
+        adaptors.push(adaptor);
+    }
+
+    // The regular daemon requests the announced transaction while the blocks-only one ignores the inv and keeps the connection
+    let mut requests2 = transaction_requests.pop().unwrap();
+    let mut requests1 = transaction_requests.pop().unwrap();
+    assert!(tokio::time::timeout(Duration::from_secs(2), requests1.recv()).await.unwrap().is_some());
+    assert!(tokio::time::timeout(Duration::from_secs(2), requests2.recv()).await.is_err());
+    for adaptor in adaptors {
+        adaptor.close().await;
+    }
+
+    // Transaction submission is rejected by the daemon with RPC submission disabled, before any validation
+    let transaction = RpcTransaction {
+        version: 0,
+        inputs: vec![],
+        outputs: vec![],
+        lock_time: 0,
+        subnetwork_id: SUBNETWORK_ID_NATIVE,
+        gas: 0,
+        payload: vec![],
+        verbose_data: None,
+    };
+    let disabled = RpcError::SubmitTransactionDisabled.to_string();
+    assert!(rpc_client2.submit_transaction(transaction.clone(), false).await.unwrap_err().to_string().contains(&disabled));
+    assert!(!rpc_client1.submit_transaction(transaction, false).await.unwrap_err().to_string().contains(&disabled));
 }
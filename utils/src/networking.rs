@@ -256,6 +256,30 @@ impl Display for NetAddress {
     }
 }
 
+#[cfg(not(target_arch = "wasm32"))]
+impl NetAddress {
+    /// Binds a non-blocking TCP listener on this address.
+    ///
+    /// IPv6 listeners are bound to IPv6 only, so that listening on both `[::]` and `0.0.0.0` on the same
+    /// port does not fail on systems where IPv6 sockets also claim the IPv4 port by default.
+    pub fn bind_tcp_listener(&self) -> std::io::Result<std::net::TcpListener> {
+        use socket2::{Domain, Protocol, Socket, Type};
+
+        let address = SocketAddr::from(*self);
+        let socket = Socket::new(Domain::for_address(address), Type::STREAM, Some(Protocol::TCP))?;
+        if address.is_ipv6() {
+            socket.set_only_v6(true)?;
+        }
+        // Same as `tokio::net::TcpListener::bind`, allow rebinding a port left in TIME_WAIT
+        #[cfg(not(windows))]
+        socket.set_reuse_address(true)?;
+        socket.bind(&address.into())?;
+        socket.listen(1024)?;
+        socket.set_nonblocking(true)?;
+        Ok(socket.into())
+    }
+}
+
 /// A network address possibly without explicit port.
 ///
 /// Use `normalize` to get a fully determined address.
@@ -572,4 +596,21 @@ mod tests {
         // Broadcast ip
         assert!(!IpAddress::from_str("255.255.255.255").unwrap().is_publicly_routable());
     }
+
+    #[test]
+    fn test_bind_tcp_listener_dual_stack() {
+        // Bind both families on the same port, in both orders
+        let listener_v6 = NetAddress::from_str("[::]:0").unwrap().bind_tcp_listener().unwrap();
+        let port = listener_v6.local_addr().unwrap().port();
+        let listener_v4 = NetAddress::new(Ipv4Addr::UNSPECIFIED.into(), port).bind_tcp_listener().unwrap();
+        assert_eq!(listener_v4.local_addr().unwrap().port(), port);
+
+        let listener_v4 = NetAddress::from_str("0.0.0.0:0").unwrap().bind_tcp_listener().unwrap();
+        let port = listener_v4.local_addr().unwrap().port();
+        let listener_v6 = NetAddress::new(Ipv6Addr::UNSPECIFIED.into(), port).bind_tcp_listener().unwrap();
+        assert_eq!(listener_v6.local_addr().unwrap().port(), port);
+
+        // A port already in use in the same family is still reported
+        assert!(NetAddress::new(Ipv6Addr::UNSPECIFIED.into(), port).bind_tcp_listener().is_err());
+    }
 }
@@ -154,6 +154,12 @@ impl Encrypted {
     }
 }
 
+impl AsRef<[u8]> for Encrypted {
+    fn as_ref(&self) -> &[u8] {
+        &self.payload
+    }
+}
+
 impl Serialize for Encrypted {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -154,6 +154,18 @@ pub enum Error {
     #[error("invalid key id: {0}")]
     KeyId(String),
 
+    #[error("address book entry '{0}' not found")]
+    AddressBookEntryNotFound(String),
+
+    #[error("address book entry '{0}' already exists")]
+    AddressBookEntryExists(String),
+
+    #[error("invalid address book alias '{0}' (aliases must not be empty, contain whitespace or be an address)")]
+    InvalidAddressBookAlias(String),
+
+    #[error("address book entry '{0}' does not belong to the network '{1}'")]
+    AddressBookNetworkMismatch(String, kaspa_consensus_core::network::NetworkId),
+
     #[error("wallet secret is required")]
     WalletSecretRequired,
 
@@ -178,6 +190,9 @@ pub enum Error {
     #[error("Receiving duplicate UTXO entry")]
     DuplicateUtxoEntry,
 
+    #[error("UTXO {0} is not available for spending (unknown, pending or already consumed)")]
+    UtxoNotAvailable(String),
+
     #[error("{0}")]
     ToValue(String),
 
@@ -190,6 +205,21 @@ pub enum Error {
     #[error("Not allowed on a resident account")]
     ResidentAccount,
 
+    #[error("Watch-only accounts can not sign transactions")]
+    WatchOnlyAccount,
+
+    #[error("invalid watch-only account: {0}")]
+    InvalidWatchOnlyAccount(String),
+
+    #[error("invalid wallet backup: {0}")]
+    InvalidWalletBackup(String),
+
+    #[error("wallet backup integrity check failed (the backup is corrupted)")]
+    WalletBackupChecksum,
+
+    #[error("invalid payment URI: {0}")]
+    InvalidPaymentUri(String),
+
     #[error("This feature is not supported by this account type")]
     AccountKindFeature,
 
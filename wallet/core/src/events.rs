@@ -1,5 +1,6 @@
 use crate::imports::*;
-use crate::runtime::Balance;
+use crate::runtime::{AccountId, Balance};
+use crate::storage::AddressBookEntry;
 use crate::storage::Hint;
 use crate::storage::TransactionRecord;
 use crate::utxo::context::UtxoContextId;
@@ -77,6 +78,14 @@ pub enum Events {
     WalletReload,
     /// Wallet has been closed
     WalletClose,
+    /// A new entry has been added to the wallet address book
+    AddressBookEntryAdd { entry: AddressBookEntry },
+    /// An existing address book entry has been modified.
+    /// `alias` contains the alias of the entry prior to
+    /// the modification (the entry may have been renamed).
+    AddressBookEntryUpdate { alias: String, entry: AddressBookEntry },
+    /// An entry has been removed from the wallet address book
+    AddressBookEntryRemove { entry: AddressBookEntry },
     /// Emitted after successful RPC connection
     /// after the initial state negotiation.
     ServerStatus {
@@ -127,6 +136,21 @@ pub enum Events {
     /// Emitted when a transaction has been created and broadcasted
     /// by the Transaction [`Generator`](crate::tx::generator::Generator)
     Outgoing { record: TransactionRecord },
+    /// Emitted by the account UTXO compounding policy
+    /// after small UTXOs have been consolidated into the
+    /// account change address.
+    Compound {
+        /// account id
+        id: AccountId,
+        /// number of account UTXOs consumed
+        utxos: usize,
+        /// value compounded into the change address
+        /// (consumed UTXO value less fees)
+        amount: u64,
+        fees: u64,
+        #[serde(rename = "transactionIds")]
+        transaction_ids: Vec<kaspa_hashes::Hash>,
+    },
     /// UtxoContext (Account) balance update. Emitted for each
     /// balance change within the UtxoContext.
     Balance {
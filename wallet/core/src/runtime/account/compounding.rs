@@ -0,0 +1,199 @@
+//!
+//! Automatic UTXO compounding policy. When enabled on an account, small
+//! mature UTXOs are periodically consolidated into the account change
+//! address so that later payments do not require chains of intermediate
+//! transactions.
+//!
+
+use crate::imports::*;
+use crate::result::Result;
+use crate::secret::Secret;
+use crate::utxo::{UtxoEntryId, UtxoEntryReference};
+
+/// Conditions under which the account UTXOs are compounded.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompoundingPolicy {
+    /// Compounding is triggered once the number of mature
+    /// (non-frozen) UTXOs exceeds this threshold
+    pub utxo_threshold: usize,
+    /// Only UTXOs with an amount at or below this value (in sompi)
+    /// are compounded; `None` selects all mature UTXOs
+    pub max_utxo_amount: Option<u64>,
+    /// Total amount of fees (in sompi) compounding is allowed
+    /// to spend while the policy is active
+    pub fee_budget: u64,
+    /// If set, compounding only takes place while the node
+    /// mempool holds at most this many transactions
+    /// (used as an indicator of a low-fee period)
+    pub max_mempool_size: Option<u64>,
+}
+
+impl CompoundingPolicy {
+    pub fn new(utxo_threshold: usize, max_utxo_amount: Option<u64>, fee_budget: u64, max_mempool_size: Option<u64>) -> Self {
+        Self { utxo_threshold, max_utxo_amount, fee_budget, max_mempool_size }
+    }
+}
+
+/// Runtime state of an active compounding policy. Holds the secrets
+/// required to sign compounding transactions in memory only.
+pub(crate) struct Compounding {
+    pub(crate) policy: CompoundingPolicy,
+    pub(crate) wallet_secret: Secret,
+    pub(crate) payment_secret: Option<Secret>,
+    /// fees spent so far against the policy fee budget
+    pub(crate) fees_spent: u64,
+    /// set while a compounding run is in progress
+    pub(crate) is_running: bool,
+}
+
+impl Compounding {
+    pub(crate) fn new(status: CompoundingStatus, wallet_secret: Secret, payment_secret: Option<Secret>) -> Self {
+        let CompoundingStatus { policy, fees_spent } = status;
+        Self { policy, wallet_secret, payment_secret, fees_spent, is_running: false }
+    }
+
+    pub(crate) fn remaining_budget(&self) -> u64 {
+        self.policy.fee_budget.saturating_sub(self.fees_spent)
+    }
+
+    pub(crate) fn status(&self) -> CompoundingStatus {
+        CompoundingStatus { policy: self.policy.clone(), fees_spent: self.fees_spent }
+    }
+}
+
+/// Compounding policy along with the fees spent against its budget.
+/// Persisted with the account settings.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompoundingStatus {
+    pub policy: CompoundingPolicy,
+    pub fees_spent: u64,
+}
+
+impl From<CompoundingPolicy> for CompoundingStatus {
+    fn from(policy: CompoundingPolicy) -> Self {
+        Self { policy, fees_spent: 0 }
+    }
+}
+
+/// Accounts for the transactions of a single compounding run,
+/// checking the fees of each one against the remaining budget.
+pub(crate) struct CompoundingRun {
+    budget: u64,
+    /// account UTXOs selected for compounding
+    selected: HashSet<UtxoEntryId>,
+    /// fees of the accepted transactions
+    pub(crate) fees: u64,
+    /// number of selected UTXOs consumed by the accepted transactions
+    pub(crate) utxos: usize,
+    /// value of the selected UTXOs consumed by the accepted transactions
+    consumed: u64,
+}
+
+impl CompoundingRun {
+    pub(crate) fn new(budget: u64, selected: &[UtxoEntryId]) -> Self {
+        Self { budget, selected: selected.iter().cloned().collect(), fees: 0, utxos: 0, consumed: 0 }
+    }
+
+    /// Returns `true` if a transaction with the given fees fits within the remaining budget
+    pub(crate) fn fits(&self, fees: u64) -> bool {
+        self.fees.saturating_add(fees) <= self.budget
+    }
+
+    pub(crate) fn remaining_budget(&self) -> u64 {
+        self.budget.saturating_sub(self.fees)
+    }
+
+    /// Accounts for a submitted transaction spending the given inputs.
+    /// Inputs created by earlier transactions of the run (intermediate
+    /// batch outputs) are not counted as compounded.
+    pub(crate) fn accept(&mut self, fees: u64, inputs: &[UtxoEntryReference]) {
+        self.fees += fees;
+        for input in inputs.iter().filter(|input| self.selected.contains(input.id_as_ref())) {
+            self.utxos += 1;
+            self.consumed += input.amount();
+        }
+    }
+
+    /// Value compounded into the change address (consumed UTXO value less fees)
+    pub(crate) fn amount(&self) -> u64 {
+        self.consumed.saturating_sub(self.fees)
+    }
+}
+
+/// Outcome of a compounding run. A run interrupted by an error still
+/// reports the fees and transactions submitted before the failure.
+pub struct CompoundingOutcome {
+    /// fees of the submitted transactions
+    pub fees: u64,
+    /// ids of the submitted transactions
+    pub transaction_ids: Vec<kaspa_hashes::Hash>,
+    pub result: Result<()>,
+}
+
+impl CompoundingOutcome {
+    /// Outcome of a run which did not submit any transaction
+    pub(crate) fn none(result: Result<()>) -> Self {
+        Self { fees: 0, transaction_ids: vec![], result }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::Settings;
+    use crate::utxo::test::utxo_entry;
+
+    #[test]
+    fn test_compounding_run_budget() {
+        let selected = (0..4).map(|seed| utxo_entry(seed, 1_000, 0)).collect::<Vec<_>>();
+        let ids = selected.iter().map(|entry| entry.id()).collect::<Vec<_>>();
+        let mut run = CompoundingRun::new(250, &ids);
+
+        assert!(run.fits(100));
+        run.accept(100, &selected[0..2]);
+        assert!(run.fits(150));
+        run.accept(150, &selected[2..3]);
+        // the next transaction would exceed the budget by a single sompi
+        assert!(!run.fits(1));
+        assert_eq!((run.fees, run.utxos), (250, 3));
+        assert_eq!(run.amount(), 3_000 - 250);
+
+        // a transaction fitting the budget exactly is accepted
+        let mut run = CompoundingRun::new(100, &ids);
+        assert!(run.fits(100));
+        run.accept(100, &selected);
+        assert_eq!(run.amount(), 4_000 - 100);
+    }
+
+    #[test]
+    fn test_compounding_run_amount() {
+        let selected = (0..3).map(|seed| utxo_entry(seed, 500, 0)).collect::<Vec<_>>();
+        let ids = selected.iter().map(|entry| entry.id()).collect::<Vec<_>>();
+        let mut run = CompoundingRun::new(u64::MAX, &ids);
+
+        // a batch transaction consolidating two of the selected UTXOs
+        let batch_output = utxo_entry(10, 1_000 - 20, 0);
+        run.accept(20, &selected[0..2]);
+        // the final transaction spends the batch output along with the remaining UTXO;
+        // the batch output must not be counted twice
+        run.accept(30, &[batch_output, selected[2].clone()]);
+        assert_eq!(run.utxos, 3);
+        assert_eq!(run.fees, 50);
+        assert_eq!(run.amount(), 1_500 - 50);
+    }
+
+    #[test]
+    fn test_compounding_settings_persistence() {
+        let status = CompoundingStatus { policy: CompoundingPolicy::new(10, Some(1_000), 5_000, None), fees_spent: 1_234 };
+        let settings = Settings { name: Some("account".to_string()), compounding: Some(status.clone()), ..Default::default() };
+        let json = serde_json::to_string(&settings).unwrap();
+        let settings: Settings = serde_json::from_str(&json).unwrap();
+        assert_eq!(settings.compounding, Some(status));
+
+        // settings stored without a compounding policy remain readable
+        let settings: Settings = serde_json::from_str(r#"{"is_visible":true,"name":"account"}"#).unwrap();
+        assert!(settings.compounding.is_none());
+    }
+}
@@ -69,6 +69,20 @@ impl AccountId {
         AccountId(Hash::from_slice(sha256_hash(&hashable.try_to_vec().unwrap()).as_ref()))
     }
 
+    pub(crate) fn from_watch_only(data: &storage::account::WatchOnly) -> AccountId {
+        let mut keys = data.xpub_keys.join(",").into_bytes();
+        keys.extend(data.minimum_signatures.to_le_bytes());
+        let hashable = AccountIdHashData {
+            account_kind: AccountKind::WatchOnly,
+            prv_key_data_id: None,
+            ecdsa: Some(data.ecdsa),
+            account_index: None,
+            secp256k1_public_key: None,
+            data: Some(keys),
+        };
+        AccountId(Hash::from_slice(sha256_hash(&hashable.try_to_vec().unwrap()).as_ref()))
+    }
+
     pub fn from_public_key(account_kind: AccountKind, public_key: &PublicKey) -> Self {
         let hashable = AccountIdHashData {
             account_kind,
@@ -1,7 +1,9 @@
+pub mod compounding;
 pub mod id;
 pub mod kind;
 pub mod variants;
 
+pub use compounding::{CompoundingPolicy, CompoundingStatus};
 pub use id::*;
 use kaspa_bip32::ChildNumber;
 pub use kind::*;
@@ -20,7 +22,7 @@ use crate::storage::interface::AccessContext;
 use crate::storage::Metadata;
 use crate::storage::{self, AccessContextT, AccountData, PrvKeyData, PrvKeyDataId};
 use crate::tx::{Fees, Generator, GeneratorSettings, GeneratorSummary, KeydataSigner, PaymentDestination, PendingTransaction, Signer};
-use crate::utxo::{UtxoContext, UtxoContextBinding};
+use crate::utxo::{UtxoContext, UtxoContextBinding, UtxoEntryId};
 use kaspa_consensus_wasm::UtxoEntryReference;
 use kaspa_notify::listener::ListenerId;
 use separator::Separatable;
@@ -43,6 +45,7 @@ pub struct Inner {
     id: AccountId,
     wallet: Arc<Wallet>,
     utxo_context: UtxoContext,
+    compounding: Mutex<Option<compounding::Compounding>>,
 }
 
 impl Inner {
@@ -50,7 +53,13 @@ impl Inner {
         let utxo_context = UtxoContext::new(wallet.utxo_processor(), UtxoContextBinding::AccountId(id));
 
         let context = Context { listener_id: None, settings };
-        Inner { context: Mutex::new(context), id, wallet: wallet.clone(), utxo_context: utxo_context.clone() }
+        Inner {
+            context: Mutex::new(context),
+            id,
+            wallet: wallet.clone(),
+            utxo_context: utxo_context.clone(),
+            compounding: Mutex::new(None),
+        }
     }
 }
 
@@ -60,20 +69,24 @@ pub async fn try_from_storage(
     meta: Option<Arc<storage::Metadata>>,
 ) -> Result<Arc<dyn Account>> {
     let storage::Account { prv_key_data_id, data, settings, .. } = (*stored_account).clone();
+    let frozen_utxos = meta.as_ref().map(|meta| meta.frozen_utxos.clone()).unwrap_or_default();
 
-    match data {
-        AccountData::Bip32(bip32) => Ok(Arc::new(Bip32::try_new(wallet, prv_key_data_id.unwrap(), settings, bip32, meta).await?)),
-        AccountData::Legacy(legacy) => Ok(Arc::new(Legacy::try_new(wallet, prv_key_data_id.unwrap(), settings, legacy, meta).await?)),
+    let account: Arc<dyn Account> = match data {
+        AccountData::Bip32(bip32) => Arc::new(Bip32::try_new(wallet, prv_key_data_id.unwrap(), settings, bip32, meta).await?),
+        AccountData::Legacy(legacy) => Arc::new(Legacy::try_new(wallet, prv_key_data_id.unwrap(), settings, legacy, meta).await?),
         AccountData::MultiSig(multisig) => {
-            Ok(Arc::new(MultiSig::try_new(wallet, prv_key_data_id.unwrap(), settings, multisig, meta).await?))
-        }
-        AccountData::Keypair(keypair) => {
-            Ok(Arc::new(Keypair::try_new(wallet, prv_key_data_id.unwrap(), settings, keypair, meta).await?))
+            Arc::new(MultiSig::try_new(wallet, prv_key_data_id.unwrap(), settings, multisig, meta).await?)
         }
+        AccountData::Keypair(keypair) => Arc::new(Keypair::try_new(wallet, prv_key_data_id.unwrap(), settings, keypair, meta).await?),
         AccountData::Hardware(_hardware) => {
             todo!()
         }
-    }
+        AccountData::WatchOnly(watch_only) => Arc::new(WatchOnly::try_new(wallet, settings, watch_only, meta).await?),
+    };
+
+    account.utxo_context().freeze(&frozen_utxos);
+
+    Ok(account)
 }
 
 #[async_trait]
@@ -244,6 +257,10 @@ pub trait Account: AnySync + Send + Sync + 'static {
             }
         }
 
+        // a full scan retrieves all the UTXOs of the account
+        if extent.is_none() {
+            self.utxo_context().prune_frozen();
+        }
         self.utxo_context().update_balance().await?;
 
         Ok(())
@@ -375,6 +392,304 @@ pub trait Account: AnySync + Send + Sync + 'static {
         Ok(generator.summary())
     }
 
+    /// Freezes (or unfreezes) the supplied UTXOs, excluding them from automatic
+    /// selection during transaction generation. The frozen set is persisted
+    /// in the account metadata.
+    async fn freeze_utxos(&self, utxos: &[UtxoEntryId], freeze: bool) -> Result<()> {
+        // accounts without metadata can not persist the frozen set
+        let metadata = self.metadata()?.ok_or(Error::AccountKindFeature)?;
+        let store = self.wallet().store().as_account_store()?;
+
+        let utxo_context = self.utxo_context();
+        let changed = utxos.iter().filter(|id| utxo_context.is_frozen(id) != freeze).cloned().collect::<Vec<_>>();
+        let apply = |freeze: bool| if freeze { utxo_context.freeze(&changed) } else { utxo_context.unfreeze(&changed) };
+
+        apply(freeze);
+        let metadata = metadata.with_frozen_utxos(utxo_context.frozen_utxos());
+        if let Err(err) = store.update_metadata(&[&metadata]).await {
+            // keep the frozen set in line with the persisted one
+            apply(!freeze);
+            return Err(err);
+        }
+        Ok(())
+    }
+
+    /// Persists the frozen UTXOs in the account metadata if some of them were spent
+    /// or reorged out since last persisted
+    async fn store_pruned_frozen_utxos(&self) -> Result<()> {
+        if self.utxo_context().take_frozen_pruned() {
+            if let Some(metadata) = self.metadata()? {
+                self.wallet().store().as_account_store()?.update_metadata(&[&metadata]).await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Sends funds spending only the supplied UTXOs (manual coin selection)
+    #[allow(clippy::too_many_arguments)]
+    async fn send_with_utxos(
+        self: Arc<Self>,
+        utxos: &[UtxoEntryId],
+        destination: PaymentDestination,
+        priority_fee_sompi: Fees,
+        payload: Option<Vec<u8>>,
+        wallet_secret: Secret,
+        payment_secret: Option<Secret>,
+        abortable: &Abortable,
+        notifier: Option<GenerationNotifier>,
+    ) -> Result<(GeneratorSummary, Vec<kaspa_hashes::Hash>)> {
+        let keydata = self.prv_key_data(wallet_secret).await?;
+        let signer = Arc::new(Signer::new(self.clone().as_dyn_arc(), keydata, payment_secret));
+
+        let settings =
+            GeneratorSettings::try_new_with_account_utxos(self.clone().as_dyn_arc(), utxos, destination, priority_fee_sompi, payload)?;
+
+        let generator = Generator::try_new(settings, Some(signer), Some(abortable))?;
+
+        let mut stream = generator.stream();
+        let mut ids = vec![];
+        while let Some(transaction) = stream.try_next().await? {
+            if let Some(notifier) = notifier.as_ref() {
+                notifier(&transaction);
+            }
+
+            transaction.try_sign()?;
+            transaction.log().await?;
+            let id = transaction.try_submit(self.wallet().rpc()).await?;
+            ids.push(id);
+            yield_executor().await;
+        }
+
+        Ok((generator.summary(), ids))
+    }
+
+    /// Enables automatic UTXO compounding. The supplied secrets are
+    /// verified and retained in memory (never stored) for as long as the
+    /// policy is active, as compounding transactions need to be signed.
+    /// The policy itself is persisted with the account settings.
+    async fn enable_compounding(
+        &self,
+        policy: CompoundingPolicy,
+        wallet_secret: Secret,
+        payment_secret: Option<Secret>,
+    ) -> Result<()> {
+        self.activate_compounding(policy.into(), wallet_secret, payment_secret).await
+    }
+
+    /// Re-activates the compounding policy persisted with the account
+    /// settings, carrying over the fees already spent against its budget.
+    async fn resume_compounding(&self, wallet_secret: Secret, payment_secret: Option<Secret>) -> Result<()> {
+        let status = self.stored_compounding_status().ok_or_else(|| Error::custom("no UTXO compounding policy is configured"))?;
+        self.activate_compounding(status, wallet_secret, payment_secret).await
+    }
+
+    async fn activate_compounding(
+        &self,
+        status: CompoundingStatus,
+        wallet_secret: Secret,
+        payment_secret: Option<Secret>,
+    ) -> Result<()> {
+        let keydata = self.prv_key_data(wallet_secret.clone()).await?;
+        keydata.payload.decrypt(payment_secret.as_ref())?;
+        self.store_compounding_status(Some(status.clone()), wallet_secret.clone()).await?;
+        *self.inner().compounding.lock().unwrap() = Some(compounding::Compounding::new(status, wallet_secret, payment_secret));
+        Ok(())
+    }
+
+    async fn disable_compounding(&self, wallet_secret: Secret) -> Result<()> {
+        self.store_compounding_status(None, wallet_secret).await?;
+        self.inner().compounding.lock().unwrap().take();
+        Ok(())
+    }
+
+    /// Deactivates the compounding policy for the current session,
+    /// keeping it stored with the account settings
+    fn suspend_compounding(&self) {
+        self.inner().compounding.lock().unwrap().take();
+    }
+
+    /// Status of the active compounding policy
+    fn compounding_status(&self) -> Option<CompoundingStatus> {
+        self.inner().compounding.lock().unwrap().as_ref().map(|compounding| compounding.status())
+    }
+
+    /// Status of the compounding policy persisted with the account
+    /// settings (which may not be active in the current session)
+    fn stored_compounding_status(&self) -> Option<CompoundingStatus> {
+        self.context().settings.as_ref().and_then(|settings| settings.compounding.clone())
+    }
+
+    async fn store_compounding_status(&self, status: Option<CompoundingStatus>, wallet_secret: Secret) -> Result<()> {
+        self.context().settings.get_or_insert_with(Default::default).compounding = status;
+
+        let account = self.as_storable()?;
+        self.wallet().store().as_account_store()?.store_single(&account, None).await?;
+
+        let ctx: Arc<dyn AccessContextT> = Arc::new(AccessContext::new(wallet_secret));
+        self.wallet().store().commit(&ctx).await?;
+        Ok(())
+    }
+
+    /// Applies the compounding policy (if enabled), consolidating small mature
+    /// UTXOs into the change address. Posts [`Events::Compound`] once some
+    /// transactions were submitted, even if the run is interrupted by an error.
+    async fn compound(self: Arc<Self>) -> Result<()> {
+        let (policy, wallet_secret, payment_secret, remaining_budget) = {
+            let mut compounding = self.inner().compounding.lock().unwrap();
+            let Some(compounding) = compounding.as_mut() else {
+                return Ok(());
+            };
+            if compounding.is_running {
+                return Ok(());
+            }
+            compounding.is_running = true;
+            (
+                compounding.policy.clone(),
+                compounding.wallet_secret.clone(),
+                compounding.payment_secret.clone(),
+                compounding.remaining_budget(),
+            )
+        };
+
+        let outcome = self.clone().compound_with_policy(&policy, wallet_secret.clone(), payment_secret, remaining_budget).await;
+
+        // the fees of the submitted transactions are spent regardless of the run result
+        // (a policy suspended during the run due to a secret change is not persisted,
+        // as the retained wallet secret may no longer be valid)
+        let status = self.inner().compounding.lock().unwrap().as_mut().and_then(|compounding| {
+            compounding.is_running = false;
+            compounding.fees_spent += outcome.fees;
+            (!outcome.transaction_ids.is_empty()).then(|| compounding.status())
+        });
+
+        // persist the fees spent against the budget
+        if let Some(status) = status {
+            self.store_compounding_status(Some(status), wallet_secret).await?;
+        }
+
+        outcome.result
+    }
+
+    /// Executes a single compounding run, reporting the fees spent and the
+    /// transactions submitted along with the error interrupting the run (if any).
+    /// Nothing is submitted if the policy conditions are not met.
+    async fn compound_with_policy(
+        self: Arc<Self>,
+        policy: &CompoundingPolicy,
+        wallet_secret: Secret,
+        payment_secret: Option<Secret>,
+        remaining_budget: u64,
+    ) -> compounding::CompoundingOutcome {
+        let utxos = match self.compounding_utxos(policy).await {
+            Ok(Some(utxos)) => utxos,
+            Ok(None) => return compounding::CompoundingOutcome::none(Ok(())),
+            Err(err) => return compounding::CompoundingOutcome::none(Err(err)),
+        };
+
+        let mut run = compounding::CompoundingRun::new(remaining_budget, &utxos);
+        let mut transaction_ids = vec![];
+        let mut result = async {
+            let abortable = Abortable::default();
+            let keydata = self.prv_key_data(wallet_secret).await?;
+            let signer = Arc::new(Signer::new(self.clone().as_dyn_arc(), keydata, payment_secret));
+            let settings = GeneratorSettings::try_new_with_account_utxos(
+                self.clone().as_dyn_arc(),
+                &utxos,
+                PaymentDestination::Change,
+                Fees::None,
+                None,
+            )?;
+            let generator = Generator::try_new(settings, Some(signer), Some(&abortable))?;
+
+            let mut stream = generator.stream();
+            while let Some(transaction) = stream.try_next().await? {
+                // each generated transaction is checked against the budget before it is submitted
+                if !run.fits(transaction.fees()) {
+                    log_info!(
+                        "account {}: stopping UTXO compounding, transaction fees {} exceed the remaining fee budget {}",
+                        self.id().short(),
+                        transaction.fees(),
+                        run.remaining_budget()
+                    );
+                    break;
+                }
+
+                transaction.try_sign()?;
+                transaction.log().await?;
+                transaction_ids.push(transaction.try_submit(self.wallet().rpc()).await?);
+                run.accept(transaction.fees(), transaction.utxo_entries());
+                yield_executor().await;
+            }
+
+            Ok(())
+        }
+        .await;
+
+        if !transaction_ids.is_empty() {
+            let event = Events::Compound {
+                id: *self.id(),
+                utxos: run.utxos,
+                amount: run.amount(),
+                fees: run.fees,
+                transaction_ids: transaction_ids.clone(),
+            };
+            result = result.and(self.wallet().notify(event).await);
+        }
+
+        compounding::CompoundingOutcome { fees: run.fees, transaction_ids, result }
+    }
+
+    /// Selects the UTXOs to compound, or `None` if the policy conditions are not met
+    async fn compounding_utxos(&self, policy: &CompoundingPolicy) -> Result<Option<Vec<UtxoEntryId>>> {
+        let utxo_context = self.utxo_context();
+        let mut utxos =
+            utxo_context.mature_utxos().into_iter().filter(|utxo| !utxo_context.is_frozen(utxo.id_as_ref())).collect::<Vec<_>>();
+        if utxos.len() <= policy.utxo_threshold {
+            return Ok(None);
+        }
+
+        if let Some(max_utxo_amount) = policy.max_utxo_amount {
+            utxos.retain(|utxo| utxo.amount() <= max_utxo_amount);
+        }
+        if utxos.len() < 2 {
+            return Ok(None);
+        }
+
+        if let Some(max_mempool_size) = policy.max_mempool_size {
+            let info = self.wallet().rpc().get_info().await?;
+            if info.mempool_size > max_mempool_size {
+                return Ok(None);
+            }
+        }
+
+        utxos.sort_by_key(|utxo| utxo.amount());
+        Ok(Some(utxos.iter().map(|utxo| utxo.id()).collect()))
+    }
+
+    /// Generates transactions without signing or submitting them. The
+    /// resulting transactions carry the UTXO entries they spend and
+    /// can be handed over to an external signer.
+    async fn create_unsigned_transactions(
+        self: Arc<Self>,
+        destination: PaymentDestination,
+        priority_fee_sompi: Fees,
+        payload: Option<Vec<u8>>,
+        abortable: &Abortable,
+    ) -> Result<(GeneratorSummary, Vec<PendingTransaction>)> {
+        let settings = GeneratorSettings::try_new_with_account(self.as_dyn_arc(), destination, priority_fee_sompi, payload)?;
+
+        let generator = Generator::try_new(settings, None, Some(abortable))?;
+
+        let mut stream = generator.stream();
+        let mut transactions = vec![];
+        while let Some(transaction) = stream.try_next().await? {
+            transactions.push(transaction);
+            yield_executor().await;
+        }
+
+        Ok((generator.summary(), transactions))
+    }
+
     fn as_derivation_capable(self: Arc<Self>) -> Result<Arc<dyn DerivationCapableAccount>> {
         Err(Error::AccountAddressDerivationCaps)
     }
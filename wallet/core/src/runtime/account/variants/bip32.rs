@@ -79,7 +79,8 @@ impl Account for Bip32 {
     }
 
     fn metadata(&self) -> Result<Option<Metadata>> {
-        let metadata = Metadata::new(self.inner.id, self.derivation.address_derivation_meta());
+        let metadata = Metadata::new(self.inner.id, self.derivation.address_derivation_meta())
+            .with_frozen_utxos(self.utxo_context().frozen_utxos());
         Ok(Some(metadata))
     }
 
@@ -0,0 +1,221 @@
+use crate::derivation::AddressDerivationManager;
+use crate::imports::*;
+use crate::result::Result;
+use crate::runtime::account::Inner;
+use crate::runtime::account::{Account, AccountId, AccountKind, DerivationCapableAccount, GenerationNotifier};
+use crate::runtime::Wallet;
+use crate::secret::Secret;
+use crate::storage::{self, Metadata, PrvKeyDataId, Settings};
+use crate::tx::{Fees, GeneratorSummary, PaymentDestination};
+use crate::AddressDerivationManagerTrait;
+use kaspa_bip32::ExtendedPublicKey;
+use workflow_core::abortable::Abortable;
+
+/// Account created from one or more extended public keys. Tracks balances
+/// and transaction history like any other account, but has no access to
+/// private keys and as such can only produce unsigned transactions.
+pub struct WatchOnly {
+    inner: Arc<Inner>,
+    xpub_keys: Arc<Vec<String>>,
+    minimum_signatures: u16,
+    ecdsa: bool,
+    derivation: Arc<AddressDerivationManager>,
+}
+
+impl WatchOnly {
+    pub async fn try_new(
+        wallet: &Arc<Wallet>,
+        settings: Settings,
+        data: storage::account::WatchOnly,
+        meta: Option<Arc<Metadata>>,
+    ) -> Result<Self> {
+        Self::validate(&data)?;
+
+        let id = AccountId::from_watch_only(&data);
+        let inner = Arc::new(Inner::new(wallet, id, Some(settings)));
+
+        let storage::account::WatchOnly { xpub_keys, minimum_signatures, ecdsa } = data;
+
+        let address_derivation_indexes = meta.and_then(|meta| meta.address_derivation_indexes()).unwrap_or_default();
+
+        // a single key is derived as a regular bip32 account while
+        // multiple keys are derived as multisig cosigners
+        let (account_kind, cosigner_index) = if xpub_keys.len() > 1 {
+            (AccountKind::MultiSig, Some(Self::cosigner_index(&xpub_keys)))
+        } else {
+            (AccountKind::Bip32, None)
+        };
+
+        let derivation = AddressDerivationManager::new(
+            wallet,
+            account_kind,
+            &xpub_keys,
+            ecdsa,
+            0,
+            cosigner_index,
+            minimum_signatures,
+            address_derivation_indexes,
+        )
+        .await?;
+
+        Ok(Self { inner, xpub_keys, minimum_signatures, ecdsa, derivation })
+    }
+
+    /// Position of the first (own) key within the lexicographically sorted key set (BIP45 cosigner ordering)
+    fn cosigner_index(xpub_keys: &[String]) -> u32 {
+        let Some(own) = xpub_keys.first() else {
+            return 0;
+        };
+        xpub_keys.iter().filter(|xpub| *xpub < own).count() as u32
+    }
+
+    pub fn validate(data: &storage::account::WatchOnly) -> Result<()> {
+        if data.xpub_keys.is_empty() {
+            return Err(Error::InvalidWatchOnlyAccount("at least one extended public key is required".to_string()));
+        }
+
+        for xpub in data.xpub_keys.iter() {
+            ExtendedPublicKey::<secp256k1::PublicKey>::from_str(xpub)
+                .map_err(|err| Error::InvalidWatchOnlyAccount(format!("invalid extended public key '{xpub}': {err}")))?;
+        }
+
+        if data.minimum_signatures == 0 || data.minimum_signatures as usize > data.xpub_keys.len() {
+            return Err(Error::InvalidWatchOnlyAccount(format!(
+                "minimum signatures ({}) must be between 1 and the number of keys ({})",
+                data.minimum_signatures,
+                data.xpub_keys.len()
+            )));
+        }
+
+        Ok(())
+    }
+
+    pub fn xpub_keys(&self) -> &Arc<Vec<String>> {
+        &self.xpub_keys
+    }
+}
+
+#[async_trait]
+impl Account for WatchOnly {
+    fn inner(&self) -> &Arc<Inner> {
+        &self.inner
+    }
+
+    fn account_kind(&self) -> AccountKind {
+        AccountKind::WatchOnly
+    }
+
+    fn prv_key_data_id(&self) -> Result<&PrvKeyDataId> {
+        Err(Error::WatchOnlyAccount)
+    }
+
+    fn as_dyn_arc(self: Arc<Self>) -> Arc<dyn Account> {
+        self
+    }
+
+    fn sig_op_count(&self) -> u8 {
+        self.xpub_keys.len() as u8
+    }
+
+    fn minimum_signatures(&self) -> u16 {
+        self.minimum_signatures
+    }
+
+    fn receive_address(&self) -> Result<Address> {
+        self.derivation.receive_address_manager().current_address()
+    }
+
+    fn change_address(&self) -> Result<Address> {
+        self.derivation.change_address_manager().current_address()
+    }
+
+    fn as_storable(&self) -> Result<storage::account::Account> {
+        let settings = self.context().settings.clone().unwrap_or_default();
+        let watch_only =
+            storage::WatchOnly { xpub_keys: self.xpub_keys.clone(), minimum_signatures: self.minimum_signatures, ecdsa: self.ecdsa };
+        let account = storage::Account::new(*self.id(), None, settings, storage::AccountData::WatchOnly(watch_only));
+        Ok(account)
+    }
+
+    fn metadata(&self) -> Result<Option<Metadata>> {
+        let metadata = Metadata::new(self.inner.id, self.derivation.address_derivation_meta())
+            .with_frozen_utxos(self.utxo_context().frozen_utxos());
+        Ok(Some(metadata))
+    }
+
+    async fn sweep(
+        self: Arc<Self>,
+        _wallet_secret: Secret,
+        _payment_secret: Option<Secret>,
+        _abortable: &Abortable,
+        _notifier: Option<GenerationNotifier>,
+    ) -> Result<(GeneratorSummary, Vec<kaspa_hashes::Hash>)> {
+        Err(Error::WatchOnlyAccount)
+    }
+
+    async fn send(
+        self: Arc<Self>,
+        _destination: PaymentDestination,
+        _priority_fee_sompi: Fees,
+        _payload: Option<Vec<u8>>,
+        _wallet_secret: Secret,
+        _payment_secret: Option<Secret>,
+        _abortable: &Abortable,
+        _notifier: Option<GenerationNotifier>,
+    ) -> Result<(GeneratorSummary, Vec<kaspa_hashes::Hash>)> {
+        Err(Error::WatchOnlyAccount)
+    }
+
+    fn as_derivation_capable(self: Arc<Self>) -> Result<Arc<dyn DerivationCapableAccount>> {
+        Ok(self.clone())
+    }
+}
+
+impl DerivationCapableAccount for WatchOnly {
+    fn derivation(&self) -> Arc<dyn AddressDerivationManagerTrait> {
+        self.derivation.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kaspa_bip32::{ChildNumber, ExtendedPrivateKey, Prefix, SecretKey};
+
+    fn xpub_keys(count: u32) -> Vec<String> {
+        let xprv = ExtendedPrivateKey::<SecretKey>::from_str(
+            "kprv5y2qurMHCsXYrNfU3GCihuwG3vMqFji7PZXajMEqyBkNh9UZUJgoHYBLTKu1eM4MvUtomcXPQ3Sw9HZ5ebbM4byoUciHo1zrPJBQfqpLorQ",
+        )
+        .unwrap();
+        (0..count)
+            .map(|index| xprv.derive_child(ChildNumber::new(index, true).unwrap()).unwrap().public_key().to_string(Some(Prefix::KPUB)))
+            .collect()
+    }
+
+    #[test]
+    fn test_watch_only_cosigner_index() {
+        let keys = ["c", "a", "b"].map(String::from);
+        assert_eq!(WatchOnly::cosigner_index(&keys), 2);
+        assert_eq!(WatchOnly::cosigner_index(&keys[1..]), 0);
+        assert_eq!(WatchOnly::cosigner_index(&["b", "a", "c"].map(String::from)), 1);
+        assert_eq!(WatchOnly::cosigner_index(&[]), 0);
+    }
+
+    #[test]
+    fn test_watch_only_validate() {
+        let data = |xpub_keys: Vec<String>, minimum_signatures: u16| storage::account::WatchOnly {
+            xpub_keys: Arc::new(xpub_keys),
+            minimum_signatures,
+            ecdsa: false,
+        };
+
+        assert!(WatchOnly::validate(&data(xpub_keys(1), 1)).is_ok());
+        assert!(WatchOnly::validate(&data(xpub_keys(3), 2)).is_ok());
+        assert!(WatchOnly::validate(&data(xpub_keys(3), 3)).is_ok());
+
+        assert!(WatchOnly::validate(&data(vec![], 1)).is_err());
+        assert!(WatchOnly::validate(&data(vec!["kpub-invalid".to_string()], 1)).is_err());
+        assert!(WatchOnly::validate(&data(xpub_keys(3), 0)).is_err());
+        assert!(WatchOnly::validate(&data(xpub_keys(3), 4)).is_err());
+    }
+}
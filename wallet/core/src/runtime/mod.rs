@@ -4,7 +4,7 @@ pub mod maps;
 pub mod sync;
 pub mod wallet;
 
-pub use account::{try_from_storage, Account, AccountId, AccountKind, Bip32, Keypair, Legacy, MultiSig};
+pub use account::{try_from_storage, Account, AccountId, AccountKind, Bip32, Keypair, Legacy, MultiSig, WatchOnly};
 pub use balance::{AtomicBalance, Balance, BalanceStrings};
 pub use maps::ActiveAccountMap;
 pub use sync::SyncMonitor;
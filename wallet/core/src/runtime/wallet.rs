@@ -3,10 +3,15 @@ use crate::result::Result;
 use crate::runtime::{try_from_storage, Account, AccountId, ActiveAccountMap};
 use crate::secret::Secret;
 use crate::settings::{SettingsStore, WalletSettings};
+use crate::storage::export::format_transaction_export;
 use crate::storage::interface::{AccessContext, CreateArgs, OpenArgs};
 use crate::storage::local::interface::LocalStore;
 use crate::storage::local::Storage;
-use crate::storage::{self, AccessContextT, AccountKind, Hint, Interface, PrvKeyData, PrvKeyDataId, PrvKeyDataInfo};
+use crate::storage::{
+    self, AccessContextT, AccountBackup, AccountKind, AddressBookEntry, Backup, BackupData, Binding, Encryptable, Hint, Interface,
+    PrvKeyData, PrvKeyDataId, PrvKeyDataInfo, TransactionExport, TransactionExportFormat, TransactionExportRange,
+    TransactionExportRecord,
+};
 use crate::utxo::UtxoProcessor;
 #[allow(unused_imports)]
 use crate::{derivation::gen0, derivation::gen0::import::*, derivation::gen1, derivation::gen1::import::*};
@@ -265,6 +270,59 @@ impl Wallet {
         self.inner.store.as_prv_key_data_store()?.load_key_data(&ctx, id).await
     }
 
+    /// Re-encrypts the wallet under a new wallet secret. Active compounding policies
+    /// (which retain the wallet secret in memory) are suspended in the process.
+    pub async fn change_wallet_secret(&self, wallet_secret: Secret, new_wallet_secret: Secret) -> Result<()> {
+        let ctx: Arc<dyn AccessContextT> = Arc::new(AccessContext::new(wallet_secret));
+        let new_ctx: Arc<dyn AccessContextT> = Arc::new(AccessContext::new(new_wallet_secret));
+        self.inner.store.change_secret(&ctx, &new_ctx).await?;
+
+        for account in self.active_accounts().collect() {
+            if account.compounding_status().is_some() {
+                log_info!("account {}: suspending UTXO compounding due to the wallet password change", account.id().short());
+                account.suspend_compounding();
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Adds, replaces or removes (if `new_payment_secret` is `None`)
+    /// the payment secret protecting the private key data. Active compounding
+    /// policies of the accounts derived from this key data (which retain the
+    /// payment secret in memory) are suspended in the process.
+    pub async fn change_payment_secret(
+        &self,
+        wallet_secret: Secret,
+        prv_key_data_id: &PrvKeyDataId,
+        payment_secret: Option<Secret>,
+        new_payment_secret: Option<Secret>,
+    ) -> Result<()> {
+        let ctx: Arc<dyn AccessContextT> = Arc::new(AccessContext::new(wallet_secret));
+        let prv_key_data_store = self.inner.store.as_prv_key_data_store()?;
+        let mut prv_key_data = prv_key_data_store
+            .load_key_data(&ctx, prv_key_data_id)
+            .await?
+            .ok_or_else(|| Error::PrivateKeyNotFound(prv_key_data_id.to_hex()))?;
+
+        let payload = prv_key_data.payload.decrypt(payment_secret.as_ref())?;
+        prv_key_data.payload = match new_payment_secret {
+            Some(new_payment_secret) => Encryptable::XChaCha20Poly1305(payload.encrypt(&new_payment_secret)?),
+            None => Encryptable::Plain(payload.as_ref().clone()),
+        };
+
+        self.inner.store.update_prv_key_data(&ctx, prv_key_data).await?;
+
+        for account in self.active_accounts().collect() {
+            if account.prv_key_data_id().ok() == Some(prv_key_data_id) && account.compounding_status().is_some() {
+                log_info!("account {}: suspending UTXO compounding due to the payment password change", account.id().short());
+                account.suspend_compounding();
+            }
+        }
+
+        Ok(())
+    }
+
     pub async fn get_prv_key_info(&self, account: &Arc<dyn Account>) -> Result<Option<Arc<PrvKeyDataInfo>>> {
         self.inner.store.as_prv_key_data_store()?.load_key_info(account.prv_key_data_id()?).await
     }
@@ -423,7 +481,7 @@ impl Wallet {
 
         let bip32 = storage::Bip32 { account_index, xpub_keys, ecdsa: false };
 
-        let settings = storage::Settings { is_visible: false, name: None, title: None };
+        let settings = storage::Settings { is_visible: false, name: None, title: None, compounding: None };
         let account: Arc<dyn Account> = Arc::new(runtime::Bip32::try_new(self, prv_key_data.id, settings, bip32, None).await?);
         let stored_account = account.as_storable()?;
 
@@ -434,6 +492,32 @@ impl Wallet {
         Ok(account)
     }
 
+    /// Creates a watch-only account from one or more extended public keys.
+    /// Multiple keys produce a multisig account requiring `minimum_signatures`.
+    pub async fn create_watch_only_account(
+        self: &Arc<Wallet>,
+        wallet_secret: Secret,
+        xpub_keys: Vec<String>,
+        minimum_signatures: u16,
+        name: Option<String>,
+    ) -> Result<Arc<dyn Account>> {
+        let watch_only = storage::WatchOnly { xpub_keys: Arc::new(xpub_keys), minimum_signatures, ecdsa: false };
+        let settings = storage::Settings { is_visible: false, name, title: None, compounding: None };
+        let account: Arc<dyn Account> = Arc::new(runtime::WatchOnly::try_new(self, settings, watch_only, None).await?);
+        let stored_account = account.as_storable()?;
+
+        let account_storage = self.inner.store.clone().as_account_store()?;
+        if account_storage.load_single(account.id()).await?.is_some() {
+            return Err(Error::Custom(format!("account {} already exists", account.id())));
+        }
+        account_storage.store_single(&stored_account, account.metadata()?.as_ref()).await?;
+        let ctx: Arc<dyn AccessContextT> = Arc::new(AccessContext::new(wallet_secret));
+        self.inner.store.clone().commit(&ctx).await?;
+        account.clone().start().await?;
+
+        Ok(account)
+    }
+
     pub async fn create_wallet(self: &Arc<Wallet>, args: WalletCreateArgs) -> Result<Option<String>> {
         self.reset().await?;
         let ctx: Arc<dyn AccessContextT> = Arc::new(AccessContext::new(args.wallet_secret.clone()));
@@ -480,7 +564,7 @@ impl Wallet {
 
         let bip32 = storage::Bip32 { account_index, xpub_keys, ecdsa: false };
 
-        let settings = storage::Settings { is_visible: false, name: None, title: None };
+        let settings = storage::Settings { is_visible: false, name: None, title: None, compounding: None };
         let account: Arc<dyn Account> = Arc::new(runtime::Bip32::try_new(self, prv_key_data.id, settings, bip32, None).await?);
         let stored_account = account.as_storable()?;
 
@@ -546,6 +630,21 @@ impl Wallet {
                     self.reload().await?;
                 }
             }
+            Events::Balance { id, .. } => {
+                let account = self.active_accounts().collect().into_iter().find(|account| account.utxo_context().id() == *id);
+                if let Some(account) = account.as_ref() {
+                    account.store_pruned_frozen_utxos().await?;
+                }
+
+                // apply UTXO compounding policies (if any) in the background
+                if let Some(account) = account.filter(|account| account.compounding_status().is_some()) {
+                    spawn(async move {
+                        if let Err(err) = account.clone().compound().await {
+                            log_error!("account {}: UTXO compounding error: {err}", account.id().short());
+                        }
+                    });
+                }
+            }
             _ => {}
         }
 
@@ -618,6 +717,236 @@ impl Wallet {
         Ok(matches)
     }
 
+    pub async fn address_book(&self) -> Result<impl Stream<Item = Result<Arc<AddressBookEntry>>>> {
+        self.inner.store.as_address_book_store()?.iter().await
+    }
+
+    pub async fn search_address_book(&self, search: &str) -> Result<Vec<Arc<AddressBookEntry>>> {
+        self.inner.store.as_address_book_store()?.search(search).await
+    }
+
+    pub async fn get_address_book_entry(&self, alias: &str) -> Result<Option<Arc<AddressBookEntry>>> {
+        self.inner.store.as_address_book_store()?.load_single(alias).await
+    }
+
+    /// Resolves an address book alias into an address, ensuring
+    /// that the entry is usable on the currently selected network.
+    pub async fn resolve_address_book_alias(&self, alias: &str) -> Result<Address> {
+        let entry = self.get_address_book_entry(alias).await?.ok_or_else(|| Error::AddressBookEntryNotFound(alias.to_string()))?;
+        let network_id = self.network_id()?;
+        if !entry.is_valid_for(&network_id) {
+            return Err(Error::AddressBookNetworkMismatch(alias.to_string(), network_id));
+        }
+        Ok(entry.address.clone())
+    }
+
+    pub async fn add_address_book_entry(&self, wallet_secret: Secret, entry: AddressBookEntry) -> Result<()> {
+        entry.validate()?;
+        let store = self.inner.store.as_address_book_store()?;
+        if store.load_single(&entry.alias).await?.is_some() {
+            return Err(Error::AddressBookEntryExists(entry.alias));
+        }
+        store.store_single(&entry).await?;
+        let ctx: Arc<dyn AccessContextT> = Arc::new(AccessContext::new(wallet_secret));
+        self.inner.store.commit(&ctx).await?;
+        self.notify(Events::AddressBookEntryAdd { entry }).await?;
+        Ok(())
+    }
+
+    /// Replaces the entry identified by `alias` with `entry`.
+    /// The entry can be renamed by supplying a different alias.
+    pub async fn update_address_book_entry(&self, wallet_secret: Secret, alias: &str, entry: AddressBookEntry) -> Result<()> {
+        entry.validate()?;
+        let store = self.inner.store.as_address_book_store()?;
+        if store.load_single(alias).await?.is_none() {
+            return Err(Error::AddressBookEntryNotFound(alias.to_string()));
+        }
+        if entry.alias != alias {
+            if store.load_single(&entry.alias).await?.is_some() {
+                return Err(Error::AddressBookEntryExists(entry.alias));
+            }
+            store.remove(&[alias]).await?;
+        }
+        store.store_single(&entry).await?;
+        let ctx: Arc<dyn AccessContextT> = Arc::new(AccessContext::new(wallet_secret));
+        self.inner.store.commit(&ctx).await?;
+        self.notify(Events::AddressBookEntryUpdate { alias: alias.to_string(), entry }).await?;
+        Ok(())
+    }
+
+    pub async fn remove_address_book_entry(&self, wallet_secret: Secret, alias: &str) -> Result<()> {
+        let store = self.inner.store.as_address_book_store()?;
+        let entry = store.load_single(alias).await?.ok_or_else(|| Error::AddressBookEntryNotFound(alias.to_string()))?;
+        store.remove(&[alias]).await?;
+        let ctx: Arc<dyn AccessContextT> = Arc::new(AccessContext::new(wallet_secret));
+        self.inner.store.commit(&ctx).await?;
+        self.notify(Events::AddressBookEntryRemove { entry: (*entry).clone() }).await?;
+        Ok(())
+    }
+
+    /// Exports the transaction history of the account on the given network.
+    /// Records that do not carry a timestamp are dated by the connected node
+    /// from their DAA score (only possible when exporting the history of the
+    /// currently selected network). Records that remain undated are exported
+    /// when the date range is unbounded and reported as skipped otherwise.
+    pub async fn export_transaction_history(
+        &self,
+        account: &Arc<dyn Account>,
+        network_id: &NetworkId,
+        range: &TransactionExportRange,
+        format: TransactionExportFormat,
+    ) -> Result<TransactionExport> {
+        let binding = Binding::from(account);
+        let store = self.inner.store.as_transaction_record_store()?;
+        let ids = match store.transaction_id_iter(&binding, network_id).await {
+            Ok(ids) => ids.try_collect::<Vec<_>>().await?,
+            Err(Error::NoRecordsFound) => vec![],
+            Err(err) => return Err(err),
+        };
+        let ids = ids.iter().map(|id| **id).collect::<Vec<_>>();
+        let mut transactions = store.load_multiple(&binding, network_id, &ids).await?;
+        transactions.sort_by_key(|record| record.block_daa_score());
+
+        let is_current_network = self.network_id().ok().as_ref() == Some(network_id);
+        let current_daa_score = self.current_daa_score().filter(|_| is_current_network);
+        let timestamps = if is_current_network && self.is_connected() {
+            let daa_scores = transactions.iter().filter(|record| record.unixtime().is_none()).map(|record| record.block_daa_score());
+            self.estimate_daa_score_timestamps(daa_scores.collect()).await
+        } else {
+            HashMap::new()
+        };
+        let account_name = account.name();
+
+        let mut skipped = 0;
+        let records = transactions
+            .iter()
+            .filter_map(|record| {
+                let unixtime = record.unixtime().or_else(|| timestamps.get(&record.block_daa_score()).copied());
+                if range.contains(unixtime) {
+                    Some(TransactionExportRecord::new(record, account_name.clone(), unixtime, current_daa_score))
+                } else {
+                    skipped += usize::from(unixtime.is_none());
+                    None
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let text = format_transaction_export(&records, format)?;
+        Ok(TransactionExport { text, exported: records.len(), skipped })
+    }
+
+    /// Queries the connected node for timestamp estimates of the given DAA scores.
+    /// Scores the node could not date (e.g. due to an RPC failure) are left out.
+    async fn estimate_daa_score_timestamps(&self, mut daa_scores: Vec<u64>) -> HashMap<u64, u64> {
+        const CHUNK_SIZE: usize = 1_000;
+
+        daa_scores.sort_unstable();
+        daa_scores.dedup();
+
+        let mut timestamps = HashMap::with_capacity(daa_scores.len());
+        for chunk in daa_scores.chunks(CHUNK_SIZE) {
+            match self.rpc().get_daa_score_timestamp_estimate(chunk.to_vec()).await {
+                Ok(estimates) => timestamps.extend(chunk.iter().copied().zip(estimates)),
+                Err(err) => {
+                    log_warning!("unable to estimate transaction timestamps: {err}");
+                    break;
+                }
+            }
+        }
+        timestamps
+    }
+
+    /// Creates an encrypted backup of the currently open wallet. The backup
+    /// is encrypted with the wallet secret, which is verified in the process.
+    pub async fn create_backup(&self, wallet_secret: Secret) -> Result<Backup> {
+        let ctx: Arc<dyn AccessContextT> = Arc::new(AccessContext::new(wallet_secret.clone()));
+        let store = self.store();
+
+        let mut data = BackupData { user_hint: store.get_user_hint().await?, ..Default::default() };
+
+        let prv_key_data_store = store.as_prv_key_data_store()?;
+        let infos = prv_key_data_store.iter().await?.try_collect::<Vec<_>>().await?;
+        for info in infos {
+            let prv_key_data =
+                prv_key_data_store.load_key_data(&ctx, &info.id).await?.ok_or_else(|| Error::PrivateKeyNotFound(info.id.to_hex()))?;
+            data.prv_key_data.push(prv_key_data);
+        }
+
+        let accounts = store.as_account_store()?.iter(None).await?.try_collect::<Vec<_>>().await?;
+        let transaction_record_store = store.as_transaction_record_store()?;
+        for (account, metadata) in accounts {
+            let binding = Binding::Account(account.id);
+            for network_id in NetworkId::iter() {
+                let ids = match transaction_record_store.transaction_id_iter(&binding, &network_id).await {
+                    Ok(ids) => ids.try_collect::<Vec<_>>().await?,
+                    Err(Error::NoRecordsFound) => continue,
+                    Err(err) => return Err(err),
+                };
+                let ids = ids.iter().map(|id| **id).collect::<Vec<_>>();
+                let transactions = transaction_record_store.load_multiple(&binding, &network_id, &ids).await?;
+                data.transactions.extend(transactions.iter().map(|record| (**record).clone()));
+            }
+            data.accounts.push(AccountBackup { account: (*account).clone(), metadata: metadata.map(|metadata| (*metadata).clone()) });
+        }
+
+        data.address_book = store
+            .as_address_book_store()?
+            .iter()
+            .await?
+            .try_collect::<Vec<_>>()
+            .await?
+            .iter()
+            .map(|entry| (**entry).clone())
+            .collect();
+
+        Backup::try_new(data, &wallet_secret)
+    }
+
+    /// Restores a wallet backup as a new wallet named `name`. The backup is
+    /// decrypted using `backup_secret`; if `wallet_secret` is supplied, the
+    /// restored wallet is re-encrypted with it. The restored wallet is left open.
+    pub async fn restore_backup(
+        self: &Arc<Self>,
+        backup: &Backup,
+        backup_secret: Secret,
+        name: Option<String>,
+        wallet_secret: Option<Secret>,
+        overwrite_wallet_storage: bool,
+    ) -> Result<Option<String>> {
+        let data = backup.try_decrypt(&backup_secret)?;
+        let wallet_secret = wallet_secret.unwrap_or(backup_secret);
+
+        let ctx: Arc<dyn AccessContextT> = Arc::new(AccessContext::new(wallet_secret));
+        let store = self.store();
+        // The currently open wallet is only torn down once the storage of the restored one could be created
+        store.create(&ctx, CreateArgs::new(name, data.user_hint.clone(), overwrite_wallet_storage)).await?;
+        self.reset().await?;
+        let descriptor = store.descriptor()?;
+
+        let prv_key_data_store = store.as_prv_key_data_store()?;
+        for prv_key_data in data.prv_key_data.iter() {
+            prv_key_data_store.store(&ctx, prv_key_data.clone()).await?;
+        }
+
+        let accounts = data.accounts.iter().map(|backup| (&backup.account, backup.metadata.as_ref())).collect::<Vec<_>>();
+        store.as_account_store()?.store_multiple(&accounts).await?;
+
+        let address_book_store = store.as_address_book_store()?;
+        for entry in data.address_book.iter() {
+            address_book_store.store_single(entry).await?;
+        }
+
+        let transactions = data.transactions.iter().collect::<Vec<_>>();
+        store.as_transaction_record_store()?.store(&transactions).await?;
+
+        store.commit(&ctx).await?;
+
+        self.notify(Events::WalletHint { hint: data.user_hint.clone() }).await?;
+        self.notify(Events::WalletOpen).await?;
+
+        Ok(descriptor)
+    }
+
     pub async fn accounts(self: &Arc<Self>, filter: Option<PrvKeyDataId>) -> Result<impl Stream<Item = Result<Arc<dyn Account>>>> {
         let iter = self.inner.store.as_account_store().unwrap().iter(filter).await.unwrap();
         let wallet = self.clone();
@@ -909,4 +1238,109 @@ mod test {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_address_book() -> Result<()> {
+        let wallet = Wallet::try_new(Wallet::resident_store()?, None)?;
+        let wallet_secret = Secret::from("wallet-secret");
+        let ctx: Arc<dyn AccessContextT> = Arc::new(AccessContext::new(wallet_secret.clone()));
+        wallet.store().create(&ctx, CreateArgs::new(None, None, false)).await?;
+        let events = wallet.multiplexer().channel();
+        let next_event = || events.receiver.try_recv().expect("missing address book event");
+
+        let network_id = NetworkId::with_suffix(NetworkType::Testnet, 11);
+        let address = Address::new(Prefix::Testnet, Version::PubKey, &[1; 32]);
+        let entry =
+            |alias: &str, address: &Address| AddressBookEntry::try_new(alias, "Title", address.clone(), vec![], Some(network_id));
+
+        wallet.add_address_book_entry(wallet_secret.clone(), entry("alice", &address)?).await?;
+        assert!(matches!(next_event(), Events::AddressBookEntryAdd { entry } if entry.alias == "alice"));
+        assert_eq!(wallet.get_address_book_entry("alice").await?.unwrap().address, address);
+
+        // duplicate aliases are rejected
+        let result = wallet.add_address_book_entry(wallet_secret.clone(), entry("alice", &address)?).await;
+        assert!(matches!(result, Err(Error::AddressBookEntryExists(alias)) if alias == "alice"));
+
+        // entries carrying an address of another network than the one they are tagged with are rejected
+        let mainnet_address = Address::new(Prefix::Mainnet, Version::PubKey, &[2; 32]);
+        let result = wallet.add_address_book_entry(wallet_secret.clone(), entry("bob", &mainnet_address)?).await;
+        assert!(matches!(result, Err(Error::AddressBookNetworkMismatch(alias, _)) if alias == "bob"));
+        let result = wallet.update_address_book_entry(wallet_secret.clone(), "alice", entry("alice", &mainnet_address)?).await;
+        assert!(matches!(result, Err(Error::AddressBookNetworkMismatch(..))));
+        assert!(wallet.get_address_book_entry("bob").await?.is_none());
+        assert_eq!(wallet.get_address_book_entry("alice").await?.unwrap().address, address);
+
+        // updating an entry under another alias renames it, unless the alias is taken
+        wallet.add_address_book_entry(wallet_secret.clone(), entry("bob", &address)?).await?;
+        assert!(matches!(next_event(), Events::AddressBookEntryAdd { entry } if entry.alias == "bob"));
+        let result = wallet.update_address_book_entry(wallet_secret.clone(), "alice", entry("bob", &address)?).await;
+        assert!(matches!(result, Err(Error::AddressBookEntryExists(alias)) if alias == "bob"));
+        wallet.update_address_book_entry(wallet_secret.clone(), "alice", entry("carol", &address)?).await?;
+        assert!(matches!(next_event(), Events::AddressBookEntryUpdate { alias, entry } if alias == "alice" && entry.alias == "carol"));
+        assert!(wallet.get_address_book_entry("alice").await?.is_none());
+        assert!(wallet.get_address_book_entry("carol").await?.is_some());
+        let result = wallet.update_address_book_entry(wallet_secret.clone(), "alice", entry("alice", &address)?).await;
+        assert!(matches!(result, Err(Error::AddressBookEntryNotFound(alias)) if alias == "alice"));
+
+        wallet.remove_address_book_entry(wallet_secret.clone(), "carol").await?;
+        assert!(matches!(next_event(), Events::AddressBookEntryRemove { entry } if entry.alias == "carol"));
+        assert!(wallet.get_address_book_entry("carol").await?.is_none());
+        assert!(matches!(wallet.remove_address_book_entry(wallet_secret, "carol").await, Err(Error::AddressBookEntryNotFound(_))));
+
+        // failed operations do not emit events
+        assert!(events.receiver.try_recv().is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_change_payment_secret() -> Result<()> {
+        let wallet = Wallet::try_new(Wallet::resident_store()?, None)?;
+        let wallet_secret = Secret::from("wallet-secret");
+        let ctx: Arc<dyn AccessContextT> = Arc::new(AccessContext::new(wallet_secret.clone()));
+        wallet.store().create(&ctx, CreateArgs::new(None, None, false)).await?;
+
+        let (payment_secret, new_payment_secret) = (Secret::from("payment-secret"), Secret::from("new-payment-secret"));
+        let phrase = "fiber boy desk trip pitch snake table awkward endorse car learn forest solid ticket enemy pink gesture wealth iron chaos clock gather honey farm";
+        let mnemonic = Mnemonic::new(phrase, Language::English)?;
+        let prv_key_data = PrvKeyData::try_new_from_mnemonic(mnemonic.clone(), Some(&payment_secret))?;
+        let prv_key_data_id = prv_key_data.id;
+        wallet.store().as_prv_key_data_store()?.store(&ctx, prv_key_data).await?;
+
+        let load = || async {
+            let prv_key_data = wallet.store().as_prv_key_data_store()?.load_key_data(&ctx, &prv_key_data_id).await?;
+            Result::<PrvKeyData>::Ok(prv_key_data.expect("missing private key data"))
+        };
+
+        // an invalid payment secret leaves the key data encrypted with the current one
+        let result = wallet
+            .change_payment_secret(
+                wallet_secret.clone(),
+                &prv_key_data_id,
+                Some(Secret::from("invalid")),
+                Some(new_payment_secret.clone()),
+            )
+            .await;
+        assert!(result.is_err());
+        assert!(load().await?.payload.decrypt(Some(&payment_secret)).is_ok());
+
+        wallet
+            .change_payment_secret(
+                wallet_secret.clone(),
+                &prv_key_data_id,
+                Some(payment_secret.clone()),
+                Some(new_payment_secret.clone()),
+            )
+            .await?;
+        let prv_key_data = load().await?;
+        assert!(prv_key_data.payload.decrypt(Some(&payment_secret)).is_err());
+        let payload = prv_key_data.payload.decrypt(Some(&new_payment_secret))?;
+        assert_eq!(payload.as_ref().as_mnemonic()?.unwrap().phrase_string(), mnemonic.phrase_string());
+
+        // removing the payment secret leaves the key data unencrypted
+        wallet.change_payment_secret(wallet_secret, &prv_key_data_id, Some(new_payment_secret), None).await?;
+        assert!(load().await?.payload.decrypt(None).is_ok());
+
+        Ok(())
+    }
 }
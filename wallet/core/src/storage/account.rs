@@ -1,4 +1,5 @@
 use crate::imports::*;
+use crate::runtime::account::CompoundingStatus;
 use crate::storage::{AccountId, AccountKind, PrvKeyDataId};
 use secp256k1::PublicKey;
 
@@ -10,6 +11,8 @@ pub struct Settings {
     pub name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub compounding: Option<CompoundingStatus>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -47,6 +50,17 @@ pub struct Keypair {
 #[serde(rename_all = "lowercase")]
 pub struct Hardware {}
 
+/// Account tracking addresses derived from one (single signature)
+/// or more (multisig) extended public keys without access to any
+/// private key data.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub struct WatchOnly {
+    pub xpub_keys: Arc<Vec<String>>,
+    pub minimum_signatures: u16,
+    pub ecdsa: bool,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type")]
 #[serde(rename_all = "lowercase")]
@@ -56,6 +70,7 @@ pub enum AccountData {
     MultiSig(MultiSig),
     Keypair(Keypair),
     Hardware(Hardware),
+    WatchOnly(WatchOnly),
 }
 
 impl AccountData {
@@ -66,6 +81,7 @@ impl AccountData {
             AccountData::MultiSig { .. } => AccountKind::MultiSig,
             AccountData::Hardware { .. } => AccountKind::Hardware,
             AccountData::Keypair { .. } => AccountKind::Keypair,
+            AccountData::WatchOnly { .. } => AccountKind::WatchOnly,
         }
     }
 }
@@ -1,8 +1,60 @@
 use crate::imports::*;
+use crate::result::Result;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AddressBookEntry {
     pub alias: String,
     pub title: String,
     pub address: Address,
+    #[serde(default)]
+    pub labels: Vec<String>,
+    #[serde(default)]
+    pub network_id: Option<NetworkId>,
+}
+
+impl AddressBookEntry {
+    pub fn try_new(alias: &str, title: &str, address: Address, labels: Vec<String>, network_id: Option<NetworkId>) -> Result<Self> {
+        Self::validate_alias(alias)?;
+        Ok(Self { alias: alias.to_string(), title: title.to_string(), address, labels, network_id })
+    }
+
+    /// Aliases are used to refer to entries from the command line,
+    /// as such they can not be empty, contain whitespace or be
+    /// mistaken for an address.
+    pub fn validate_alias(alias: &str) -> Result<()> {
+        if alias.is_empty() || alias.chars().any(char::is_whitespace) || Address::try_from(alias).is_ok() {
+            Err(Error::InvalidAddressBookAlias(alias.to_string()))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Validates the alias and ensures that an entry tagged
+    /// with a network id carries an address of that network.
+    pub fn validate(&self) -> Result<()> {
+        Self::validate_alias(&self.alias)?;
+        match self.network_id.as_ref() {
+            Some(network_id) if !self.is_valid_for(network_id) => {
+                Err(Error::AddressBookNetworkMismatch(self.alias.clone(), *network_id))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Case-insensitive match against the alias, title, address and labels
+    pub fn matches(&self, search: &str) -> bool {
+        let search = search.to_lowercase();
+        self.alias.to_lowercase().contains(&search)
+            || self.title.to_lowercase().contains(&search)
+            || self.address.to_string().contains(&search)
+            || self.labels.iter().any(|label| label.to_lowercase().contains(&search))
+    }
+
+    /// Returns `true` if the entry is usable on the given network.
+    /// Entries without a network id are accepted on any network
+    /// matching the address prefix.
+    pub fn is_valid_for(&self, network_id: &NetworkId) -> bool {
+        let prefix: Prefix = network_id.network_type().into();
+        self.address.prefix == prefix && self.network_id.as_ref().map(|id| id == network_id).unwrap_or(true)
+    }
 }
@@ -0,0 +1,121 @@
+//!
+//! Encrypted full-wallet backup. The backup contains private key data, accounts
+//! (with their metadata and address derivation indexes), the address book and
+//! the transaction history (including transaction notes) of all networks.
+//!
+
+use crate::encryption::sha256_hash;
+use crate::imports::*;
+use crate::result::Result;
+use crate::secret::Secret;
+use crate::storage::{Account, AddressBookEntry, Decrypted, Encrypted, Hint, Metadata, PrvKeyData, TransactionRecord};
+use zeroize::Zeroize;
+
+pub const WALLET_BACKUP_VERSION: [u16; 3] = [0, 0, 1];
+const WALLET_BACKUP_MAGIC: &str = "kaspa-wallet-backup";
+
+/// Account as stored in the wallet along with its metadata
+#[derive(Clone, Serialize, Deserialize)]
+pub struct AccountBackup {
+    pub account: Account,
+    pub metadata: Option<Metadata>,
+}
+
+/// Wallet data contained in the backup (always stored encrypted)
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupData {
+    pub user_hint: Option<Hint>,
+    pub prv_key_data: Vec<PrvKeyData>,
+    pub accounts: Vec<AccountBackup>,
+    pub address_book: Vec<AddressBookEntry>,
+    pub transactions: Vec<TransactionRecord>,
+}
+
+impl Zeroize for BackupData {
+    fn zeroize(&mut self) {
+        self.prv_key_data.zeroize();
+    }
+}
+
+/// Backup file envelope. The checksum covers the encrypted payload, allowing
+/// a corrupted backup to be detected (and distinguished from an invalid
+/// password) before decryption. The payload itself is authenticated by
+/// XChaCha20-Poly1305.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Backup {
+    magic: String,
+    pub version: [u16; 3],
+    /// backup creation time (unix time in milliseconds)
+    pub timestamp: u64,
+    checksum: String,
+    payload: Encrypted,
+}
+
+impl Backup {
+    pub fn try_new(data: BackupData, secret: &Secret) -> Result<Self> {
+        let payload = Decrypted::new(data).encrypt(secret)?;
+        let checksum = sha256_hash(payload.as_ref()).as_ref().to_hex();
+        let timestamp = workflow_core::time::unixtime_as_millis_f64() as u64;
+        Ok(Self { magic: WALLET_BACKUP_MAGIC.to_string(), version: WALLET_BACKUP_VERSION, timestamp, checksum, payload })
+    }
+
+    /// Verifies the backup integrity and decrypts its contents
+    pub fn try_decrypt(&self, secret: &Secret) -> Result<Decrypted<BackupData>> {
+        self.verify()?;
+        self.payload.decrypt::<BackupData>(secret)
+    }
+
+    pub fn verify(&self) -> Result<()> {
+        if self.magic != WALLET_BACKUP_MAGIC {
+            return Err(Error::InvalidWalletBackup("not a wallet backup".to_string()));
+        }
+        if self.version[0] > WALLET_BACKUP_VERSION[0] {
+            return Err(Error::InvalidWalletBackup(format!("unsupported backup version {:?}", self.version)));
+        }
+        if sha256_hash(self.payload.as_ref()).as_ref().to_hex() != self.checksum {
+            return Err(Error::WalletBackupChecksum);
+        }
+        Ok(())
+    }
+
+    pub fn try_to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    pub fn try_from_json(json: &str) -> Result<Self> {
+        let backup = serde_json::from_str::<Backup>(json).map_err(|err| Error::InvalidWalletBackup(err.to_string()))?;
+        backup.verify()?;
+        Ok(backup)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wallet_backup_roundtrip() -> Result<()> {
+        let secret = Secret::new(b"backup-secret".to_vec());
+        let address = Address::try_from("kaspatest:qz7ulu4c25dh7fzec9zjyrmlhnkzrg4wmf89q7gzr3gfrsj3uz6xjceef60sd")?;
+        let entry = AddressBookEntry::try_new("alice", "Alice", address, vec!["friends".to_string()], None)?;
+        let data = BackupData { user_hint: Some(Hint::from("hint")), address_book: vec![entry], ..Default::default() };
+
+        let json = Backup::try_new(data, &secret)?.try_to_json()?;
+        let backup = Backup::try_from_json(&json)?;
+        let restored = backup.try_decrypt(&secret)?;
+        assert_eq!(restored.address_book.len(), 1);
+        assert_eq!(restored.address_book[0].alias, "alice");
+        assert_eq!(restored.user_hint.as_ref().map(|hint| hint.text.as_str()), Some("hint"));
+
+        assert!(backup.try_decrypt(&Secret::new(b"invalid".to_vec())).is_err());
+
+        let mut tampered = backup.clone();
+        let mut payload = tampered.payload.as_ref().to_vec();
+        payload[0] ^= 0xff;
+        tampered.payload = Encrypted::new(payload);
+        assert!(matches!(tampered.try_decrypt(&secret), Err(Error::WalletBackupChecksum)));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,324 @@
+//!
+//! Transaction history export (CSV and JSON) for accounting and reconciliation.
+//!
+
+use crate::imports::*;
+use crate::result::Result;
+use crate::storage::transaction::{TransactionData, TransactionMetadata, TransactionRecord, TransactionType};
+use kaspa_txscript::extract_script_pub_key_address;
+
+const MILLIS_PER_DAY: u64 = 86_400_000;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum TransactionExportFormat {
+    Csv,
+    Json,
+}
+
+impl FromStr for TransactionExportFormat {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "csv" => Ok(TransactionExportFormat::Csv),
+            "json" => Ok(TransactionExportFormat::Json),
+            _ => Err(Error::custom(format!("invalid export format '{s}', must be 'csv' or 'json'"))),
+        }
+    }
+}
+
+/// Inclusive time range (unix time in milliseconds) used to filter exported transactions.
+#[derive(Debug, Clone, Default)]
+pub struct TransactionExportRange {
+    pub start: Option<u64>,
+    pub end: Option<u64>,
+}
+
+impl TransactionExportRange {
+    pub fn new(start: Option<u64>, end: Option<u64>) -> Self {
+        Self { start, end }
+    }
+
+    /// Creates a range from dates in the `YYYY-MM-DD` format (UTC) or unix
+    /// timestamps in milliseconds. The end date is inclusive of the entire day.
+    pub fn try_from_dates(start: Option<&str>, end: Option<&str>) -> Result<Self> {
+        let start = start.map(|start| parse_date(start, false)).transpose()?;
+        let end = end.map(|end| parse_date(end, true)).transpose()?;
+        Ok(Self { start, end })
+    }
+
+    pub fn contains(&self, timestamp: Option<u64>) -> bool {
+        match timestamp {
+            Some(timestamp) => {
+                self.start.map(|start| timestamp >= start).unwrap_or(true) && self.end.map(|end| timestamp <= end).unwrap_or(true)
+            }
+            // records without a timestamp can only match an unbounded range
+            None => self.start.is_none() && self.end.is_none(),
+        }
+    }
+}
+
+/// Result of a transaction history export
+#[derive(Debug, Clone)]
+pub struct TransactionExport {
+    /// export contents in the requested format
+    pub text: String,
+    /// number of exported records
+    pub exported: usize,
+    /// number of records left out since no timestamp was available
+    /// to match them against the bounds of the date range
+    pub skipped: usize,
+}
+
+/// Flattened representation of a [`TransactionRecord`] as it appears in the export.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionExportRecord {
+    pub id: TransactionId,
+    pub account_id: String,
+    pub account_name: Option<String>,
+    pub network: NetworkId,
+    #[serde(rename = "type")]
+    pub transaction_type: TransactionType,
+    /// unix time in milliseconds
+    pub timestamp: Option<u64>,
+    /// ISO 8601 (UTC) representation of the timestamp
+    pub date: Option<String>,
+    pub block_daa_score: u64,
+    /// value in sompi
+    pub value: u64,
+    /// fees in sompi (outgoing transactions only)
+    pub fees: Option<u64>,
+    /// payment destinations of outgoing transactions
+    pub counterparties: Vec<String>,
+    /// account addresses that received funds
+    pub addresses: Vec<String>,
+    pub is_coinbase: bool,
+    /// `pending` or `confirmed`, if the current DAA score is known
+    pub state: Option<String>,
+    pub note: Option<String>,
+}
+
+impl TransactionExportRecord {
+    pub fn new(
+        record: &TransactionRecord,
+        account_name: Option<String>,
+        timestamp: Option<u64>,
+        current_daa_score: Option<u64>,
+    ) -> Self {
+        let prefix = Prefix::from(record.network_id().network_type());
+
+        let (counterparties, addresses) = match record.transaction_data() {
+            TransactionData::Reorg { utxo_entries, .. }
+            | TransactionData::Incoming { utxo_entries, .. }
+            | TransactionData::External { utxo_entries, .. } => {
+                let mut addresses = utxo_entries
+                    .iter()
+                    .filter_map(|utxo| utxo.address.as_ref().map(|address| address.to_string()))
+                    .collect::<Vec<_>>();
+                addresses.dedup();
+                (vec![], addresses)
+            }
+            TransactionData::Outgoing { transaction, payment_value, change_value, .. } => {
+                // the change output (if any) is always the last transaction output
+                let outputs = if payment_value.is_none() {
+                    &[][..]
+                } else if *change_value > 0 {
+                    &transaction.outputs[..transaction.outputs.len().saturating_sub(1)]
+                } else {
+                    &transaction.outputs[..]
+                };
+                let counterparties = outputs
+                    .iter()
+                    .filter_map(|output| extract_script_pub_key_address(&output.script_public_key, prefix).ok())
+                    .map(|address| address.to_string())
+                    .collect::<Vec<_>>();
+                (counterparties, vec![])
+            }
+        };
+
+        let note = record.metadata().map(|metadata| match metadata {
+            TransactionMetadata::Custom(note) => note.clone(),
+        });
+
+        Self {
+            id: *record.id(),
+            account_id: record.binding().to_hex(),
+            account_name,
+            network: *record.network_id(),
+            transaction_type: record.transaction_type(),
+            timestamp,
+            date: timestamp.map(unixtime_to_iso8601),
+            block_daa_score: record.block_daa_score(),
+            value: record.value(),
+            fees: record.fees(),
+            counterparties,
+            addresses,
+            is_coinbase: record.is_coinbase(),
+            state: current_daa_score
+                .map(|score| if record.is_mature(score) { "confirmed".to_string() } else { "pending".to_string() }),
+            note,
+        }
+    }
+}
+
+/// Renders exported records in the requested format. Amounts are expressed in sompi.
+pub fn format_transaction_export(records: &[TransactionExportRecord], format: TransactionExportFormat) -> Result<String> {
+    match format {
+        TransactionExportFormat::Json => Ok(serde_json::to_string_pretty(records)?),
+        TransactionExportFormat::Csv => {
+            let mut lines = vec![[
+                "id",
+                "accountId",
+                "accountName",
+                "network",
+                "type",
+                "timestamp",
+                "date",
+                "blockDaaScore",
+                "value",
+                "fees",
+                "counterparties",
+                "addresses",
+                "isCoinbase",
+                "state",
+                "note",
+            ]
+            .join(",")];
+
+            for record in records {
+                let fields = [
+                    record.id.to_string(),
+                    record.account_id.clone(),
+                    record.account_name.clone().unwrap_or_default(),
+                    record.network.to_string(),
+                    record.transaction_type.to_string(),
+                    record.timestamp.map(|timestamp| timestamp.to_string()).unwrap_or_default(),
+                    record.date.clone().unwrap_or_default(),
+                    record.block_daa_score.to_string(),
+                    record.value.to_string(),
+                    record.fees.map(|fees| fees.to_string()).unwrap_or_default(),
+                    record.counterparties.join(" "),
+                    record.addresses.join(" "),
+                    record.is_coinbase.to_string(),
+                    record.state.clone().unwrap_or_default(),
+                    record.note.clone().unwrap_or_default(),
+                ];
+                lines.push(fields.iter().map(|field| csv_escape(field)).collect::<Vec<_>>().join(","));
+            }
+
+            Ok(lines.join("\n") + "\n")
+        }
+    }
+}
+
+fn csv_escape(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Parses a `YYYY-MM-DD` date (UTC) or a unix timestamp in milliseconds.
+/// If `end_of_day` is set, the last millisecond of the given day is returned.
+fn parse_date(s: &str, end_of_day: bool) -> Result<u64> {
+    if let Ok(timestamp) = s.parse::<u64>() {
+        return Ok(timestamp);
+    }
+
+    let invalid = || Error::custom(format!("invalid date '{s}', expecting YYYY-MM-DD or unix time in milliseconds"));
+    let parts = s.split('-').map(|part| part.parse::<u64>().map_err(|_| invalid())).collect::<Result<Vec<_>>>()?;
+    let [year, month, day] = parts[..] else {
+        return Err(invalid());
+    };
+    if !(1970..=9999).contains(&year) || !(1..=12).contains(&month) || !(1..=days_in_month(year, month)).contains(&day) {
+        return Err(invalid());
+    }
+
+    let start = days_from_civil(year, month, day) * MILLIS_PER_DAY;
+    Ok(if end_of_day { start + MILLIS_PER_DAY - 1 } else { start })
+}
+
+/// Formats unix time in milliseconds as an ISO 8601 UTC date and time
+fn unixtime_to_iso8601(unixtime: u64) -> String {
+    let (year, month, day) = civil_from_days(unixtime / MILLIS_PER_DAY);
+    let millis = unixtime % MILLIS_PER_DAY;
+    let (hours, minutes, seconds) = (millis / 3_600_000, millis / 60_000 % 60, millis / 1000 % 60);
+    format!("{year:04}-{month:02}-{day:02}T{hours:02}:{minutes:02}:{seconds:02}Z")
+}
+
+// Conversions between days since the unix epoch and the civil (Gregorian) calendar,
+// see <http://howardhinnant.github.io/date_algorithms.html>
+
+fn days_in_month(year: u64, month: u64) -> u64 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+fn days_from_civil(year: u64, month: u64, day: u64) -> u64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year / 400;
+    let yoe = year - era * 400;
+    let doy = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
+
+fn civil_from_days(days: u64) -> (u64, u64, u64) {
+    let days = days + 719468;
+    let era = days / 146097;
+    let doe = days - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + u64::from(month <= 2);
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transaction_export_dates() {
+        assert_eq!(parse_date("1970-01-01", false).unwrap(), 0);
+        assert_eq!(parse_date("1970-01-01", true).unwrap(), MILLIS_PER_DAY - 1);
+        assert_eq!(parse_date("2023-10-19", false).unwrap(), 1_697_673_600_000);
+        assert_eq!(parse_date("1697673600123", false).unwrap(), 1_697_673_600_123);
+        assert!(parse_date("2023-13-01", false).is_err());
+        assert!(parse_date("2023-01-32", false).is_err());
+        assert!(parse_date("2023-04-31", false).is_err());
+        assert!(parse_date("2023-02-29", false).is_err());
+        assert!(parse_date("2023-02-31", false).is_err());
+        assert!(parse_date("2100-02-29", false).is_err());
+        assert!(parse_date("2023-00-10", false).is_err());
+        assert!(parse_date("2023-06-00", false).is_err());
+        assert_eq!(parse_date("2024-02-29", false).unwrap(), 1_709_164_800_000);
+        assert_eq!(parse_date("2000-02-29", false).unwrap(), 951_782_400_000);
+        assert_eq!(parse_date("2023-04-30", true).unwrap(), 1_682_899_199_999);
+        assert!(parse_date("yesterday", false).is_err());
+
+        assert_eq!(unixtime_to_iso8601(0), "1970-01-01T00:00:00Z");
+        assert_eq!(unixtime_to_iso8601(1_709_210_096_000), "2024-02-29T12:34:56Z");
+
+        let range = TransactionExportRange::try_from_dates(Some("2023-10-19"), Some("2023-10-19")).unwrap();
+        assert!(range.contains(Some(1_697_673_600_000)));
+        assert!(range.contains(Some(1_697_759_999_999)));
+        assert!(!range.contains(Some(1_697_760_000_000)));
+        assert!(!range.contains(None));
+        assert!(TransactionExportRange::default().contains(None));
+    }
+
+    #[test]
+    fn test_transaction_export_csv_escape() {
+        assert_eq!(csv_escape("plain"), "plain");
+        assert_eq!(csv_escape("a,b"), "\"a,b\"");
+        assert_eq!(csv_escape("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+}
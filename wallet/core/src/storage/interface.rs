@@ -78,6 +78,16 @@ pub trait AddressBookStore: Send + Sync {
     async fn search(&self, _search: &str) -> Result<Vec<Arc<AddressBookEntry>>> {
         Err(Error::NotImplemented)
     }
+    async fn load_single(&self, _alias: &str) -> Result<Option<Arc<AddressBookEntry>>> {
+        Err(Error::NotImplemented)
+    }
+    /// store an entry, replacing any existing entry with the same alias
+    async fn store_single(&self, _entry: &AddressBookEntry) -> Result<()> {
+        Err(Error::NotImplemented)
+    }
+    async fn remove(&self, _aliases: &[&str]) -> Result<()> {
+        Err(Error::NotImplemented)
+    }
 }
 
 #[async_trait]
@@ -149,6 +159,14 @@ pub trait Interface: Send + Sync + AnySync {
     /// commit any changes changes to storage
     async fn commit(&self, ctx: &Arc<dyn AccessContextT>) -> Result<()>;
 
+    /// re-encrypt wallet data using the wallet secret supplied by `new_ctx`
+    /// and commit the changes (the current wallet secret is verified using `ctx`)
+    async fn change_secret(&self, ctx: &Arc<dyn AccessContextT>, new_ctx: &Arc<dyn AccessContextT>) -> Result<()>;
+
+    /// replace existing private key data (e.g. re-encrypted with a new payment secret)
+    /// and commit the changes, keeping the previous key data if the commit fails
+    async fn update_prv_key_data(&self, ctx: &Arc<dyn AccessContextT>, prv_key_data: PrvKeyData) -> Result<()>;
+
     /// stop the storage subsystem
     async fn close(&self) -> Result<()>;
 
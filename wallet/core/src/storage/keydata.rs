@@ -43,6 +43,12 @@ impl FromHex for KeyDataId {
     }
 }
 
+impl std::fmt::Display for KeyDataId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_hex())
+    }
+}
+
 impl std::fmt::Debug for KeyDataId {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "KeyDataId ( {:?} )", self.0)
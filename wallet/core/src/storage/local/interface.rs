@@ -113,6 +113,45 @@ impl LocalStoreInner {
         }
     }
 
+    pub async fn change_secret(&self, ctx: &Arc<dyn AccessContextT>, new_ctx: &Arc<dyn AccessContextT>) -> Result<()> {
+        let wallet_secret = ctx.wallet_secret().await;
+        let new_wallet_secret = new_ctx.wallet_secret().await;
+        let prv_key_data_map: Decrypted<PrvKeyDataMap> = self.cache().prv_key_data.decrypt(&wallet_secret)?;
+        let prv_key_data = prv_key_data_map.encrypt(&new_wallet_secret)?;
+        let previous = std::mem::replace(&mut self.cache().prv_key_data, prv_key_data);
+        self.set_modified(true);
+        if let Err(err) = self.store(new_ctx).await {
+            // the wallet file remains encrypted with the current secret
+            self.cache().prv_key_data = previous;
+            self.set_modified(false);
+            return Err(err);
+        }
+        Ok(())
+    }
+
+    pub async fn update_prv_key_data(&self, ctx: &Arc<dyn AccessContextT>, prv_key_data: PrvKeyData) -> Result<()> {
+        let previous_info = self
+            .cache()
+            .prv_key_data_info
+            .map
+            .get(&prv_key_data.id)
+            .cloned()
+            .ok_or_else(|| Error::PrivateKeyNotFound(prv_key_data.id.to_hex()))?;
+        let previous = self.cache().prv_key_data.clone();
+        let is_modified = self.is_modified();
+        PrvKeyDataStore::store(self, ctx, prv_key_data).await?;
+        if let Err(err) = self.store(ctx).await {
+            // the wallet file retains the previous key data
+            let mut cache = self.cache();
+            cache.prv_key_data = previous;
+            cache.prv_key_data_info.insert(previous_info.id, previous_info)?;
+            drop(cache);
+            self.set_modified(is_modified);
+            return Err(err);
+        }
+        Ok(())
+    }
+
     #[inline]
     pub fn set_modified(&self, modified: bool) {
         match self.store {
@@ -260,6 +299,14 @@ impl Interface for LocalStore {
         Ok(())
     }
 
+    async fn change_secret(&self, ctx: &Arc<dyn AccessContextT>, new_ctx: &Arc<dyn AccessContextT>) -> Result<()> {
+        self.inner()?.change_secret(ctx, new_ctx).await
+    }
+
+    async fn update_prv_key_data(&self, ctx: &Arc<dyn AccessContextT>, prv_key_data: PrvKeyData) -> Result<()> {
+        self.inner()?.update_prv_key_data(ctx, prv_key_data).await
+    }
+
     async fn close(&self) -> Result<()> {
         if self.inner()?.is_modified() {
             panic!("LocalStore::close called while modified flag is true");
@@ -304,8 +351,12 @@ impl PrvKeyDataStore for LocalStoreInner {
     async fn store(&self, ctx: &Arc<dyn AccessContextT>, prv_key_data: PrvKeyData) -> Result<()> {
         let wallet_secret = ctx.wallet_secret().await;
         let mut prv_key_data_map: Decrypted<PrvKeyDataMap> = self.cache().prv_key_data.decrypt(&wallet_secret)?;
+        let prv_key_data_info = Arc::new(PrvKeyDataInfo::from(&prv_key_data));
         prv_key_data_map.insert(prv_key_data.id, prv_key_data);
-        self.cache().prv_key_data.replace(prv_key_data_map.encrypt(&wallet_secret)?);
+        let mut cache = self.cache();
+        cache.prv_key_data.replace(prv_key_data_map.encrypt(&wallet_secret)?);
+        cache.prv_key_data_info.insert(prv_key_data_info.id, prv_key_data_info)?;
+        drop(cache);
         self.set_modified(true);
         Ok(())
     }
@@ -314,7 +365,10 @@ impl PrvKeyDataStore for LocalStoreInner {
         let wallet_secret = ctx.wallet_secret().await; //.ok_or(Error::WalletSecretRequired)?;
         let mut prv_key_data_map: Decrypted<PrvKeyDataMap> = self.cache().prv_key_data.decrypt(&wallet_secret)?;
         prv_key_data_map.remove(prv_key_data_id);
-        self.cache().prv_key_data.replace(prv_key_data_map.encrypt(&wallet_secret)?);
+        let mut cache = self.cache();
+        cache.prv_key_data.replace(prv_key_data_map.encrypt(&wallet_secret)?);
+        cache.prv_key_data_info.remove(&[prv_key_data_id])?;
+        drop(cache);
         self.set_modified(true);
         Ok(())
     }
@@ -398,9 +452,130 @@ impl AddressBookStore for LocalStoreInner {
             .cache()
             .address_book
             .iter()
-            .filter_map(|entry| if entry.alias.contains(search) { Some(Arc::new(entry.clone())) } else { None })
+            .filter_map(|entry| if entry.matches(search) { Some(Arc::new(entry.clone())) } else { None })
             .collect();
 
         Ok(matches)
     }
+
+    async fn load_single(&self, alias: &str) -> Result<Option<Arc<AddressBookEntry>>> {
+        Ok(self.cache().address_book.iter().find(|entry| entry.alias == alias).map(|entry| Arc::new(entry.clone())))
+    }
+
+    async fn store_single(&self, entry: &AddressBookEntry) -> Result<()> {
+        let mut cache = self.cache();
+        if let Some(existing) = cache.address_book.iter_mut().find(|existing| existing.alias == entry.alias) {
+            *existing = entry.clone();
+        } else {
+            cache.address_book.push(entry.clone());
+        }
+        self.set_modified(true);
+        Ok(())
+    }
+
+    async fn remove(&self, aliases: &[&str]) -> Result<()> {
+        self.cache().address_book.retain(|entry| !aliases.contains(&entry.alias.as_str()));
+        self.set_modified(true);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::secret::Secret;
+    use crate::storage::interface::AccessContext;
+    use kaspa_bip32::{Language, Mnemonic};
+
+    fn context(secret: &str) -> Arc<dyn AccessContextT> {
+        Arc::new(AccessContext::new(Secret::from(secret)))
+    }
+
+    fn test_folder(name: &str) -> String {
+        let folder = std::env::temp_dir().join(format!("kaspa-wallet-{name}-{}", std::process::id()));
+        std::fs::create_dir_all(&folder).unwrap();
+        folder.to_str().unwrap().to_string()
+    }
+
+    #[tokio::test]
+    async fn test_change_secret_reopen() -> Result<()> {
+        let folder = test_folder("change-secret-reopen");
+        let (current, new) = (context("current-secret"), context("new-secret"));
+        let args = CreateArgs::new(Some("test".to_string()), None, true);
+        let inner = LocalStoreInner::try_create(&current, &folder, args, false).await?;
+        inner.store(&current).await?;
+
+        inner.change_secret(&current, &new).await?;
+        assert!(!inner.is_modified());
+        assert!(inner.cache().prv_key_data.decrypt::<PrvKeyDataMap>(&Secret::from("new-secret")).is_ok());
+
+        // the wallet file can only be opened with the new secret
+        assert!(LocalStoreInner::try_load(&current, &folder, OpenArgs::new(Some("test".to_string()))).await.is_err());
+        LocalStoreInner::try_load(&new, &folder, OpenArgs::new(Some("test".to_string()))).await?;
+
+        std::fs::remove_dir_all(folder)?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_change_secret_keeps_old_on_failure() -> Result<()> {
+        let folder = test_folder("change-secret-failure");
+        let (current, new) = (context("current-secret"), context("new-secret"));
+        let args = CreateArgs::new(Some("test".to_string()), None, true);
+        let inner = LocalStoreInner::try_create(&current, &folder, args, false).await?;
+        inner.store(&current).await?;
+
+        // an invalid current secret is rejected without altering the wallet
+        assert!(inner.change_secret(&context("invalid-secret"), &new).await.is_err());
+        assert!(inner.cache().prv_key_data.decrypt::<PrvKeyDataMap>(&Secret::from("current-secret")).is_ok());
+
+        // replace the wallet file with a (non-empty) directory so that storing the wallet fails
+        let Store::Storage(ref storage) = inner.store else { panic!("expecting a file backed store") };
+        let filename = storage.filename().clone();
+        std::fs::remove_file(&filename)?;
+        std::fs::create_dir_all(filename.join("blocker"))?;
+
+        assert!(inner.change_secret(&current, &new).await.is_err());
+        assert!(!inner.is_modified());
+        assert!(inner.cache().prv_key_data.decrypt::<PrvKeyDataMap>(&Secret::from("current-secret")).is_ok());
+        assert!(inner.cache().prv_key_data.decrypt::<PrvKeyDataMap>(&Secret::from("new-secret")).is_err());
+
+        std::fs::remove_dir_all(folder)?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_update_prv_key_data_keeps_old_on_failure() -> Result<()> {
+        let folder = test_folder("update-prv-key-data-failure");
+        let ctx = context("wallet-secret");
+        let args = CreateArgs::new(Some("test".to_string()), None, true);
+        let inner = LocalStoreInner::try_create(&ctx, &folder, args, false).await?;
+
+        let (payment_secret, new_payment_secret) = (Secret::from("payment-secret"), Secret::from("new-payment-secret"));
+        let phrase = "fiber boy desk trip pitch snake table awkward endorse car learn forest solid ticket enemy pink gesture wealth iron chaos clock gather honey farm";
+        let mnemonic = Mnemonic::new(phrase, Language::English)?;
+        let mut prv_key_data = PrvKeyData::try_new_from_mnemonic(mnemonic, Some(&payment_secret))?;
+        let prv_key_data_id = prv_key_data.id;
+        PrvKeyDataStore::store(&inner, &ctx, prv_key_data.clone()).await?;
+        inner.store(&ctx).await?;
+
+        // replace the wallet file with a (non-empty) directory so that storing the wallet fails
+        let Store::Storage(ref storage) = inner.store else { panic!("expecting a file backed store") };
+        let filename = storage.filename().clone();
+        std::fs::remove_file(&filename)?;
+        std::fs::create_dir_all(filename.join("blocker"))?;
+
+        let payload = prv_key_data.payload.decrypt(Some(&payment_secret))?;
+        prv_key_data.payload = Encryptable::XChaCha20Poly1305(payload.encrypt(&new_payment_secret)?);
+        assert!(inner.update_prv_key_data(&ctx, prv_key_data).await.is_err());
+
+        // the cache retains the key data encrypted with the current payment secret and can be closed
+        assert!(!inner.is_modified());
+        let prv_key_data = inner.load_key_data(&ctx, &prv_key_data_id).await?.expect("missing private key data");
+        assert!(prv_key_data.payload.decrypt(Some(&payment_secret)).is_ok());
+        assert!(inner.load_key_info(&prv_key_data_id).await?.expect("missing private key info").is_encrypted());
+
+        std::fs::remove_dir_all(folder)?;
+        Ok(())
+    }
 }
@@ -57,7 +57,30 @@ impl Wallet {
 
     pub async fn try_store(&self, store: &Storage) -> Result<()> {
         store.ensure_dir().await?;
-        fs::write_json(store.filename(), self).await?;
+
+        cfg_if! {
+            if #[cfg(target_arch = "wasm32")] {
+                fs::write_json(store.filename(), self).await?;
+            } else {
+                // write to a temporary file and swap it with the wallet file
+                // so that the wallet is never left partially written
+                use std::io::Write;
+
+                let filename = store.filename();
+                let temp = filename.with_extension("wallet.tmp");
+                let mut file = std::fs::OpenOptions::new().write(true).create(true).truncate(true).open(&temp)?;
+                file.write_all(serde_json::to_string(self)?.as_bytes())?;
+                file.sync_all()?;
+                drop(file);
+                std::fs::rename(&temp, filename)?;
+                // persist the rename itself (directories can not be opened for syncing on windows)
+                #[cfg(unix)]
+                if let Some(parent) = filename.parent() {
+                    std::fs::File::open(parent)?.sync_all()?;
+                }
+            }
+        }
+
         Ok(())
     }
 
@@ -2,17 +2,26 @@ use crate::derivation::AddressDerivationMeta;
 use crate::imports::*;
 use crate::storage::AccountId;
 use crate::storage::IdT;
+use crate::utxo::UtxoEntryId;
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Metadata {
     pub id: AccountId,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub indexes: Option<AddressDerivationMeta>,
+    /// UTXOs excluded from automatic selection (coin control)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub frozen_utxos: Vec<UtxoEntryId>,
 }
 
 impl Metadata {
     pub fn new(id: AccountId, indexes: AddressDerivationMeta) -> Self {
-        Self { id, indexes: Some(indexes) }
+        Self { id, indexes: Some(indexes), frozen_utxos: vec![] }
+    }
+
+    pub fn with_frozen_utxos(mut self, frozen_utxos: Vec<UtxoEntryId>) -> Self {
+        self.frozen_utxos = frozen_utxos;
+        self
     }
 
     pub fn address_derivation_indexes(&self) -> Option<AddressDerivationMeta> {
@@ -2,7 +2,9 @@ pub use crate::encryption::{Decrypted, Encryptable, Encrypted};
 
 pub mod account;
 pub mod address;
+pub mod backup;
 pub mod binding;
+pub mod export;
 pub mod hint;
 pub mod id;
 pub mod interface;
@@ -12,12 +14,14 @@ pub mod metadata;
 pub mod transaction;
 
 pub use crate::runtime::{AccountId, AccountKind};
-pub use account::{Account, AccountData, Bip32, Keypair, Legacy, MultiSig, Settings};
+pub use account::{Account, AccountData, Bip32, Keypair, Legacy, MultiSig, Settings, WatchOnly};
 pub use address::AddressBookEntry;
+pub use backup::{AccountBackup, Backup, BackupData};
 pub use binding::Binding;
+pub use export::{TransactionExport, TransactionExportFormat, TransactionExportRange, TransactionExportRecord};
 pub use hint::Hint;
 pub use id::IdT;
-pub use interface::{AccessContextT, AccountStore, Interface, PrvKeyDataStore, TransactionRecordStore};
+pub use interface::{AccessContextT, AccountStore, AddressBookStore, Interface, PrvKeyDataStore, TransactionRecordStore};
 pub use keydata::{KeyCaps, PrvKeyData, PrvKeyDataId, PrvKeyDataInfo, PrvKeyDataMap, PrvKeyDataPayload};
 pub use metadata::Metadata;
 pub use transaction::{TransactionMetadata, TransactionRecord, TransactionType};
@@ -30,6 +34,7 @@ mod tests {
     use crate::secret::Secret;
     use crate::storage::local::Payload;
     use crate::storage::local::Wallet;
+    use kaspa_addresses::Address;
     use kaspa_bip32::{Language, Mnemonic};
     use std::sync::Arc;
 
@@ -62,18 +67,26 @@ mod tests {
         payload.prv_key_data.push(prv_key_data1.clone());
         payload.prv_key_data.push(prv_key_data2.clone());
 
-        let settings = Settings { name: Some("Wallet-A".to_string()), title: Some("Wallet A".to_string()), is_visible: false };
+        let settings =
+            Settings { name: Some("Wallet-A".to_string()), title: Some("Wallet A".to_string()), is_visible: false, compounding: None };
         let bip32 = Bip32 { account_index: 0, xpub_keys: pub_key_data1.clone(), ecdsa: false };
         let id = AccountId::from_bip32(&prv_key_data1.id, &bip32);
         let account1 = Account::new(id, Some(prv_key_data1.id), settings, AccountData::Bip32(bip32));
         payload.accounts.push(account1);
 
-        let settings = Settings { name: Some("Wallet-B".to_string()), title: Some("Wallet B".to_string()), is_visible: false };
+        let settings =
+            Settings { name: Some("Wallet-B".to_string()), title: Some("Wallet B".to_string()), is_visible: false, compounding: None };
         let bip32 = Bip32 { account_index: 0, xpub_keys: pub_key_data2.clone(), ecdsa: false };
         let id = AccountId::from_bip32(&prv_key_data2.id, &bip32);
         let account2 = Account::new(id, Some(prv_key_data2.id), settings, AccountData::Bip32(bip32));
         payload.accounts.push(account2);
 
+        let address = Address::try_from("kaspatest:qqnys5nyennjkvyl77vwneq5j2vmjss57zerd88ptzaeqhm998smx0vp8yfkm")?;
+        let entry = AddressBookEntry::try_new("alice", "Alice", address, vec!["friends".to_string()], None)?;
+        assert!(entry.matches("FRIEND") && entry.matches("ali") && !entry.matches("bob"));
+        assert!(AddressBookEntry::validate_alias("alice smith").is_err());
+        payload.address_book.push(entry);
+
         let payload_json = serde_json::to_string(&payload).unwrap();
         // let settings = WalletSettings::new(account_id);
 
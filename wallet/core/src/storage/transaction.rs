@@ -214,6 +214,46 @@ impl TransactionRecord {
             _ => false,
         }
     }
+
+    pub fn transaction_data(&self) -> &TransactionData {
+        &self.transaction_data
+    }
+
+    pub fn metadata(&self) -> Option<&TransactionMetadata> {
+        self.metadata.as_ref()
+    }
+
+    /// Returns `true` if the transaction has reached maturity at the given DAA score
+    pub fn is_mature(&self, current_daa_score: u64) -> bool {
+        // TODO - refactor @ high BPS processing
+        let maturity = if self.is_coinbase() {
+            crate::utxo::UTXO_MATURITY_PERIOD_COINBASE_TRANSACTION_DAA.load(Ordering::SeqCst)
+        } else {
+            crate::utxo::UTXO_MATURITY_PERIOD_USER_TRANSACTION_DAA.load(Ordering::SeqCst)
+        };
+
+        current_daa_score >= self.block_daa_score + maturity
+    }
+
+    /// Value transferred by the transaction: the received amount for incoming
+    /// transactions, the payment amount for outgoing transactions or the
+    /// aggregated input amount for sweep (batch) transactions.
+    pub fn value(&self) -> u64 {
+        match &self.transaction_data {
+            TransactionData::Reorg { aggregate_input_value, .. }
+            | TransactionData::Incoming { aggregate_input_value, .. }
+            | TransactionData::External { aggregate_input_value, .. } => *aggregate_input_value,
+            TransactionData::Outgoing { payment_value, aggregate_input_value, .. } => payment_value.unwrap_or(*aggregate_input_value),
+        }
+    }
+
+    /// Fees paid by the transaction (available only for outgoing transactions)
+    pub fn fees(&self) -> Option<u64> {
+        match &self.transaction_data {
+            TransactionData::Outgoing { fees, .. } => Some(*fees),
+            _ => None,
+        }
+    }
 }
 
 impl TransactionRecord {
@@ -347,20 +387,7 @@ impl TransactionRecord {
         let kind = transaction_type.style(&transaction_type.to_string());
 
         let maturity = current_daa_score
-            .map(|score| {
-                // TODO - refactor @ high BPS processing
-                let maturity = if self.is_coinbase() {
-                    crate::utxo::UTXO_MATURITY_PERIOD_COINBASE_TRANSACTION_DAA.load(Ordering::SeqCst)
-                } else {
-                    crate::utxo::UTXO_MATURITY_PERIOD_USER_TRANSACTION_DAA.load(Ordering::SeqCst)
-                };
-
-                if score < self.block_daa_score() + maturity {
-                    style("pending").dim().to_string()
-                } else {
-                    style("confirmed").dim().to_string()
-                }
-            })
+            .map(|score| if self.is_mature(score) { style("confirmed").dim().to_string() } else { style("pending").dim().to_string() })
             .unwrap_or_default();
 
         let block_daa_score = block_daa_score.separated_string();
@@ -162,6 +162,11 @@ impl PendingTransaction {
         self.inner.signable_tx.lock().unwrap().tx.clone()
     }
 
+    /// Transaction along with the UTXO entries it spends (as required for signing)
+    pub fn signable_transaction(&self) -> SignableTransaction {
+        self.inner.signable_tx.lock().unwrap().clone()
+    }
+
     pub fn rpc_transaction(&self) -> RpcTransaction {
         self.inner.signable_tx.lock().unwrap().tx.as_ref().into()
     }
@@ -1,7 +1,7 @@
 use crate::result::Result;
 use crate::runtime::Account;
 use crate::tx::{Fees, PaymentDestination};
-use crate::utxo::{UtxoContext, UtxoEntryReference, UtxoIterator};
+use crate::utxo::{UtxoContext, UtxoEntryId, UtxoEntryReference, UtxoIterator};
 use crate::Events;
 use kaspa_addresses::Address;
 use kaspa_consensus_core::network::NetworkType;
@@ -63,6 +63,22 @@ impl GeneratorSettings {
         Ok(settings)
     }
 
+    /// Settings spending only the supplied account UTXOs (manual coin selection).
+    /// Frozen UTXOs are accepted here as the selection is explicit.
+    pub fn try_new_with_account_utxos(
+        account: Arc<dyn Account>,
+        utxos: &[UtxoEntryId],
+        final_transaction_destination: PaymentDestination,
+        final_priority_fee: Fees,
+        final_transaction_payload: Option<Vec<u8>>,
+    ) -> Result<Self> {
+        let entries = account.utxo_context().get_mature_entries(utxos)?;
+        let mut settings =
+            Self::try_new_with_account(account, final_transaction_destination, final_priority_fee, final_transaction_payload)?;
+        settings.utxo_iterator = Box::new(UtxoIterator::new_with_entries(entries));
+        Ok(settings)
+    }
+
     pub fn try_new_with_context(
         utxo_context: UtxoContext,
         change_address: Address,
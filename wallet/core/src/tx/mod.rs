@@ -3,9 +3,11 @@ pub mod fees;
 pub mod generator;
 pub mod mass;
 pub mod payment;
+pub mod uri;
 
 pub use consensus::*;
 pub use fees::*;
 pub use generator::*;
 pub use mass::*;
 pub use payment::*;
+pub use uri::*;
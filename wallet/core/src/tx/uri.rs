@@ -0,0 +1,250 @@
+//!
+//! Payment request URIs in the form of
+//! `kaspa:<address>?amount=<KAS>&label=<label>&message=<message>&id=<request id>`.
+//!
+//! The URI scheme is the address prefix (`kaspa`, `kaspatest`, ...), i.e. the
+//! URI path combined with its scheme is a regular [`Address`]. Amounts are
+//! expressed in KAS (with up to 8 decimal places) and are parsed into sompi.
+//! Unknown parameters are ignored unless they carry the `req-` prefix, in which
+//! case the URI is rejected as the request can not be fulfilled.
+//!
+
+use crate::imports::*;
+use crate::result::Result;
+use kaspa_consensus_core::constants::SOMPI_PER_KASPA;
+
+const KASPA_DECIMALS: usize = 8;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[wasm_bindgen(inspectable)]
+pub struct PaymentUri {
+    #[wasm_bindgen(getter_with_clone)]
+    pub address: Address,
+    /// requested amount in sompi
+    pub amount: Option<u64>,
+    #[wasm_bindgen(getter_with_clone)]
+    pub label: Option<String>,
+    #[wasm_bindgen(getter_with_clone)]
+    pub message: Option<String>,
+    #[wasm_bindgen(getter_with_clone, js_name = "requestId")]
+    pub request_id: Option<String>,
+}
+
+impl PaymentUri {
+    pub fn new(address: Address) -> Self {
+        Self { address, amount: None, label: None, message: None, request_id: None }
+    }
+
+    pub fn with_amount(mut self, amount: u64) -> Self {
+        self.amount = Some(amount);
+        self
+    }
+
+    pub fn with_label<S: Into<String>>(mut self, label: S) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    pub fn with_message<S: Into<String>>(mut self, message: S) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+
+    pub fn with_request_id<S: Into<String>>(mut self, request_id: S) -> Self {
+        self.request_id = Some(request_id.into());
+        self
+    }
+
+    /// Returns `true` if the string looks like a payment URI (as opposed to a plain address)
+    pub fn is_uri(s: &str) -> bool {
+        s.contains('?')
+    }
+
+    pub fn try_validate_network(&self, network_type: NetworkType) -> Result<()> {
+        if self.address.prefix != Prefix::from(network_type) {
+            Err(Error::InvalidPaymentUri(format!("address '{}' does not belong to the {network_type} network", self.address)))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl FromStr for PaymentUri {
+    type Err = Error;
+    fn from_str(uri: &str) -> Result<Self> {
+        let uri = uri.trim();
+        let (address, query) = uri.split_once('?').unwrap_or((uri, ""));
+        if !address.contains(':') {
+            return Err(Error::InvalidPaymentUri(format!("missing URI scheme in '{uri}'")));
+        }
+        let address = Address::try_from(address.to_lowercase().as_str())
+            .map_err(|err| Error::InvalidPaymentUri(format!("invalid address '{address}': {err}")))?;
+
+        let mut payment_uri = PaymentUri::new(address);
+        for param in query.split('&').filter(|param| !param.is_empty()) {
+            let (key, value) = param.split_once('=').unwrap_or((param, ""));
+            let value = percent_decode(value)?;
+            match key {
+                "amount" => payment_uri.amount = Some(parse_amount(&value)?),
+                "label" => payment_uri.label = Some(value),
+                "message" => payment_uri.message = Some(value),
+                "id" => payment_uri.request_id = Some(value),
+                key if key.starts_with("req-") => {
+                    return Err(Error::InvalidPaymentUri(format!("unsupported required parameter '{key}'")));
+                }
+                _ => {}
+            }
+        }
+
+        Ok(payment_uri)
+    }
+}
+
+impl std::fmt::Display for PaymentUri {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut params = vec![];
+        if let Some(amount) = self.amount {
+            params.push(format!("amount={}", format_amount(amount)));
+        }
+        if let Some(label) = &self.label {
+            params.push(format!("label={}", percent_encode(label)));
+        }
+        if let Some(message) = &self.message {
+            params.push(format!("message={}", percent_encode(message)));
+        }
+        if let Some(request_id) = &self.request_id {
+            params.push(format!("id={}", percent_encode(request_id)));
+        }
+
+        if params.is_empty() {
+            write!(f, "{}", self.address)
+        } else {
+            write!(f, "{}?{}", self.address, params.join("&"))
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl PaymentUri {
+    #[wasm_bindgen(constructor)]
+    pub fn constructor(
+        address: Address,
+        amount: Option<u64>,
+        label: Option<String>,
+        message: Option<String>,
+        request_id: Option<String>,
+    ) -> Self {
+        Self { address, amount, label, message, request_id }
+    }
+
+    /// Parses a `kaspa:` payment URI
+    #[wasm_bindgen(js_name = "parse")]
+    pub fn js_parse(uri: &str) -> Result<PaymentUri> {
+        uri.parse()
+    }
+
+    #[wasm_bindgen(js_name = "toString")]
+    pub fn js_to_string(&self) -> String {
+        self.to_string()
+    }
+}
+
+/// Parses a KAS amount into sompi without loss of precision
+fn parse_amount(amount: &str) -> Result<u64> {
+    let invalid = || Error::InvalidPaymentUri(format!("invalid amount '{amount}'"));
+    let (integer, fraction) = amount.split_once('.').unwrap_or((amount, ""));
+    if (integer.is_empty() && fraction.is_empty())
+        || fraction.len() > KASPA_DECIMALS
+        || !integer.chars().chain(fraction.chars()).all(|c| c.is_ascii_digit())
+    {
+        return Err(invalid());
+    }
+
+    let integer = if integer.is_empty() { 0 } else { integer.parse::<u64>().map_err(|_| invalid())? };
+    let fraction =
+        if fraction.is_empty() { 0 } else { format!("{fraction:0<KASPA_DECIMALS$}").parse::<u64>().map_err(|_| invalid())? };
+    integer.checked_mul(SOMPI_PER_KASPA).and_then(|sompi| sompi.checked_add(fraction)).ok_or_else(invalid)
+}
+
+fn format_amount(sompi: u64) -> String {
+    let integer = sompi / SOMPI_PER_KASPA;
+    let fraction = sompi % SOMPI_PER_KASPA;
+    if fraction == 0 {
+        integer.to_string()
+    } else {
+        let fraction = format!("{fraction:0>KASPA_DECIMALS$}");
+        format!("{integer}.{}", fraction.trim_end_matches('0'))
+    }
+}
+
+fn percent_encode(s: &str) -> String {
+    s.bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => (byte as char).to_string(),
+            _ => format!("%{byte:02X}"),
+        })
+        .collect()
+}
+
+fn percent_decode(s: &str) -> Result<String> {
+    let invalid = || Error::InvalidPaymentUri(format!("invalid URI encoding in '{s}'"));
+    let mut bytes = Vec::with_capacity(s.len());
+    let mut iter = s.bytes();
+    while let Some(byte) = iter.next() {
+        match byte {
+            b'%' => {
+                let hex = [iter.next().ok_or_else(invalid)?, iter.next().ok_or_else(invalid)?];
+                let hex = std::str::from_utf8(&hex).map_err(|_| invalid())?;
+                bytes.push(u8::from_str_radix(hex, 16).map_err(|_| invalid())?);
+            }
+            b'+' => bytes.push(b' '),
+            byte => bytes.push(byte),
+        }
+    }
+    String::from_utf8(bytes).map_err(|_| invalid())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ADDRESS: &str = "kaspatest:qz7ulu4c25dh7fzec9zjyrmlhnkzrg4wmf89q7gzr3gfrsj3uz6xjceef60sd";
+
+    #[test]
+    fn test_payment_uri_parse() {
+        let uri = format!("{ADDRESS}?amount=1.5&label=Coffee%20Shop&message=order+42&id=abc&foo=bar").parse::<PaymentUri>().unwrap();
+        assert_eq!(uri.address.to_string(), ADDRESS);
+        assert_eq!(uri.amount, Some(150_000_000));
+        assert_eq!(uri.label.as_deref(), Some("Coffee Shop"));
+        assert_eq!(uri.message.as_deref(), Some("order 42"));
+        assert_eq!(uri.request_id.as_deref(), Some("abc"));
+        assert!(uri.try_validate_network(NetworkType::Testnet).is_ok());
+        assert!(uri.try_validate_network(NetworkType::Mainnet).is_err());
+
+        let uri = ADDRESS.parse::<PaymentUri>().unwrap();
+        assert_eq!(uri, PaymentUri::new(Address::try_from(ADDRESS).unwrap()));
+
+        assert_eq!(parse_amount("0.00000001").unwrap(), 1);
+        assert_eq!(parse_amount(".5").unwrap(), 50_000_000);
+        assert_eq!(parse_amount("21").unwrap(), 2_100_000_000);
+        for amount in ["", ".", "-1", "1.000000001", "1e3", "1,5"] {
+            assert!(parse_amount(amount).is_err(), "amount '{amount}' should be rejected");
+        }
+
+        assert!(format!("{ADDRESS}?req-unknown=1").parse::<PaymentUri>().is_err());
+        assert!(format!("{ADDRESS}?label=%4").parse::<PaymentUri>().is_err());
+        assert!("qz7ulu4c25dh7fzec9zjyrmlhnkzrg4wmf89q7gzr3gfrsj3uz6xjceef60sd?amount=1".parse::<PaymentUri>().is_err());
+    }
+
+    #[test]
+    fn test_payment_uri_roundtrip() {
+        let uri = PaymentUri::new(Address::try_from(ADDRESS).unwrap())
+            .with_amount(123_450_000)
+            .with_label("Alice & Bob")
+            .with_message("thanks!")
+            .with_request_id("inv-7");
+        let text = uri.to_string();
+        assert_eq!(text, format!("{ADDRESS}?amount=1.2345&label=Alice%20%26%20Bob&message=thanks%21&id=inv-7"));
+        assert_eq!(text.parse::<PaymentUri>().unwrap(), uri);
+    }
+}
@@ -96,6 +96,11 @@ pub struct Context {
     balance: Option<Balance>,
     /// Addresses monitored by this UTXO context
     addresses: Arc<DashSet<Arc<Address>>>,
+    /// UTXOs excluded from automatic selection during transaction
+    /// generation (coin control). Retained across [`Context::clear`].
+    pub(crate) frozen: HashSet<UtxoEntryId>,
+    /// Set when frozen UTXOs were dropped since the frozen set was last persisted
+    frozen_pruned: bool,
 }
 
 impl Context {
@@ -112,6 +117,14 @@ impl Context {
         self.addresses.clear();
         self.balance = None;
     }
+
+    /// Drops the frozen ids of UTXOs no longer held by this context
+    fn prune_frozen(&mut self) {
+        let Context { map, frozen, frozen_pruned, .. } = self;
+        let len = frozen.len();
+        frozen.retain(|id| map.contains_key(id));
+        *frozen_pruned |= frozen.len() != len;
+    }
 }
 
 struct Inner {
@@ -187,6 +200,66 @@ impl UtxoContext {
         self.context().addresses.clone()
     }
 
+    pub fn mature_utxos(&self) -> Vec<UtxoEntryReference> {
+        self.context().mature.clone()
+    }
+
+    pub fn pending_utxos(&self) -> Vec<UtxoEntryReference> {
+        self.context().pending.values().cloned().collect()
+    }
+
+    /// Mark UTXOs as frozen, excluding them from automatic selection
+    pub fn freeze(&self, ids: &[UtxoEntryId]) {
+        self.context().frozen.extend(ids.iter().cloned());
+    }
+
+    pub fn unfreeze(&self, ids: &[UtxoEntryId]) {
+        let mut context = self.context();
+        ids.iter().for_each(|id| {
+            context.frozen.remove(id);
+        });
+    }
+
+    pub fn is_frozen(&self, id: &UtxoEntryId) -> bool {
+        self.context().frozen.contains(id)
+    }
+
+    pub fn frozen_utxos(&self) -> Vec<UtxoEntryId> {
+        let mut frozen = self.context().frozen.iter().cloned().collect::<Vec<_>>();
+        frozen.sort();
+        frozen
+    }
+
+    /// Unfreezes the UTXOs that are no longer held by this context, such as entries
+    /// spent while the wallet was offline. Must only be called once the context
+    /// holds all the UTXOs of its addresses.
+    pub fn prune_frozen(&self) {
+        self.context().prune_frozen();
+    }
+
+    /// Returns whether frozen UTXOs were dropped, because spent or reorged out, since
+    /// the last call. If so, the frozen set must be persisted again.
+    pub fn take_frozen_pruned(&self) -> bool {
+        std::mem::take(&mut self.context().frozen_pruned)
+    }
+
+    /// Returns the mature UTXO entries matching the supplied ids, in the
+    /// order supplied. Fails if any of the entries is not mature (pending
+    /// or consumed) or does not belong to this context.
+    pub fn get_mature_entries(&self, ids: &[UtxoEntryId]) -> Result<Vec<UtxoEntryReference>> {
+        let context = self.context();
+        ids.iter()
+            .map(|id| {
+                let available =
+                    context.map.contains_key(id) && !context.pending.contains_key(id) && !context.consumed.contains_key(id);
+                available
+                    .then(|| context.mature.iter().find(|entry| entry.id_as_ref() == id).cloned())
+                    .flatten()
+                    .ok_or_else(|| Error::UtxoNotAvailable(id.to_string()))
+            })
+            .collect()
+    }
+
     pub async fn clear(&self) -> Result<()> {
         let local = self.addresses();
         let addresses = local.iter().map(|v| v.clone()).collect::<Vec<_>>();
@@ -286,6 +359,11 @@ impl UtxoContext {
         let mut remove_mature_ids = vec![];
 
         for id in ids.into_iter() {
+            // spent or reorged out UTXOs are no longer frozen
+            if context.frozen.remove(&id) {
+                context.frozen_pruned = true;
+            }
+
             // remove from local map
             if context.map.remove(&id).is_some() {
                 if let Some(pending) = context.pending.remove(&id) {
@@ -526,6 +604,39 @@ impl UtxoContext {
         self.update_balance().await?;
         Ok(())
     }
+
+    /// Replaces the UTXO entries of this context with the ones currently held by the node for the
+    /// monitored addresses, recovering from `UtxosChanged` notifications missed while disconnected.
+    /// Entries consumed by outgoing transactions remain consumed as long as the node still holds them.
+    pub async fn resync(&self, current_daa_score: u64) -> Result<()> {
+        let addresses = self.addresses().iter().map(|address| (**address).clone()).collect::<Vec<_>>();
+        if addresses.is_empty() {
+            return Ok(());
+        }
+
+        let resp = self.processor().rpc().get_utxos_by_addresses(addresses).await?;
+        let refs: Vec<UtxoEntryReference> = resp.into_iter().map(UtxoEntryReference::from).collect();
+        let refs = {
+            let mut context = self.context();
+            let pending = self.processor().pending();
+            context.pending.keys().for_each(|id| {
+                pending.remove(id);
+            });
+            context.pending.clear();
+            context.mature.clear();
+
+            let ids = refs.iter().map(|entry| entry.id()).collect::<HashSet<_>>();
+            context.consumed.retain(|id, _| ids.contains(id));
+            let Context { map, consumed, .. } = &mut *context;
+            map.retain(|id, _| consumed.contains_key(id));
+            refs.into_iter().filter(|entry| !consumed.contains_key(entry.id_as_ref())).collect::<Vec<_>>()
+        };
+
+        self.extend(refs, current_daa_score).await?;
+        self.prune_frozen();
+        self.update_balance().await?;
+        Ok(())
+    }
 }
 
 impl Eq for UtxoContext {}
@@ -6,8 +6,16 @@ pub struct UtxoIterator {
 }
 
 impl UtxoIterator {
+    /// Iterates over mature UTXOs of the context, skipping frozen entries
     pub fn new(utxo_context: &UtxoContext) -> Self {
-        Self { entries: utxo_context.context().mature.clone(), cursor: 0 }
+        let context = utxo_context.context();
+        let entries = context.mature.iter().filter(|entry| !context.frozen.contains(entry.id_as_ref())).cloned().collect();
+        Self { entries, cursor: 0 }
+    }
+
+    /// Iterates over an explicit list of UTXO entries (manual coin selection)
+    pub fn new_with_entries(entries: Vec<UtxoEntryReference>) -> Self {
+        Self { entries, cursor: 0 }
     }
 }
 
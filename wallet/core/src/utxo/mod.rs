@@ -7,6 +7,8 @@ pub mod reference;
 pub mod scan;
 pub mod settings;
 pub mod stream;
+#[cfg(test)]
+pub(crate) mod test;
 
 pub use binding::UtxoContextBinding;
 pub use context::{UtxoContext, UtxoContextId};
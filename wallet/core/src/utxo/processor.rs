@@ -312,10 +312,34 @@ impl UtxoProcessor {
 
         self.inner.is_connected.store(true, Ordering::SeqCst);
         self.register_notification_listener().await?;
+        if !self.inner.address_to_utxo_context_map.is_empty() {
+            // Reconnecting with registered addresses
+            self.resync().await?;
+        }
         self.notify(Events::UtxoProcStart).await?;
         Ok(())
     }
 
+    /// Renews the `UtxosChanged` subscription of all registered addresses and resyncs
+    /// the UTXO entries of their contexts, as notifications sent by the node while
+    /// disconnected are lost.
+    pub async fn resync(&self) -> Result<()> {
+        let addresses = self.inner.address_to_utxo_context_map.iter().map(|entry| (**entry.key()).clone()).collect::<Vec<_>>();
+        if addresses.is_empty() {
+            return Ok(());
+        }
+        let utxos_changed_scope = UtxosChangedScope { addresses };
+        self.rpc().start_notify(self.listener_id(), Scope::UtxosChanged(utxos_changed_scope)).await?;
+
+        let contexts = self.inner.address_to_utxo_context_map.iter().map(|entry| entry.value().clone()).collect::<HashSet<_>>();
+        let current_daa_score = self.inner.current_daa_score.load(Ordering::SeqCst);
+        for context in contexts.into_iter() {
+            context.resync(current_daa_score).await?;
+        }
+
+        Ok(())
+    }
+
     pub async fn handle_connect(&self) -> Result<()> {
         if let Err(err) = self.handle_connect_impl().await {
             self.notify(Events::UtxoProcError(err.to_string())).await?;
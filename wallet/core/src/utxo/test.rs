@@ -0,0 +1,116 @@
+use crate::result::Result;
+use crate::runtime::Wallet;
+use crate::utxo::{UtxoContext, UtxoContextBinding, UtxoEntryId, UtxoEntryReference, UtxoIterator};
+use kaspa_addresses::{Address, Prefix, Version};
+use kaspa_consensus_core::tx::{TransactionId, UtxoEntry};
+use kaspa_consensus_wasm::TransactionOutpoint;
+use std::sync::Arc;
+
+const CURRENT_DAA_SCORE: u64 = 1_000;
+
+/// Creates a UTXO entry with a distinct outpoint per `seed`
+pub(crate) fn utxo_entry(seed: u8, amount: u64, block_daa_score: u64) -> UtxoEntryReference {
+    let address = Address::new(Prefix::Testnet, Version::PubKey, &[0; 32]);
+    let outpoint = TransactionOutpoint::new(TransactionId::from_slice(&[seed; kaspa_hashes::HASH_SIZE]), 0);
+    let script_public_key = kaspa_txscript::pay_to_address_script(&address);
+    let entry = UtxoEntry { amount, script_public_key, block_daa_score, is_coinbase: false };
+    UtxoEntryReference::from(kaspa_consensus_wasm::UtxoEntry { address: Some(address), outpoint, entry })
+}
+
+/// Creates a context holding three mature entries and a single pending one
+async fn utxo_context() -> Result<(UtxoContext, Vec<UtxoEntryReference>)> {
+    let wallet = Arc::new(Wallet::try_new(Wallet::resident_store()?, None)?);
+    let utxo_context = UtxoContext::new(wallet.utxo_processor(), UtxoContextBinding::default());
+    let entries = vec![utxo_entry(1, 100, 0), utxo_entry(2, 200, 0), utxo_entry(3, 300, 0), utxo_entry(4, 400, CURRENT_DAA_SCORE)];
+    utxo_context.extend(entries.clone(), CURRENT_DAA_SCORE).await?;
+    Ok((utxo_context, entries))
+}
+
+fn ids(entries: &[UtxoEntryReference]) -> Vec<UtxoEntryId> {
+    entries.iter().map(|entry| entry.id()).collect()
+}
+
+#[tokio::test]
+async fn test_utxo_freeze_unfreeze() -> Result<()> {
+    let (utxo_context, entries) = utxo_context().await?;
+    let (first, second) = (entries[0].id(), entries[1].id());
+
+    utxo_context.freeze(&[first.clone(), second.clone()]);
+    assert!(utxo_context.is_frozen(&first) && utxo_context.is_frozen(&second));
+    assert!(!utxo_context.is_frozen(&entries[2].id()));
+    // freezing is idempotent
+    utxo_context.freeze(&[first.clone()]);
+    assert_eq!(utxo_context.frozen_utxos().len(), 2);
+
+    utxo_context.unfreeze(&[first.clone()]);
+    assert!(!utxo_context.is_frozen(&first) && utxo_context.is_frozen(&second));
+    assert_eq!(utxo_context.frozen_utxos(), vec![second.clone()]);
+
+    // unfreezing an entry that is not frozen is a no-op
+    utxo_context.unfreeze(&[first, second]);
+    utxo_context.unfreeze(&[entries[3].id()]);
+    assert!(utxo_context.frozen_utxos().is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_utxo_frozen_pruned() -> Result<()> {
+    let (utxo_context, entries) = utxo_context().await?;
+    let unknown = utxo_entry(5, 500, 0).id();
+    utxo_context.freeze(&[entries[0].id(), entries[1].id(), entries[3].id()]);
+    assert!(!utxo_context.take_frozen_pruned());
+
+    // spent and reorged out entries are no longer frozen
+    utxo_context.remove(vec![entries[0].id(), entries[3].id()]).await?;
+    assert_eq!(utxo_context.frozen_utxos(), vec![entries[1].id()]);
+    assert!(utxo_context.take_frozen_pruned());
+    assert!(!utxo_context.take_frozen_pruned());
+
+    // frozen entries not held by the context, e.g. spent while offline, are dropped once it holds all its UTXOs
+    utxo_context.freeze(&[unknown]);
+    utxo_context.prune_frozen();
+    assert_eq!(utxo_context.frozen_utxos(), vec![entries[1].id()]);
+    assert!(utxo_context.take_frozen_pruned());
+
+    // pruning leaves the set untouched if all frozen entries are held
+    utxo_context.prune_frozen();
+    assert!(!utxo_context.take_frozen_pruned());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_utxo_frozen_excluded_from_selection() -> Result<()> {
+    let (utxo_context, entries) = utxo_context().await?;
+
+    assert_eq!(ids(&UtxoIterator::new(&utxo_context).collect::<Vec<_>>()), ids(&entries[0..3]));
+
+    utxo_context.freeze(&[entries[1].id()]);
+    let selected = UtxoIterator::new(&utxo_context).collect::<Vec<_>>();
+    assert_eq!(ids(&selected), vec![entries[0].id(), entries[2].id()]);
+
+    utxo_context.unfreeze(&[entries[1].id()]);
+    assert_eq!(ids(&UtxoIterator::new(&utxo_context).collect::<Vec<_>>()), ids(&entries[0..3]));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_utxo_get_mature_entries() -> Result<()> {
+    let (utxo_context, entries) = utxo_context().await?;
+
+    // entries are returned in the order supplied
+    let requested = vec![entries[2].id(), entries[0].id()];
+    assert_eq!(ids(&utxo_context.get_mature_entries(&requested)?), requested);
+
+    // frozen entries are only excluded from automatic selection and can still be spent explicitly
+    utxo_context.freeze(&[entries[1].id()]);
+    assert_eq!(ids(&utxo_context.get_mature_entries(&[entries[1].id()])?), vec![entries[1].id()]);
+
+    // pending and unknown entries are rejected
+    assert!(utxo_context.get_mature_entries(&[entries[0].id(), entries[3].id()]).is_err());
+    assert!(utxo_context.get_mature_entries(&[utxo_entry(5, 500, 0).id()]).is_err());
+
+    Ok(())
+}
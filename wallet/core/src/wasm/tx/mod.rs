@@ -7,3 +7,5 @@ pub use consensus::*;
 pub use generator::*;
 pub use mass::*;
 pub use utils::*;
+
+pub use crate::tx::PaymentUri;
@@ -116,5 +116,6 @@ pub mod rpc {
 }
 
 pub use kaspa_consensus_wasm::*;
+pub use kaspa_merkle::wasm::*;
 
 pub use kaspa_wallet_core::wasm::{tx::*, utils::*, utxo::*, wallet::*, xprivatekey::*, xpublickey::*};